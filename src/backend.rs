@@ -0,0 +1,1163 @@
+use num_complex::Complex;
+
+#[cfg(feature = "lapack")]
+pub mod lapack;
+pub mod native;
+
+#[cfg(feature = "lapack")]
+pub use self::lapack::Lapack;
+pub use self::native::Native;
+
+// backend used by the matrix operations. OpenBLAS/LAPACK with the `lapack` feature, pure Rust otherwise.
+#[cfg(feature = "lapack")]
+pub type DefaultBackend = Lapack;
+#[cfg(not(feature = "lapack"))]
+pub type DefaultBackend = Native;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transpose {
+    No,
+    Trans,
+    ConjTrans,
+}
+
+impl Transpose {
+    pub const fn as_lapack_char(&self) -> i8 {
+        match self {
+            Self::No => 'N' as i8,
+            Self::Trans => 'T' as i8,
+            Self::ConjTrans => 'C' as i8,
+        }
+    }
+}
+
+impl Default for Transpose {
+    fn default() -> Self {
+        Self::No
+    }
+}
+
+// which triangle of a symmetric matrix is referenced
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Uplo {
+    // A = U^T * U
+    Upper,
+    // A = L * L^T
+    Lower,
+}
+
+impl Uplo {
+    pub const fn as_lapack_char(&self) -> i8 {
+        match self {
+            Self::Upper => 'U' as i8,
+            Self::Lower => 'L' as i8,
+        }
+    }
+}
+
+impl Default for Uplo {
+    fn default() -> Self {
+        Self::Lower
+    }
+}
+
+// element types with BLAS/LAPACK routines: f32, f64 (s/d) and Complex<f32>, Complex<f64> (c/z).
+pub trait BlasScalar: Copy {
+    // the type of norms and absolute values
+    type Real: Copy;
+
+    fn re(self) -> Self::Real;
+    fn im(self) -> Self::Real;
+    fn conj(self) -> Self;
+    // |re| + |im|, the magnitude used by i?amax and by the pivot search of ?getrf
+    fn abs1(self) -> Self::Real;
+}
+
+macro_rules! impl_blas_scalar {
+    ($real: ty) => {
+        impl BlasScalar for $real {
+            type Real = $real;
+
+            fn re(self) -> $real {
+                self
+            }
+            fn im(self) -> $real {
+                0.
+            }
+            fn conj(self) -> Self {
+                self
+            }
+            fn abs1(self) -> $real {
+                self.abs()
+            }
+        }
+
+        impl BlasScalar for Complex<$real> {
+            type Real = $real;
+
+            fn re(self) -> $real {
+                self.re
+            }
+            fn im(self) -> $real {
+                self.im
+            }
+            fn conj(self) -> Self {
+                Complex::conj(&self)
+            }
+            fn abs1(self) -> $real {
+                self.re.abs() + self.im.abs()
+            }
+        }
+    };
+}
+
+impl_blas_scalar!(f32);
+impl_blas_scalar!(f64);
+
+// every matrix is column-major with an explicit leading dimension, as in BLAS/LAPACK.
+// LAPACK routines return `info` unchanged.
+pub trait Backend<T: BlasScalar> {
+    // y = alpha * x + y
+    fn axpy(n: usize, alpha: T, x: &[T], incx: usize, y: &mut [T], incy: usize);
+
+    // x = alpha * x
+    fn scal(n: usize, alpha: T, x: &mut [T], incx: usize);
+
+    // x^T * y
+    fn dot(n: usize, x: &[T], incx: usize, y: &[T], incy: usize) -> T;
+
+    // x^H * y. the same as dot for real T.
+    fn dotc(n: usize, x: &[T], incx: usize, y: &[T], incy: usize) -> T;
+
+    // Euclidean norm of x, without overflow in the intermediate sum of squares
+    fn nrm2(n: usize, x: &[T], incx: usize) -> T::Real;
+
+    // sum of abs1(x_i)
+    fn asum(n: usize, x: &[T], incx: usize) -> T::Real;
+
+    // 1-based index of the first element of the largest abs1. 0 if n is 0.
+    fn iamax(n: usize, x: &[T], incx: usize) -> usize;
+
+    // y = alpha * op(A) * x + beta * y, where A is m x n
+    #[allow(clippy::too_many_arguments)]
+    fn gemv(
+        trans: Transpose,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: &[T],
+        lda: usize,
+        x: &[T],
+        incx: usize,
+        beta: T,
+        y: &mut [T],
+        incy: usize,
+    );
+
+    // A = alpha * x * y^T + A, where A is m x n. unconjugated (?geru) for complex T.
+    #[allow(clippy::too_many_arguments)]
+    fn ger(
+        m: usize,
+        n: usize,
+        alpha: T,
+        x: &[T],
+        incx: usize,
+        y: &[T],
+        incy: usize,
+        a: &mut [T],
+        lda: usize,
+    );
+
+    // C = alpha * op(A) * op(B) + beta * C, where op(A) is m x k and op(B) is k x n
+    #[allow(clippy::too_many_arguments)]
+    fn gemm(
+        trans_a: Transpose,
+        trans_b: Transpose,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: &[T],
+        lda: usize,
+        b: &[T],
+        ldb: usize,
+        beta: T,
+        c: &mut [T],
+        ldc: usize,
+    );
+
+    // LU factorization with partial pivoting. ipiv is 1-based.
+    fn getrf(m: usize, n: usize, a: &mut [T], lda: usize, ipiv: &mut [i32]) -> i32;
+
+    // solves op(A) * X = B with the factorization from getrf.
+    #[allow(clippy::too_many_arguments)]
+    fn getrs(
+        trans: Transpose,
+        n: usize,
+        nrhs: usize,
+        a: &[T],
+        lda: usize,
+        ipiv: &[i32],
+        b: &mut [T],
+        ldb: usize,
+    ) -> i32;
+
+    // inverse from the factorization of getrf, in place.
+    fn getri(n: usize, a: &mut [T], lda: usize, ipiv: &[i32]) -> i32;
+}
+
+// the routines behind the factorizations, for real T only: the complex variants return
+// eigenvalues and singular vectors differently. conventions are those of `Backend`.
+// jobs are LAPACK's characters, e.g. 'N' for no vectors and 'V' for vectors.
+pub trait RealBackend<T: BlasScalar>: Backend<T> {
+    // Cholesky factorization of the `uplo` triangle. the other triangle is not referenced.
+    // info > 0 is the order of the leading minor that is not positive definite.
+    fn potrf(uplo: Uplo, n: usize, a: &mut [T], lda: usize) -> i32;
+
+    // solves A * X = B with the factorization from potrf.
+    #[allow(clippy::too_many_arguments)]
+    fn potrs(
+        uplo: Uplo,
+        n: usize,
+        nrhs: usize,
+        a: &[T],
+        lda: usize,
+        b: &mut [T],
+        ldb: usize,
+    ) -> i32;
+
+    // QR factorization of the m x n matrix A. R is stored on and above the diagonal,
+    // the Householder reflectors below it with their scalars in tau (length min(m, n)).
+    fn geqrf(m: usize, n: usize, a: &mut [T], lda: usize, tau: &mut [T]) -> i32;
+
+    // overwrites the m x n matrix A, holding k reflectors from geqrf, with the first n columns of Q.
+    fn orgqr(m: usize, n: usize, k: usize, a: &mut [T], lda: usize, tau: &[T]) -> i32;
+
+    // C = op(Q) * C, where C is m x n and Q is given by k reflectors from geqrf.
+    #[allow(clippy::too_many_arguments)]
+    fn ormqr(
+        trans: Transpose,
+        m: usize,
+        n: usize,
+        k: usize,
+        a: &[T],
+        lda: usize,
+        tau: &[T],
+        c: &mut [T],
+        ldc: usize,
+    ) -> i32;
+
+    // least squares (m >= n) or minimum norm (m < n) solution of A * X = B, where A is m x n of full rank.
+    // B is max(m, n) x nrhs and the solution is returned in its first n rows. A is overwritten.
+    #[allow(clippy::too_many_arguments)]
+    fn gels(
+        m: usize,
+        n: usize,
+        nrhs: usize,
+        a: &mut [T],
+        lda: usize,
+        b: &mut [T],
+        ldb: usize,
+    ) -> i32;
+
+    // singular values in descending order, and the singular vectors as selected by job:
+    // 'N' none, 'S' the first min(m, n) columns of U and rows of V^T, 'A' all of them. A is overwritten.
+    #[allow(clippy::too_many_arguments)]
+    fn gesdd(
+        job: char,
+        m: usize,
+        n: usize,
+        a: &mut [T],
+        lda: usize,
+        s: &mut [T],
+        u: &mut [T],
+        ldu: usize,
+        vt: &mut [T],
+        ldvt: usize,
+    ) -> i32;
+
+    // the same as gesdd by the slower but more robust QR iteration.
+    #[allow(clippy::too_many_arguments)]
+    fn gesvd(
+        job: char,
+        m: usize,
+        n: usize,
+        a: &mut [T],
+        lda: usize,
+        s: &mut [T],
+        u: &mut [T],
+        ldu: usize,
+        vt: &mut [T],
+        ldvt: usize,
+    ) -> i32;
+
+    // all eigenvalues of a symmetric matrix in ascending order, and its orthonormal eigenvectors
+    // into A if job is 'V'.
+    fn syevd(job: char, uplo: Uplo, n: usize, a: &mut [T], lda: usize, w: &mut [T]) -> i32;
+
+    // selected eigenvalues of a symmetric matrix in ascending order, and their eigenvectors into Z if job is 'V'.
+    // range is 'A' (all), 'I' (the il-th to iu-th, 1-based) or 'V' (those in (vl, vu]).
+    // the number found is written to m. A is overwritten.
+    #[allow(clippy::too_many_arguments)]
+    fn syevr(
+        job: char,
+        range: char,
+        uplo: Uplo,
+        n: usize,
+        a: &mut [T],
+        lda: usize,
+        vl: T,
+        vu: T,
+        il: usize,
+        iu: usize,
+        m: &mut usize,
+        w: &mut [T],
+        z: &mut [T],
+        ldz: usize,
+    ) -> i32;
+
+    // eigenvalues wr + i * wi of a general matrix and its left (jobvl) and right (jobvr) eigenvectors.
+    // a complex conjugate pair is adjacent, positive imaginary part first, and its eigenvectors are
+    // stored as two columns (re, im). each vector has norm 1. A is overwritten.
+    #[allow(clippy::too_many_arguments)]
+    fn geev(
+        jobvl: char,
+        jobvr: char,
+        n: usize,
+        a: &mut [T],
+        lda: usize,
+        wr: &mut [T],
+        wi: &mut [T],
+        vl: &mut [T],
+        ldvl: usize,
+        vr: &mut [T],
+        ldvr: usize,
+    ) -> i32;
+
+    // real Schur form A = Z * T * Z^T. T overwrites A and Z is written to vs.
+    // 2x2 diagonal blocks of T hold complex conjugate pairs in standard form.
+    #[allow(clippy::too_many_arguments)]
+    fn gees(
+        n: usize,
+        a: &mut [T],
+        lda: usize,
+        wr: &mut [T],
+        wi: &mut [T],
+        vs: &mut [T],
+        ldvs: usize,
+    ) -> i32;
+
+    // reorders the Schur form (T, Q) so that the selected eigenvalues lead the diagonal of T.
+    // the dimension of the leading block is written to m. info 1 means the swap was too ill-conditioned.
+    #[allow(clippy::too_many_arguments)]
+    fn trsen(
+        select: &[bool],
+        n: usize,
+        t: &mut [T],
+        ldt: usize,
+        q: &mut [T],
+        ldq: usize,
+        wr: &mut [T],
+        wi: &mut [T],
+        m: &mut usize,
+    ) -> i32;
+
+    // LU factorization of a tridiagonal matrix with partial pivoting. U's second super diagonal
+    // is written to du2 (length n - 2).
+    fn gttrf(
+        n: usize,
+        dl: &mut [T],
+        d: &mut [T],
+        du: &mut [T],
+        du2: &mut [T],
+        ipiv: &mut [i32],
+    ) -> i32;
+
+    // solves A * X = B with the factorization from gttrf.
+    #[allow(clippy::too_many_arguments)]
+    fn gttrs(
+        n: usize,
+        nrhs: usize,
+        dl: &[T],
+        d: &[T],
+        du: &[T],
+        du2: &[T],
+        ipiv: &[i32],
+        b: &mut [T],
+        ldb: usize,
+    ) -> i32;
+
+    // solves A * X = B for a tridiagonal A, overwriting its bands.
+    #[allow(clippy::too_many_arguments)]
+    fn gtsv(
+        n: usize,
+        nrhs: usize,
+        dl: &mut [T],
+        d: &mut [T],
+        du: &mut [T],
+        b: &mut [T],
+        ldb: usize,
+    ) -> i32;
+}
+
+#[cfg(all(test, feature = "lapack"))]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn random(len: usize) -> Vec<f64> {
+        let mut rng = rand::thread_rng();
+        (0..len).map(|_| rng.gen_range(-1.0..1.0)).collect()
+    }
+
+    fn assert_close(left: &[f64], right: &[f64]) {
+        assert_eq!(left.len(), right.len());
+        for (l, r) in left.iter().zip(right) {
+            assert!((l - r).abs() < 1e-10, "{} != {}", l, r);
+        }
+    }
+
+    // the factors of LAPACK and the native backend may differ in signs and order,
+    // so the tests of RealBackend compare what they must agree on instead
+
+    // C = A * B for the m x k matrix A and the k x n matrix B
+    fn mul(m: usize, k: usize, n: usize, a: &[f64], b: &[f64]) -> Vec<f64> {
+        let mut c = vec![0.; m * n];
+        for col in 0..n {
+            for i in 0..k {
+                for row in 0..m {
+                    c[row + col * m] += a[row + i * m] * b[i + col * k];
+                }
+            }
+        }
+        c
+    }
+
+    fn transpose(m: usize, n: usize, a: &[f64]) -> Vec<f64> {
+        (0..m * n).map(|i| a[i / n + (i % n) * m]).collect()
+    }
+
+    fn identity(n: usize) -> Vec<f64> {
+        (0..n * n)
+            .map(|i| if i % n == i / n { 1. } else { 0. })
+            .collect()
+    }
+
+    // Q^T * Q = I for the m x n matrix Q
+    fn assert_orthonormal(m: usize, n: usize, q: &[f64]) {
+        assert_close(&mul(n, m, n, &transpose(m, n, q), q), &identity(n));
+    }
+
+    // random symmetric matrix
+    fn random_symmetric(n: usize) -> Vec<f64> {
+        let a = random(n * n);
+        (0..n * n).map(|i| a[i] + a[i / n + (i % n) * n]).collect()
+    }
+
+    // eigenvalues sorted by their real parts, then imaginary parts
+    fn sorted_eigenvalues(wr: &[f64], wi: &[f64]) -> Vec<f64> {
+        let mut w: Vec<(f64, f64)> = wr.iter().copied().zip(wi.iter().copied()).collect();
+        w.sort_by(|x, y| x.partial_cmp(y).expect("eigenvalues are not NaN"));
+        w.into_iter().flat_map(|(re, im)| [re, im]).collect()
+    }
+
+    #[test]
+    fn cross_check_axpy() {
+        let x = random(20);
+        let mut native = random(20);
+        let mut lapack = native.clone();
+
+        <Native as Backend<f64>>::axpy(10, 0.5, &x, 2, &mut native, 2);
+        <Lapack as Backend<f64>>::axpy(10, 0.5, &x, 2, &mut lapack, 2);
+
+        assert_close(&native, &lapack);
+    }
+
+    #[test]
+    fn cross_check_scal() {
+        let mut native = random(20);
+        let mut lapack = native.clone();
+
+        <Native as Backend<f64>>::scal(7, -1.5, &mut native, 3);
+        <Lapack as Backend<f64>>::scal(7, -1.5, &mut lapack, 3);
+
+        assert_close(&native, &lapack);
+    }
+
+    #[test]
+    fn cross_check_level1() {
+        let x = random(21);
+        let y = random(21);
+
+        let (native, lapack) = (
+            <Native as Backend<f64>>::dot(7, &x, 3, &y, 3),
+            <Lapack as Backend<f64>>::dot(7, &x, 3, &y, 3),
+        );
+        assert_close(&[native], &[lapack]);
+
+        let (native, lapack) = (
+            <Native as Backend<f64>>::nrm2(21, &x, 1),
+            <Lapack as Backend<f64>>::nrm2(21, &x, 1),
+        );
+        assert_close(&[native], &[lapack]);
+
+        let (native, lapack) = (
+            <Native as Backend<f64>>::asum(21, &x, 1),
+            <Lapack as Backend<f64>>::asum(21, &x, 1),
+        );
+        assert_close(&[native], &[lapack]);
+
+        assert_eq!(
+            <Native as Backend<f64>>::iamax(10, &x, 2),
+            <Lapack as Backend<f64>>::iamax(10, &x, 2)
+        );
+    }
+
+    #[test]
+    fn cross_check_gemv_ger() {
+        let (m, n) = (5, 4);
+        let a = random(m * n);
+        let x = random(m.max(n));
+        let y = random(m.max(n));
+
+        for (trans, len) in [(Transpose::No, m), (Transpose::Trans, n)] {
+            let mut native = y[..len].to_vec();
+            let mut lapack = native.clone();
+
+            <Native as Backend<f64>>::gemv(trans, m, n, 2., &a, m, &x, 1, 0.5, &mut native, 1);
+            <Lapack as Backend<f64>>::gemv(trans, m, n, 2., &a, m, &x, 1, 0.5, &mut lapack, 1);
+
+            assert_close(&native, &lapack);
+        }
+
+        let (mut native, mut lapack) = (a.clone(), a);
+        <Native as Backend<f64>>::ger(m, n, -1., &x, 1, &y, 1, &mut native, m);
+        <Lapack as Backend<f64>>::ger(m, n, -1., &x, 1, &y, 1, &mut lapack, m);
+
+        assert_close(&native, &lapack);
+    }
+
+    #[test]
+    fn cross_check_gemm() {
+        let (m, n, k) = (7, 5, 6);
+        let a = random(m * k);
+        let b = random(k * n);
+        let c = random(m * n);
+
+        for (ta, lda) in [(Transpose::No, m), (Transpose::Trans, k)] {
+            for (tb, ldb) in [(Transpose::No, k), (Transpose::Trans, n)] {
+                let mut native = c.clone();
+                let mut lapack = c.clone();
+
+                <Native as Backend<f64>>::gemm(
+                    ta,
+                    tb,
+                    m,
+                    n,
+                    k,
+                    1.5,
+                    &a,
+                    lda,
+                    &b,
+                    ldb,
+                    0.5,
+                    &mut native,
+                    m,
+                );
+                <Lapack as Backend<f64>>::gemm(
+                    ta,
+                    tb,
+                    m,
+                    n,
+                    k,
+                    1.5,
+                    &a,
+                    lda,
+                    &b,
+                    ldb,
+                    0.5,
+                    &mut lapack,
+                    m,
+                );
+
+                assert_close(&native, &lapack);
+            }
+        }
+    }
+
+    #[test]
+    fn cross_check_getrf_getrs() {
+        let n = 8;
+        let a = random(n * n);
+        let b = random(n * 3);
+
+        let (mut native_a, mut lapack_a) = (a.clone(), a);
+        let (mut native_piv, mut lapack_piv) = (vec![0; n], vec![0; n]);
+
+        assert_eq!(
+            <Native as Backend<f64>>::getrf(n, n, &mut native_a, n, &mut native_piv),
+            0
+        );
+        assert_eq!(
+            <Lapack as Backend<f64>>::getrf(n, n, &mut lapack_a, n, &mut lapack_piv),
+            0
+        );
+
+        assert_eq!(native_piv, lapack_piv);
+        assert_close(&native_a, &lapack_a);
+
+        for trans in [Transpose::No, Transpose::Trans] {
+            let (mut native_b, mut lapack_b) = (b.clone(), b.clone());
+
+            <Native as Backend<f64>>::getrs(
+                trans,
+                n,
+                3,
+                &native_a,
+                n,
+                &native_piv,
+                &mut native_b,
+                n,
+            );
+            <Lapack as Backend<f64>>::getrs(
+                trans,
+                n,
+                3,
+                &lapack_a,
+                n,
+                &lapack_piv,
+                &mut lapack_b,
+                n,
+            );
+
+            assert_close(&native_b, &lapack_b);
+        }
+    }
+
+    #[test]
+    fn cross_check_complex() {
+        let random_complex = |len: usize| -> Vec<Complex<f64>> {
+            random(2 * len)
+                .chunks(2)
+                .map(|c| Complex::new(c[0], c[1]))
+                .collect()
+        };
+        let close = |left: &[Complex<f64>], right: &[Complex<f64>]| {
+            for (l, r) in left.iter().zip(right) {
+                assert!((l - r).norm() < 1e-10, "{} != {}", l, r);
+            }
+        };
+
+        let n = 6;
+        let a = random_complex(n * n);
+        let b = random_complex(n * n);
+        let alpha = Complex::new(0.5, -1.);
+
+        let (native, lapack) = (
+            <Native as Backend<Complex<f64>>>::dotc(n, &a, 1, &b, 1),
+            <Lapack as Backend<Complex<f64>>>::dotc(n, &a, 1, &b, 1),
+        );
+        close(&[native], &[lapack]);
+
+        for trans in [Transpose::No, Transpose::Trans, Transpose::ConjTrans] {
+            let (mut native, mut lapack) = (
+                vec![Complex::new(0., 0.); n * n],
+                vec![Complex::new(0., 0.); n * n],
+            );
+
+            <Native as Backend<Complex<f64>>>::gemm(
+                trans,
+                Transpose::ConjTrans,
+                n,
+                n,
+                n,
+                alpha,
+                &a,
+                n,
+                &b,
+                n,
+                alpha,
+                &mut native,
+                n,
+            );
+            <Lapack as Backend<Complex<f64>>>::gemm(
+                trans,
+                Transpose::ConjTrans,
+                n,
+                n,
+                n,
+                alpha,
+                &a,
+                n,
+                &b,
+                n,
+                alpha,
+                &mut lapack,
+                n,
+            );
+
+            close(&native, &lapack);
+        }
+
+        let (mut native_a, mut lapack_a) = (a.clone(), a);
+        let (mut native_piv, mut lapack_piv) = (vec![0; n], vec![0; n]);
+
+        assert_eq!(
+            <Native as Backend<Complex<f64>>>::getrf(n, n, &mut native_a, n, &mut native_piv),
+            0
+        );
+        assert_eq!(
+            <Lapack as Backend<Complex<f64>>>::getrf(n, n, &mut lapack_a, n, &mut lapack_piv),
+            0
+        );
+
+        assert_eq!(native_piv, lapack_piv);
+        close(&native_a, &lapack_a);
+    }
+
+    #[test]
+    fn cross_check_potrf_potrs() {
+        let n = 6;
+        let b = random(n * n);
+        // B^T * B + n * I is positive definite
+        let mut a = mul(n, n, n, &transpose(n, n, &b), &b);
+        for i in 0..n {
+            a[i + i * n] += n as f64;
+        }
+        let rhs = random(n * 2);
+
+        fn solve<B: RealBackend<f64>>(uplo: Uplo, n: usize, a: &[f64], rhs: &[f64]) -> Vec<f64> {
+            let mut factor = a.to_vec();
+            assert_eq!(B::potrf(uplo, n, &mut factor, n), 0);
+
+            // the triangle not referenced is kept as it is
+            let triangle: Vec<f64> = (0..n * n)
+                .map(|i| {
+                    let (row, col) = (i % n, i / n);
+                    let inside = match uplo {
+                        Uplo::Upper => row <= col,
+                        Uplo::Lower => row >= col,
+                    };
+                    if inside {
+                        factor[i]
+                    } else {
+                        assert_eq!(factor[i], a[i]);
+                        0.
+                    }
+                })
+                .collect();
+            let product = match uplo {
+                Uplo::Upper => mul(n, n, n, &transpose(n, n, &triangle), &triangle),
+                Uplo::Lower => mul(n, n, n, &triangle, &transpose(n, n, &triangle)),
+            };
+            assert_close(&product, a);
+
+            let mut x = rhs.to_vec();
+            assert_eq!(B::potrs(uplo, n, 2, &factor, n, &mut x, n), 0);
+            assert_close(&mul(n, n, 2, a, &x), rhs);
+            x
+        }
+
+        for uplo in [Uplo::Upper, Uplo::Lower] {
+            assert_close(
+                &solve::<Native>(uplo, n, &a, &rhs),
+                &solve::<Lapack>(uplo, n, &a, &rhs),
+            );
+        }
+
+        // info is the order of the leading minor that is not positive definite
+        let mut indefinite = identity(3);
+        indefinite[4] = -1.;
+        for uplo in [Uplo::Upper, Uplo::Lower] {
+            assert_eq!(
+                <Native as RealBackend<f64>>::potrf(uplo, 3, &mut indefinite.clone(), 3),
+                <Lapack as RealBackend<f64>>::potrf(uplo, 3, &mut indefinite.clone(), 3)
+            );
+        }
+    }
+
+    #[test]
+    fn cross_check_qr() {
+        fn check<B: RealBackend<f64>>(m: usize, n: usize, a: &[f64]) {
+            let k = m.min(n);
+            let mut qr = a.to_vec();
+            let mut tau = vec![0.; k];
+            assert_eq!(B::geqrf(m, n, &mut qr, m, &mut tau), 0);
+            let r: Vec<f64> = (0..k * n)
+                .map(|i| {
+                    if i % k <= i / k {
+                        qr[i % k + (i / k) * m]
+                    } else {
+                        0.
+                    }
+                })
+                .collect();
+
+            // the first k columns of Q
+            let mut q = qr[..m * k].to_vec();
+            assert_eq!(B::orgqr(m, k, k, &mut q, m, &tau), 0);
+            assert_orthonormal(m, k, &q);
+            assert_close(&mul(m, k, n, &q, &r), a);
+
+            // Q^T * A = [R; 0] and Q * [R; 0] = A
+            let mut c = a.to_vec();
+            assert_eq!(
+                B::ormqr(Transpose::Trans, m, n, k, &qr, m, &tau, &mut c, m),
+                0
+            );
+            let padded: Vec<f64> = (0..m * n)
+                .map(|i| {
+                    if i % m < k {
+                        r[i % m + (i / m) * k]
+                    } else {
+                        0.
+                    }
+                })
+                .collect();
+            assert_close(&c, &padded);
+            assert_eq!(B::ormqr(Transpose::No, m, n, k, &qr, m, &tau, &mut c, m), 0);
+            assert_close(&c, a);
+        }
+
+        fn gels<B: RealBackend<f64>>(m: usize, n: usize, a: &[f64], b: &[f64]) -> Vec<f64> {
+            let ldb = m.max(n);
+            let mut x = vec![0.; ldb * 2];
+            for col in 0..2 {
+                x[col * ldb..col * ldb + m].copy_from_slice(&b[col * m..(col + 1) * m]);
+            }
+            assert_eq!(B::gels(m, n, 2, &mut a.to_vec(), m, &mut x, ldb), 0);
+            (0..n * 2).map(|i| x[i % n + (i / n) * ldb]).collect()
+        }
+
+        for (m, n) in [(7, 4), (4, 7), (5, 5)] {
+            let a = random(m * n);
+            check::<Native>(m, n, &a);
+            check::<Lapack>(m, n, &a);
+
+            // the least squares and minimum norm solutions are unique
+            let b = random(m * 2);
+            assert_close(&gels::<Native>(m, n, &a, &b), &gels::<Lapack>(m, n, &a, &b));
+        }
+    }
+
+    #[test]
+    fn cross_check_svd() {
+        type Svd = fn(
+            char,
+            usize,
+            usize,
+            &mut [f64],
+            usize,
+            &mut [f64],
+            &mut [f64],
+            usize,
+            &mut [f64],
+            usize,
+        ) -> i32;
+
+        fn singular_values(svd: Svd, m: usize, n: usize, a: &[f64]) -> Vec<f64> {
+            let k = m.min(n);
+            let (mut u, mut vt) = (vec![0.; m * m], vec![0.; n * n]);
+            let mut s = vec![0.; k];
+            assert_eq!(
+                svd('A', m, n, &mut a.to_vec(), m, &mut s, &mut u, m, &mut vt, n),
+                0
+            );
+
+            assert!(s.windows(2).all(|w| w[0] >= w[1]) && s[k - 1] >= 0.);
+            assert_orthonormal(m, m, &u);
+            assert_orthonormal(n, n, &vt);
+            let us: Vec<f64> = (0..m * k).map(|i| u[i] * s[i / m]).collect();
+            let vt_k: Vec<f64> = (0..k * n).map(|i| vt[i % k + (i / k) * n]).collect();
+            assert_close(&mul(m, k, n, &us, &vt_k), a);
+
+            // the same values without vectors
+            let mut values = vec![0.; k];
+            assert_eq!(
+                svd(
+                    'N',
+                    m,
+                    n,
+                    &mut a.to_vec(),
+                    m,
+                    &mut values,
+                    &mut [],
+                    1,
+                    &mut [],
+                    1
+                ),
+                0
+            );
+            assert_close(&values, &s);
+            s
+        }
+
+        for (m, n) in [(7, 4), (4, 7), (5, 5)] {
+            let a = random(m * n);
+            let s = singular_values(<Lapack as RealBackend<f64>>::gesdd, m, n, &a);
+            for svd in [
+                <Native as RealBackend<f64>>::gesdd as Svd,
+                <Native as RealBackend<f64>>::gesvd,
+                <Lapack as RealBackend<f64>>::gesvd,
+            ] {
+                assert_close(&singular_values(svd, m, n, &a), &s);
+            }
+        }
+    }
+
+    #[test]
+    fn cross_check_syevd_syevr() {
+        let n = 7;
+        let a = random_symmetric(n);
+
+        // A * Z = Z * diag(w) for the n x m matrix Z
+        let assert_eigenpairs = |m: usize, w: &[f64], z: &[f64]| {
+            assert!(w.windows(2).all(|w| w[0] <= w[1]));
+            assert_orthonormal(n, m, z);
+            let zw: Vec<f64> = (0..n * m).map(|i| z[i] * w[i / n]).collect();
+            assert_close(&mul(n, n, m, &a, z), &zw);
+        };
+
+        let syevd = |uplo: Uplo, lapack: bool| {
+            let (mut z, mut w) = (a.clone(), vec![0.; n]);
+            let info = if lapack {
+                <Lapack as RealBackend<f64>>::syevd('V', uplo, n, &mut z, n, &mut w)
+            } else {
+                <Native as RealBackend<f64>>::syevd('V', uplo, n, &mut z, n, &mut w)
+            };
+            assert_eq!(info, 0);
+            assert_eigenpairs(n, &w, &z);
+            w
+        };
+
+        let syevr = |range: char, uplo: Uplo, lapack: bool| {
+            let (mut z, mut w, mut m) = (vec![0.; n * n], vec![0.; n], 0);
+            let syevr = if lapack {
+                <Lapack as RealBackend<f64>>::syevr
+            } else {
+                <Native as RealBackend<f64>>::syevr
+            };
+            let info = syevr(
+                'V',
+                range,
+                uplo,
+                n,
+                &mut a.clone(),
+                n,
+                -1.,
+                1.,
+                2,
+                5,
+                &mut m,
+                &mut w,
+                &mut z,
+                n,
+            );
+            assert_eq!(info, 0);
+            assert_eigenpairs(m, &w[..m], &z[..n * m]);
+            w.truncate(m);
+            w
+        };
+
+        for uplo in [Uplo::Upper, Uplo::Lower] {
+            let w = syevd(uplo, true);
+            assert_close(&syevd(uplo, false), &w);
+
+            for lapack in [false, true] {
+                assert_close(&syevr('A', uplo, lapack), &w);
+                assert_close(&syevr('I', uplo, lapack), &w[1..5]);
+                let inside: Vec<f64> = w.iter().copied().filter(|&x| -1. < x && x <= 1.).collect();
+                assert_close(&syevr('V', uplo, lapack), &inside);
+            }
+        }
+    }
+
+    #[test]
+    fn cross_check_geev() {
+        let n = 7;
+        let a = random(n * n);
+
+        let geev = |lapack: bool| {
+            let (mut wr, mut wi) = (vec![0.; n], vec![0.; n]);
+            let (mut vl, mut vr) = (vec![0.; n * n], vec![0.; n * n]);
+            let geev = if lapack {
+                <Lapack as RealBackend<f64>>::geev
+            } else {
+                <Native as RealBackend<f64>>::geev
+            };
+            assert_eq!(
+                geev(
+                    'V',
+                    'V',
+                    n,
+                    &mut a.clone(),
+                    n,
+                    &mut wr,
+                    &mut wi,
+                    &mut vl,
+                    n,
+                    &mut vr,
+                    n
+                ),
+                0
+            );
+
+            let mut j = 0;
+            while j < n {
+                // a conjugate pair shares the columns (re, im)
+                let pair = wi[j] != 0.;
+                if pair {
+                    assert_eq!((wr[j], wi[j]), (wr[j + 1], -wi[j + 1]));
+                    assert!(wi[j] > 0.);
+                }
+                let lambda = Complex::new(wr[j], wi[j]);
+                let vector = |v: &[f64], i: usize| {
+                    Complex::new(v[i + j * n], if pair { v[i + (j + 1) * n] } else { 0. })
+                };
+
+                let (mut right, mut left) = (0., 0.);
+                for i in 0..n {
+                    // A * v = lambda * v and u^H * A = lambda * u^H, i.e. A^T * u = conj(lambda) * u
+                    let av: Complex<f64> = (0..n).map(|k| vector(&vr, k) * a[i + k * n]).sum();
+                    let atu: Complex<f64> = (0..n).map(|k| vector(&vl, k) * a[k + i * n]).sum();
+                    right += (av - lambda * vector(&vr, i)).norm();
+                    left += (atu - lambda.conj() * vector(&vl, i)).norm();
+                }
+                assert!(right < 1e-10 && left < 1e-10, "{} {}", right, left);
+
+                let (norm_r, norm_l) = (0..n).fold((0., 0.), |(r, l), i| {
+                    (r + vector(&vr, i).norm_sqr(), l + vector(&vl, i).norm_sqr())
+                });
+                assert_close(&[norm_r, norm_l], &[1., 1.]);
+
+                j += if pair { 2 } else { 1 };
+            }
+
+            sorted_eigenvalues(&wr, &wi)
+        };
+
+        assert_close(&geev(false), &geev(true));
+    }
+
+    #[test]
+    fn cross_check_gees_trsen() {
+        let n = 7;
+        let a = random(n * n);
+
+        let schur = |lapack: bool| {
+            let (mut t, mut z) = (a.clone(), vec![0.; n * n]);
+            let (mut wr, mut wi) = (vec![0.; n], vec![0.; n]);
+            let info = if lapack {
+                <Lapack as RealBackend<f64>>::gees(n, &mut t, n, &mut wr, &mut wi, &mut z, n)
+            } else {
+                <Native as RealBackend<f64>>::gees(n, &mut t, n, &mut wr, &mut wi, &mut z, n)
+            };
+            assert_eq!(info, 0);
+
+            // A = Z * T * Z^T with an orthogonal Z and a quasi triangular T
+            let assert_schur = |t: &[f64], z: &[f64]| {
+                assert_orthonormal(n, n, z);
+                assert_close(&mul(n, n, n, &mul(n, n, n, z, t), &transpose(n, n, z)), &a);
+                for col in 0..n {
+                    for row in col + 2..n {
+                        assert!(t[row + col * n].abs() < 1e-10);
+                    }
+                    if col + 2 < n {
+                        assert!(t[col + 1 + col * n] == 0. || t[col + 2 + (col + 1) * n] == 0.);
+                    }
+                }
+            };
+            assert_schur(&t, &z);
+            let all = sorted_eigenvalues(&wr, &wi);
+
+            // the eigenvalues in the right half plane first. a conjugate pair is selected together
+            let select: Vec<bool> = wr.iter().map(|&re| re > 0.).collect();
+            let mut m = 0;
+            let info = if lapack {
+                <Lapack as RealBackend<f64>>::trsen(
+                    &select, n, &mut t, n, &mut z, n, &mut wr, &mut wi, &mut m,
+                )
+            } else {
+                <Native as RealBackend<f64>>::trsen(
+                    &select, n, &mut t, n, &mut z, n, &mut wr, &mut wi, &mut m,
+                )
+            };
+            assert_eq!(info, 0);
+            assert_schur(&t, &z);
+            assert_eq!(m, select.iter().filter(|&&s| s).count());
+            assert!(wr[..m].iter().all(|&re| re > 0.) && wr[m..].iter().all(|&re| re <= 0.));
+            assert_close(&sorted_eigenvalues(&wr, &wi), &all);
+
+            all
+        };
+
+        assert_close(&schur(false), &schur(true));
+    }
+
+    #[test]
+    fn cross_check_tridiagonal() {
+        let n = 8;
+        let (dl, d, du) = (random(n - 1), random(n), random(n - 1));
+        let b = random(n * 3);
+
+        // A * X for the tridiagonal A
+        let apply = |x: &[f64]| -> Vec<f64> {
+            (0..n * 3)
+                .map(|i| {
+                    let (row, col) = (i % n, i / n);
+                    let mut y = d[row] * x[i];
+                    if row > 0 {
+                        y += dl[row - 1] * x[row - 1 + col * n];
+                    }
+                    if row + 1 < n {
+                        y += du[row] * x[row + 1 + col * n];
+                    }
+                    y
+                })
+                .collect()
+        };
+
+        let solve = |lapack: bool| {
+            let (mut l, mut diag, mut u) = (dl.clone(), d.clone(), du.clone());
+            let (mut u2, mut ipiv) = (vec![0.; n - 2], vec![0; n]);
+            let mut x = b.clone();
+            if lapack {
+                assert_eq!(
+                    <Lapack as RealBackend<f64>>::gttrf(
+                        n, &mut l, &mut diag, &mut u, &mut u2, &mut ipiv
+                    ),
+                    0
+                );
+                assert_eq!(
+                    <Lapack as RealBackend<f64>>::gttrs(n, 3, &l, &diag, &u, &u2, &ipiv, &mut x, n),
+                    0
+                );
+            } else {
+                assert_eq!(
+                    <Native as RealBackend<f64>>::gttrf(
+                        n, &mut l, &mut diag, &mut u, &mut u2, &mut ipiv
+                    ),
+                    0
+                );
+                assert_eq!(
+                    <Native as RealBackend<f64>>::gttrs(n, 3, &l, &diag, &u, &u2, &ipiv, &mut x, n),
+                    0
+                );
+            }
+            assert_close(&apply(&x), &b);
+
+            let (mut l, mut diag, mut u) = (dl.clone(), d.clone(), du.clone());
+            let mut y = b.clone();
+            let info = if lapack {
+                <Lapack as RealBackend<f64>>::gtsv(n, 3, &mut l, &mut diag, &mut u, &mut y, n)
+            } else {
+                <Native as RealBackend<f64>>::gtsv(n, 3, &mut l, &mut diag, &mut u, &mut y, n)
+            };
+            assert_eq!(info, 0);
+            assert_close(&y, &x);
+            x
+        };
+
+        assert_close(&solve(false), &solve(true));
+    }
+}
@@ -0,0 +1,1143 @@
+use super::{Backend, BlasScalar, RealBackend, Transpose, Uplo};
+
+use num_complex::Complex;
+use num_traits::Zero;
+
+// OpenBLAS / reference LAPACK through the Fortran ABI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lapack;
+
+// ?dot differs by type: the complex functions return by value, which has no portable Fortran ABI,
+// so the CBLAS *_sub variants writing through a pointer are used instead.
+trait LapackDot: Sized {
+    fn dot(n: usize, x: &[Self], incx: usize, y: &[Self], incy: usize) -> Self;
+    fn dotc(n: usize, x: &[Self], incx: usize, y: &[Self], incy: usize) -> Self;
+}
+
+macro_rules! impl_real_dot {
+    ($dot: ident, $type: ty) => {
+        paste::paste! {
+            #[link(name = "openblas")]
+            extern "C" {
+                fn [<$dot _>](
+                    n: *const i32,
+                    x: *const $type,
+                    incx: *const i32,
+                    y: *const $type,
+                    incy: *const i32,
+                ) -> $type;
+            }
+
+            impl LapackDot for $type {
+                fn dot(n: usize, x: &[$type], incx: usize, y: &[$type], incy: usize) -> $type {
+                    unsafe { [<$dot _>](&(n as i32), x.as_ptr(), &(incx as i32), y.as_ptr(), &(incy as i32)) }
+                }
+
+                fn dotc(n: usize, x: &[$type], incx: usize, y: &[$type], incy: usize) -> $type {
+                    Self::dot(n, x, incx, y, incy)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_complex_dot {
+    ($dotu: ident, $dotc: ident, $type: ty) => {
+        #[link(name = "openblas")]
+        extern "C" {
+            fn $dotu(
+                n: i32,
+                x: *const $type,
+                incx: i32,
+                y: *const $type,
+                incy: i32,
+                dotu: *mut $type,
+            );
+            fn $dotc(
+                n: i32,
+                x: *const $type,
+                incx: i32,
+                y: *const $type,
+                incy: i32,
+                dotc: *mut $type,
+            );
+        }
+
+        impl LapackDot for $type {
+            fn dot(n: usize, x: &[$type], incx: usize, y: &[$type], incy: usize) -> $type {
+                let mut ret = <$type>::zero();
+                unsafe {
+                    $dotu(
+                        n as i32,
+                        x.as_ptr(),
+                        incx as i32,
+                        y.as_ptr(),
+                        incy as i32,
+                        &mut ret,
+                    )
+                };
+                ret
+            }
+
+            fn dotc(n: usize, x: &[$type], incx: usize, y: &[$type], incy: usize) -> $type {
+                let mut ret = <$type>::zero();
+                unsafe {
+                    $dotc(
+                        n as i32,
+                        x.as_ptr(),
+                        incx as i32,
+                        y.as_ptr(),
+                        incy as i32,
+                        &mut ret,
+                    )
+                };
+                ret
+            }
+        }
+    };
+}
+
+impl_real_dot!(sdot, f32);
+impl_real_dot!(ddot, f64);
+impl_complex_dot!(cblas_cdotu_sub, cblas_cdotc_sub, Complex<f32>);
+impl_complex_dot!(cblas_zdotu_sub, cblas_zdotc_sub, Complex<f64>);
+
+macro_rules! impl_macro {
+    ($axpy: ident, $scal: ident, $nrm2: ident, $asum: ident, $iamax: ident, $gemv: ident, $ger: ident, $gemm: ident, $getrf: ident, $getrs: ident, $getri: ident, $type: ty, $real: ty) => {
+        paste::paste! {
+            #[link(name = "openblas")]
+            extern "C" {
+                fn [<$axpy _>](
+                    n: *const i32,
+                    alpha: *const $type,
+                    x: *const $type,
+                    incx: *const i32,
+                    y: *mut $type,
+                    incy: *const i32,
+                );
+                fn [<$scal _>](
+                    n: *const i32,
+                    alpha: *const $type,
+                    x: *mut $type,
+                    incx: *const i32,
+                );
+                fn [<$nrm2 _>](n: *const i32, x: *const $type, incx: *const i32) -> $real;
+                fn [<$asum _>](n: *const i32, x: *const $type, incx: *const i32) -> $real;
+                fn [<$iamax _>](n: *const i32, x: *const $type, incx: *const i32) -> i32;
+                fn [<$gemv _>](
+                    // y = alpha * op(A) * x + beta * y
+                    trans: *const i8,
+                    m: *const i32,
+                    n: *const i32,
+                    alpha: *const $type,
+                    a: *const $type,
+                    lda: *const i32,
+                    x: *const $type,
+                    incx: *const i32,
+                    beta: *const $type,
+                    y: *mut $type,
+                    incy: *const i32,
+                );
+                fn [<$ger _>](
+                    // A = alpha * x * y^T + A
+                    m: *const i32,
+                    n: *const i32,
+                    alpha: *const $type,
+                    x: *const $type,
+                    incx: *const i32,
+                    y: *const $type,
+                    incy: *const i32,
+                    a: *mut $type,
+                    lda: *const i32,
+                );
+                fn [<$gemm _>](
+                    // C = alpha * A * B + beta * C
+                    trans_mode_a: *const i8,
+                    trans_mode_b: *const i8,
+                    height_of_a: *const i32,
+                    width_of_b: *const i32,
+                    widht_of_a_height_of_b: *const i32,
+                    alpha: *const $type,
+                    a: *const $type,
+                    leading_a: *const i32,
+                    b: *const $type,
+                    leading_b: *const i32,
+                    beta: *const $type,
+                    c: *mut $type,
+                    leading_c: *const i32,
+                );
+            }
+
+            #[link(name = "lapack")]
+            extern "C" {
+                fn [<$getrf _>](
+                    m: *const i32,   // integer
+                    n: *const i32,   // integer
+                    a: *mut $type,   // array of $type. length = lda * n
+                    lda: *const i32, // integer
+                    ipiv: *mut i32,  // array of integer. length = min(m, n)
+                    info: *mut i32,  // integer
+                );
+                fn [<$getrs _>](
+                    trans: *const i8, // character
+                    n: *const i32,    // integer
+                    nrhs: *const i32, // integer
+                    a: *const $type,  // array of $type. length = lda * n
+                    lda: *const i32,  // integer
+                    ipiv: *const i32, // array of integer. length = n
+                    b: *mut $type,    // array of $type. length = ldb * nrhs
+                    ldb: *const i32,  // integer
+                    info: *mut i32,   // integer
+                );
+                fn [<$getri _>](
+                    n: *const i32,     // integer
+                    a: *mut $type,     // array of $type. length = lda * n
+                    lda: *const i32,   // integer
+                    ipiv: *const i32,  // array of integer. length = n
+                    work: *mut $type,  // array of $type. length = lwork
+                    lwork: *const i32, // integer
+                    info: *mut i32,    // integer
+                );
+            }
+
+            impl Backend<$type> for Lapack {
+                fn axpy(n: usize, alpha: $type, x: &[$type], incx: usize, y: &mut [$type], incy: usize) {
+                    unsafe {
+                        [<$axpy _>](
+                            &(n as i32),
+                            &alpha,
+                            x.as_ptr(),
+                            &(incx as i32),
+                            y.as_mut_ptr(),
+                            &(incy as i32),
+                        )
+                    }
+                }
+
+                fn scal(n: usize, alpha: $type, x: &mut [$type], incx: usize) {
+                    unsafe { [<$scal _>](&(n as i32), &alpha, x.as_mut_ptr(), &(incx as i32)) }
+                }
+
+                fn dot(n: usize, x: &[$type], incx: usize, y: &[$type], incy: usize) -> $type {
+                    <$type as LapackDot>::dot(n, x, incx, y, incy)
+                }
+
+                fn dotc(n: usize, x: &[$type], incx: usize, y: &[$type], incy: usize) -> $type {
+                    <$type as LapackDot>::dotc(n, x, incx, y, incy)
+                }
+
+                fn nrm2(n: usize, x: &[$type], incx: usize) -> $real {
+                    unsafe { [<$nrm2 _>](&(n as i32), x.as_ptr(), &(incx as i32)) }
+                }
+
+                fn asum(n: usize, x: &[$type], incx: usize) -> $real {
+                    unsafe { [<$asum _>](&(n as i32), x.as_ptr(), &(incx as i32)) }
+                }
+
+                fn iamax(n: usize, x: &[$type], incx: usize) -> usize {
+                    unsafe { [<$iamax _>](&(n as i32), x.as_ptr(), &(incx as i32)) as usize }
+                }
+
+                fn gemv(
+                    trans: Transpose,
+                    m: usize,
+                    n: usize,
+                    alpha: $type,
+                    a: &[$type],
+                    lda: usize,
+                    x: &[$type],
+                    incx: usize,
+                    beta: $type,
+                    y: &mut [$type],
+                    incy: usize,
+                ) {
+                    unsafe {
+                        [<$gemv _>](
+                            &trans.as_lapack_char(),
+                            &(m as i32),
+                            &(n as i32),
+                            &alpha,
+                            a.as_ptr(),
+                            &(lda.max(1) as i32),
+                            x.as_ptr(),
+                            &(incx as i32),
+                            &beta,
+                            y.as_mut_ptr(),
+                            &(incy as i32),
+                        )
+                    }
+                }
+
+                fn ger(
+                    m: usize,
+                    n: usize,
+                    alpha: $type,
+                    x: &[$type],
+                    incx: usize,
+                    y: &[$type],
+                    incy: usize,
+                    a: &mut [$type],
+                    lda: usize,
+                ) {
+                    unsafe {
+                        [<$ger _>](
+                            &(m as i32),
+                            &(n as i32),
+                            &alpha,
+                            x.as_ptr(),
+                            &(incx as i32),
+                            y.as_ptr(),
+                            &(incy as i32),
+                            a.as_mut_ptr(),
+                            &(lda.max(1) as i32),
+                        )
+                    }
+                }
+
+                fn gemm(
+                    trans_a: Transpose,
+                    trans_b: Transpose,
+                    m: usize,
+                    n: usize,
+                    k: usize,
+                    alpha: $type,
+                    a: &[$type],
+                    lda: usize,
+                    b: &[$type],
+                    ldb: usize,
+                    beta: $type,
+                    c: &mut [$type],
+                    ldc: usize,
+                ) {
+                    unsafe {
+                        [<$gemm _>](
+                            &trans_a.as_lapack_char(),
+                            &trans_b.as_lapack_char(),
+                            &(m as i32),
+                            &(n as i32),
+                            &(k as i32),
+                            &alpha,
+                            a.as_ptr(),
+                            &(lda.max(1) as i32),
+                            b.as_ptr(),
+                            &(ldb.max(1) as i32),
+                            &beta,
+                            c.as_mut_ptr(),
+                            &(ldc.max(1) as i32),
+                        )
+                    }
+                }
+
+                fn getrf(m: usize, n: usize, a: &mut [$type], lda: usize, ipiv: &mut [i32]) -> i32 {
+                    let mut info = 0;
+                    unsafe {
+                        [<$getrf _>](
+                            &(m as i32),
+                            &(n as i32),
+                            a.as_mut_ptr(),
+                            &(lda.max(1) as i32),
+                            ipiv.as_mut_ptr(),
+                            &mut info,
+                        )
+                    };
+                    info
+                }
+
+                fn getrs(
+                    trans: Transpose,
+                    n: usize,
+                    nrhs: usize,
+                    a: &[$type],
+                    lda: usize,
+                    ipiv: &[i32],
+                    b: &mut [$type],
+                    ldb: usize,
+                ) -> i32 {
+                    let mut info = 0;
+                    unsafe {
+                        [<$getrs _>](
+                            &trans.as_lapack_char(),
+                            &(n as i32),
+                            &(nrhs as i32),
+                            a.as_ptr(),
+                            &(lda.max(1) as i32),
+                            ipiv.as_ptr(),
+                            b.as_mut_ptr(),
+                            &(ldb.max(1) as i32),
+                            &mut info,
+                        )
+                    };
+                    info
+                }
+
+                fn getri(n: usize, a: &mut [$type], lda: usize, ipiv: &[i32]) -> i32 {
+                    let n_: *const i32 = &(n as i32);
+                    let lda: *const i32 = &(lda.max(1) as i32);
+                    let mut info = 0;
+
+                    // workspace query
+                    let mut optimal = <$type>::zero();
+                    unsafe { [<$getri _>](n_, a.as_mut_ptr(), lda, ipiv.as_ptr(), &mut optimal, &-1, &mut info) };
+                    if info != 0 {
+                        return info;
+                    }
+
+                    // the optimal size is returned in the real part for complex types
+                    let lwork = (optimal.re() as usize).max(n).max(1);
+                    let mut work = vec![<$type>::zero(); lwork];
+                    unsafe {
+                        [<$getri _>](n_, a.as_mut_ptr(), lda, ipiv.as_ptr(), work.as_mut_ptr(), &(lwork as i32), &mut info)
+                    };
+                    info
+                }
+            }
+        }
+    };
+}
+
+impl_macro!(
+    saxpy, sscal, snrm2, sasum, isamax, sgemv, sger, sgemm, sgetrf, sgetrs, sgetri, f32, f32
+);
+impl_macro!(
+    daxpy, dscal, dnrm2, dasum, idamax, dgemv, dger, dgemm, dgetrf, dgetrs, dgetri, f64, f64
+);
+impl_macro!(
+    caxpy,
+    cscal,
+    scnrm2,
+    scasum,
+    icamax,
+    cgemv,
+    cgeru,
+    cgemm,
+    cgetrf,
+    cgetrs,
+    cgetri,
+    Complex<f32>,
+    f32
+);
+impl_macro!(
+    zaxpy,
+    zscal,
+    dznrm2,
+    dzasum,
+    izamax,
+    zgemv,
+    zgeru,
+    zgemm,
+    zgetrf,
+    zgetrs,
+    zgetri,
+    Complex<f64>,
+    f64
+);
+
+macro_rules! impl_real {
+    ($prefix: ident, $type: ty) => {
+        paste::paste! {
+            #[link(name = "lapack")]
+            extern "C" {
+                fn [<$prefix potrf _>](
+                    uplo: *const i8, // character
+                    n: *const i32,   // integer
+                    a: *mut $type,   // array of $type. length = lda * n
+                    lda: *const i32, // integer
+                    info: *mut i32,  // integer
+                );
+                fn [<$prefix potrs _>](
+                    uplo: *const i8,  // character
+                    n: *const i32,    // integer
+                    nrhs: *const i32, // integer
+                    a: *const $type,  // array of $type. length = lda * n
+                    lda: *const i32,  // integer
+                    b: *mut $type,    // array of $type. length = ldb * nrhs
+                    ldb: *const i32,  // integer
+                    info: *mut i32,   // integer
+                );
+                fn [<$prefix geqrf _>](
+                    m: *const i32,     // integer
+                    n: *const i32,     // integer
+                    a: *mut $type,     // array of $type. length = lda * n
+                    lda: *const i32,   // integer
+                    tau: *mut $type,   // array of $type. length = min(m, n)
+                    work: *mut $type,  // array of $type. length = lwork
+                    lwork: *const i32, // integer
+                    info: *mut i32,    // integer
+                );
+                fn [<$prefix orgqr _>](
+                    m: *const i32,     // integer
+                    n: *const i32,     // integer
+                    k: *const i32,     // integer
+                    a: *mut $type,     // array of $type. length = lda * n
+                    lda: *const i32,   // integer
+                    tau: *const $type, // array of $type. length = k
+                    work: *mut $type,  // array of $type. length = lwork
+                    lwork: *const i32, // integer
+                    info: *mut i32,    // integer
+                );
+                fn [<$prefix ormqr _>](
+                    side: *const i8,   // character
+                    trans: *const i8,  // character
+                    m: *const i32,     // integer
+                    n: *const i32,     // integer
+                    k: *const i32,     // integer
+                    a: *const $type,   // array of $type. length = lda * k
+                    lda: *const i32,   // integer
+                    tau: *const $type, // array of $type. length = k
+                    c: *mut $type,     // array of $type. length = ldc * n
+                    ldc: *const i32,   // integer
+                    work: *mut $type,  // array of $type. length = lwork
+                    lwork: *const i32, // integer
+                    info: *mut i32,    // integer
+                );
+                fn [<$prefix gels _>](
+                    trans: *const i8,  // character
+                    m: *const i32,     // integer
+                    n: *const i32,     // integer
+                    nrhs: *const i32,  // integer
+                    a: *mut $type,     // array of $type. length = lda * n
+                    lda: *const i32,   // integer
+                    b: *mut $type,     // array of $type. length = ldb * nrhs
+                    ldb: *const i32,   // integer
+                    work: *mut $type,  // array of $type. length = lwork
+                    lwork: *const i32, // integer
+                    info: *mut i32,    // integer
+                );
+                fn [<$prefix gesdd _>](
+                    jobz: *const i8,   // character
+                    m: *const i32,     // integer
+                    n: *const i32,     // integer
+                    a: *mut $type,     // array of $type. length = lda * n
+                    lda: *const i32,   // integer
+                    s: *mut $type,     // array of $type. length = min(m, n)
+                    u: *mut $type,     // array of $type. length = ldu * ucol
+                    ldu: *const i32,   // integer
+                    vt: *mut $type,    // array of $type. length = ldvt * n
+                    ldvt: *const i32,  // integer
+                    work: *mut $type,  // array of $type. length = lwork
+                    lwork: *const i32, // integer
+                    iwork: *mut i32,   // array of integer. length = 8 * min(m, n)
+                    info: *mut i32,    // integer
+                );
+                fn [<$prefix gesvd _>](
+                    jobu: *const i8,   // character
+                    jobvt: *const i8,  // character
+                    m: *const i32,     // integer
+                    n: *const i32,     // integer
+                    a: *mut $type,     // array of $type. length = lda * n
+                    lda: *const i32,   // integer
+                    s: *mut $type,     // array of $type. length = min(m, n)
+                    u: *mut $type,     // array of $type. length = ldu * ucol
+                    ldu: *const i32,   // integer
+                    vt: *mut $type,    // array of $type. length = ldvt * n
+                    ldvt: *const i32,  // integer
+                    work: *mut $type,  // array of $type. length = lwork
+                    lwork: *const i32, // integer
+                    info: *mut i32,    // integer
+                );
+                fn [<$prefix syevd _>](
+                    jobz: *const i8,    // character
+                    uplo: *const i8,    // character
+                    n: *const i32,      // integer
+                    a: *mut $type,      // array of $type. length = lda * n
+                    lda: *const i32,    // integer
+                    w: *mut $type,      // array of $type. length = n
+                    work: *mut $type,   // array of $type. length = lwork
+                    lwork: *const i32,  // integer
+                    iwork: *mut i32,    // array of integer. length = liwork
+                    liwork: *const i32, // integer
+                    info: *mut i32,     // integer
+                );
+                fn [<$prefix syevr _>](
+                    jobz: *const i8,      // character
+                    range: *const i8,     // character
+                    uplo: *const i8,      // character
+                    n: *const i32,        // integer
+                    a: *mut $type,        // array of $type. length = lda * n
+                    lda: *const i32,      // integer
+                    vl: *const $type,     // $type
+                    vu: *const $type,     // $type
+                    il: *const i32,       // integer
+                    iu: *const i32,       // integer
+                    abstol: *const $type, // $type
+                    m: *mut i32,          // integer
+                    w: *mut $type,        // array of $type. length = n
+                    z: *mut $type,        // array of $type. length = ldz * n
+                    ldz: *const i32,      // integer
+                    isuppz: *mut i32,     // array of integer. length = 2 * n
+                    work: *mut $type,     // array of $type. length = lwork
+                    lwork: *const i32,    // integer
+                    iwork: *mut i32,      // array of integer. length = liwork
+                    liwork: *const i32,   // integer
+                    info: *mut i32,       // integer
+                );
+                fn [<$prefix geev _>](
+                    jobvl: *const i8,  // character
+                    jobvr: *const i8,  // character
+                    n: *const i32,     // integer
+                    a: *mut $type,     // array of $type. length = lda * n
+                    lda: *const i32,   // integer
+                    wr: *mut $type,    // array of $type. length = n
+                    wi: *mut $type,    // array of $type. length = n
+                    vl: *mut $type,    // array of $type. length = ldvl * n
+                    ldvl: *const i32,  // integer
+                    vr: *mut $type,    // array of $type. length = ldvr * n
+                    ldvr: *const i32,  // integer
+                    work: *mut $type,  // array of $type. length = lwork
+                    lwork: *const i32, // integer
+                    info: *mut i32,    // integer
+                );
+                fn [<$prefix gees _>](
+                    jobvs: *const i8,  // character
+                    sort: *const i8,   // character
+                    select: Option<extern "C" fn(*const $type, *const $type) -> i32>, // not referenced
+                    n: *const i32,     // integer
+                    a: *mut $type,     // array of $type. length = lda * n
+                    lda: *const i32,   // integer
+                    sdim: *mut i32,    // integer
+                    wr: *mut $type,    // array of $type. length = n
+                    wi: *mut $type,    // array of $type. length = n
+                    vs: *mut $type,    // array of $type. length = ldvs * n
+                    ldvs: *const i32,  // integer
+                    work: *mut $type,  // array of $type. length = lwork
+                    lwork: *const i32, // integer
+                    bwork: *mut i32,   // array of logical. not referenced
+                    info: *mut i32,    // integer
+                );
+                fn [<$prefix trsen _>](
+                    job: *const i8,     // character
+                    compq: *const i8,   // character
+                    select: *const i32, // array of logical. length = n
+                    n: *const i32,      // integer
+                    t: *mut $type,      // array of $type. length = ldt * n
+                    ldt: *const i32,    // integer
+                    q: *mut $type,      // array of $type. length = ldq * n
+                    ldq: *const i32,    // integer
+                    wr: *mut $type,     // array of $type. length = n
+                    wi: *mut $type,     // array of $type. length = n
+                    m: *mut i32,        // integer
+                    s: *mut $type,      // $type. not referenced
+                    sep: *mut $type,    // $type. not referenced
+                    work: *mut $type,   // array of $type. length = lwork
+                    lwork: *const i32,  // integer
+                    iwork: *mut i32,    // array of integer. length = liwork
+                    liwork: *const i32, // integer
+                    info: *mut i32,     // integer
+                );
+                fn [<$prefix gttrf _>](
+                    n: *const i32,   // integer
+                    dl: *mut $type,  // array of $type. length = n - 1
+                    d: *mut $type,   // array of $type. length = n
+                    du: *mut $type,  // array of $type. length = n - 1
+                    du2: *mut $type, // array of $type. length = n - 2
+                    ipiv: *mut i32,  // array of integer. length = n
+                    info: *mut i32,  // integer
+                );
+                fn [<$prefix gttrs _>](
+                    trans: *const i8,  // character
+                    n: *const i32,     // integer
+                    nrhs: *const i32,  // integer
+                    dl: *const $type,  // array of $type. length = n - 1
+                    d: *const $type,   // array of $type. length = n
+                    du: *const $type,  // array of $type. length = n - 1
+                    du2: *const $type, // array of $type. length = n - 2
+                    ipiv: *const i32,  // array of integer. length = n
+                    b: *mut $type,     // array of $type. length = ldb * nrhs
+                    ldb: *const i32,   // integer
+                    info: *mut i32,    // integer
+                );
+                fn [<$prefix gtsv _>](
+                    n: *const i32,    // integer
+                    nrhs: *const i32, // integer
+                    dl: *mut $type,   // array of $type. length = n - 1
+                    d: *mut $type,    // array of $type. length = n
+                    du: *mut $type,   // array of $type. length = n - 1
+                    b: *mut $type,    // array of $type. length = ldb * nrhs
+                    ldb: *const i32,  // integer
+                    info: *mut i32,   // integer
+                );
+            }
+
+            impl RealBackend<$type> for Lapack {
+                fn potrf(uplo: Uplo, n: usize, a: &mut [$type], lda: usize) -> i32 {
+                    let mut info = 0;
+                    unsafe {
+                        [<$prefix potrf _>](&uplo.as_lapack_char(), &(n as i32), a.as_mut_ptr(), &(lda.max(1) as i32), &mut info)
+                    };
+                    info
+                }
+
+                fn potrs(uplo: Uplo, n: usize, nrhs: usize, a: &[$type], lda: usize, b: &mut [$type], ldb: usize) -> i32 {
+                    let mut info = 0;
+                    unsafe {
+                        [<$prefix potrs _>](
+                            &uplo.as_lapack_char(),
+                            &(n as i32),
+                            &(nrhs as i32),
+                            a.as_ptr(),
+                            &(lda.max(1) as i32),
+                            b.as_mut_ptr(),
+                            &(ldb.max(1) as i32),
+                            &mut info,
+                        )
+                    };
+                    info
+                }
+
+                fn geqrf(m: usize, n: usize, a: &mut [$type], lda: usize, tau: &mut [$type]) -> i32 {
+                    let (m_, n_): (*const i32, *const i32) = (&(m as i32), &(n as i32));
+                    let lda: *const i32 = &(lda.max(1) as i32);
+                    let mut info = 0;
+
+                    // workspace query
+                    let mut optimal: $type = 0.;
+                    unsafe { [<$prefix geqrf _>](m_, n_, a.as_mut_ptr(), lda, tau.as_mut_ptr(), &mut optimal, &-1, &mut info) };
+                    if info != 0 {
+                        return info;
+                    }
+
+                    let lwork = (optimal as usize).max(n).max(1);
+                    let mut work = vec![0. as $type; lwork];
+                    unsafe {
+                        [<$prefix geqrf _>](m_, n_, a.as_mut_ptr(), lda, tau.as_mut_ptr(), work.as_mut_ptr(), &(lwork as i32), &mut info)
+                    };
+                    info
+                }
+
+                fn orgqr(m: usize, n: usize, k: usize, a: &mut [$type], lda: usize, tau: &[$type]) -> i32 {
+                    let (m_, n_, k_): (*const i32, *const i32, *const i32) = (&(m as i32), &(n as i32), &(k as i32));
+                    let lda: *const i32 = &(lda.max(1) as i32);
+                    let mut info = 0;
+
+                    // workspace query
+                    let mut optimal: $type = 0.;
+                    unsafe { [<$prefix orgqr _>](m_, n_, k_, a.as_mut_ptr(), lda, tau.as_ptr(), &mut optimal, &-1, &mut info) };
+                    if info != 0 {
+                        return info;
+                    }
+
+                    let lwork = (optimal as usize).max(n).max(1);
+                    let mut work = vec![0. as $type; lwork];
+                    unsafe {
+                        [<$prefix orgqr _>](m_, n_, k_, a.as_mut_ptr(), lda, tau.as_ptr(), work.as_mut_ptr(), &(lwork as i32), &mut info)
+                    };
+                    info
+                }
+
+                fn ormqr(
+                    trans: Transpose,
+                    m: usize,
+                    n: usize,
+                    k: usize,
+                    a: &[$type],
+                    lda: usize,
+                    tau: &[$type],
+                    c: &mut [$type],
+                    ldc: usize,
+                ) -> i32 {
+                    let side: *const i8 = &('L' as i8);
+                    let trans: *const i8 = &trans.as_lapack_char();
+                    let (m_, n_, k_): (*const i32, *const i32, *const i32) = (&(m as i32), &(n as i32), &(k as i32));
+                    let lda: *const i32 = &(lda.max(1) as i32);
+                    let ldc: *const i32 = &(ldc.max(1) as i32);
+                    let mut info = 0;
+
+                    // workspace query
+                    let mut optimal: $type = 0.;
+                    unsafe {
+                        [<$prefix ormqr _>](side, trans, m_, n_, k_, a.as_ptr(), lda, tau.as_ptr(), c.as_mut_ptr(), ldc, &mut optimal, &-1, &mut info)
+                    };
+                    if info != 0 {
+                        return info;
+                    }
+
+                    let lwork = (optimal as usize).max(n).max(1);
+                    let mut work = vec![0. as $type; lwork];
+                    unsafe {
+                        [<$prefix ormqr _>](side, trans, m_, n_, k_, a.as_ptr(), lda, tau.as_ptr(), c.as_mut_ptr(), ldc, work.as_mut_ptr(), &(lwork as i32), &mut info)
+                    };
+                    info
+                }
+
+                fn gels(m: usize, n: usize, nrhs: usize, a: &mut [$type], lda: usize, b: &mut [$type], ldb: usize) -> i32 {
+                    let trans: *const i8 = &('N' as i8);
+                    let (m_, n_, nrhs_): (*const i32, *const i32, *const i32) = (&(m as i32), &(n as i32), &(nrhs as i32));
+                    let lda: *const i32 = &(lda.max(1) as i32);
+                    let ldb: *const i32 = &(ldb.max(1) as i32);
+                    let mut info = 0;
+
+                    // workspace query
+                    let mut optimal: $type = 0.;
+                    unsafe {
+                        [<$prefix gels _>](trans, m_, n_, nrhs_, a.as_mut_ptr(), lda, b.as_mut_ptr(), ldb, &mut optimal, &-1, &mut info)
+                    };
+                    if info != 0 {
+                        return info;
+                    }
+
+                    let lwork = (optimal as usize).max(m.min(n) + m.max(n).max(nrhs)).max(1);
+                    let mut work = vec![0. as $type; lwork];
+                    unsafe {
+                        [<$prefix gels _>](trans, m_, n_, nrhs_, a.as_mut_ptr(), lda, b.as_mut_ptr(), ldb, work.as_mut_ptr(), &(lwork as i32), &mut info)
+                    };
+                    info
+                }
+
+                fn gesdd(
+                    job: char,
+                    m: usize,
+                    n: usize,
+                    a: &mut [$type],
+                    lda: usize,
+                    s: &mut [$type],
+                    u: &mut [$type],
+                    ldu: usize,
+                    vt: &mut [$type],
+                    ldvt: usize,
+                ) -> i32 {
+                    let job: *const i8 = &(job as i8);
+                    let (m_, n_): (*const i32, *const i32) = (&(m as i32), &(n as i32));
+                    let lda: *const i32 = &(lda.max(1) as i32);
+                    let ldu: *const i32 = &(ldu.max(1) as i32);
+                    let ldvt: *const i32 = &(ldvt.max(1) as i32);
+                    let mut iwork = vec![0; 8 * m.min(n).max(1)];
+                    let mut info = 0;
+
+                    // workspace query
+                    let mut optimal: $type = 0.;
+                    unsafe {
+                        [<$prefix gesdd _>](
+                            job, m_, n_, a.as_mut_ptr(), lda, s.as_mut_ptr(), u.as_mut_ptr(), ldu, vt.as_mut_ptr(), ldvt,
+                            &mut optimal, &-1, iwork.as_mut_ptr(), &mut info,
+                        )
+                    };
+                    if info != 0 {
+                        return info;
+                    }
+
+                    let lwork = (optimal as usize).max(1);
+                    let mut work = vec![0. as $type; lwork];
+                    unsafe {
+                        [<$prefix gesdd _>](
+                            job, m_, n_, a.as_mut_ptr(), lda, s.as_mut_ptr(), u.as_mut_ptr(), ldu, vt.as_mut_ptr(), ldvt,
+                            work.as_mut_ptr(), &(lwork as i32), iwork.as_mut_ptr(), &mut info,
+                        )
+                    };
+                    info
+                }
+
+                fn gesvd(
+                    job: char,
+                    m: usize,
+                    n: usize,
+                    a: &mut [$type],
+                    lda: usize,
+                    s: &mut [$type],
+                    u: &mut [$type],
+                    ldu: usize,
+                    vt: &mut [$type],
+                    ldvt: usize,
+                ) -> i32 {
+                    let job: *const i8 = &(job as i8);
+                    let (m_, n_): (*const i32, *const i32) = (&(m as i32), &(n as i32));
+                    let lda: *const i32 = &(lda.max(1) as i32);
+                    let ldu: *const i32 = &(ldu.max(1) as i32);
+                    let ldvt: *const i32 = &(ldvt.max(1) as i32);
+                    let mut info = 0;
+
+                    // workspace query
+                    let mut optimal: $type = 0.;
+                    unsafe {
+                        [<$prefix gesvd _>](
+                            job, job, m_, n_, a.as_mut_ptr(), lda, s.as_mut_ptr(), u.as_mut_ptr(), ldu, vt.as_mut_ptr(), ldvt,
+                            &mut optimal, &-1, &mut info,
+                        )
+                    };
+                    if info != 0 {
+                        return info;
+                    }
+
+                    let lwork = (optimal as usize).max(5 * m.min(n)).max(1);
+                    let mut work = vec![0. as $type; lwork];
+                    unsafe {
+                        [<$prefix gesvd _>](
+                            job, job, m_, n_, a.as_mut_ptr(), lda, s.as_mut_ptr(), u.as_mut_ptr(), ldu, vt.as_mut_ptr(), ldvt,
+                            work.as_mut_ptr(), &(lwork as i32), &mut info,
+                        )
+                    };
+                    info
+                }
+
+                fn syevd(job: char, uplo: Uplo, n: usize, a: &mut [$type], lda: usize, w: &mut [$type]) -> i32 {
+                    let jobz: *const i8 = &(job as i8);
+                    let uplo: *const i8 = &uplo.as_lapack_char();
+                    let n_: *const i32 = &(n as i32);
+                    let lda: *const i32 = &(lda.max(1) as i32);
+                    let mut info = 0;
+
+                    // workspace query
+                    let mut optimal: $type = 0.;
+                    let mut optimal_i: i32 = 0;
+                    unsafe {
+                        [<$prefix syevd _>](jobz, uplo, n_, a.as_mut_ptr(), lda, w.as_mut_ptr(), &mut optimal, &-1, &mut optimal_i, &-1, &mut info)
+                    };
+                    if info != 0 {
+                        return info;
+                    }
+
+                    let lwork = (optimal as usize).max(1);
+                    let liwork = (optimal_i as usize).max(1);
+                    let mut work = vec![0. as $type; lwork];
+                    let mut iwork = vec![0; liwork];
+                    unsafe {
+                        [<$prefix syevd _>](
+                            jobz, uplo, n_, a.as_mut_ptr(), lda, w.as_mut_ptr(),
+                            work.as_mut_ptr(), &(lwork as i32),
+                            iwork.as_mut_ptr(), &(liwork as i32),
+                            &mut info,
+                        )
+                    };
+                    info
+                }
+
+                fn syevr(
+                    job: char,
+                    range: char,
+                    uplo: Uplo,
+                    n: usize,
+                    a: &mut [$type],
+                    lda: usize,
+                    vl: $type,
+                    vu: $type,
+                    il: usize,
+                    iu: usize,
+                    m: &mut usize,
+                    w: &mut [$type],
+                    z: &mut [$type],
+                    ldz: usize,
+                ) -> i32 {
+                    let jobz: *const i8 = &(job as i8);
+                    let range: *const i8 = &(range as i8);
+                    let uplo: *const i8 = &uplo.as_lapack_char();
+                    let n_: *const i32 = &(n as i32);
+                    let lda: *const i32 = &(lda.max(1) as i32);
+                    let (il, iu): (*const i32, *const i32) = (&(il as i32), &(iu as i32));
+                    let ldz: *const i32 = &(ldz.max(1) as i32);
+                    let mut isuppz = vec![0; 2 * n.max(1)];
+                    let mut found = 0;
+                    let mut info = 0;
+
+                    // workspace query
+                    let mut optimal: $type = 0.;
+                    let mut optimal_i: i32 = 0;
+                    unsafe {
+                        [<$prefix syevr _>](
+                            jobz, range, uplo, n_, a.as_mut_ptr(), lda, &vl, &vu, il, iu, &(0. as $type), &mut found,
+                            w.as_mut_ptr(), z.as_mut_ptr(), ldz, isuppz.as_mut_ptr(),
+                            &mut optimal, &-1, &mut optimal_i, &-1,
+                            &mut info,
+                        )
+                    };
+                    if info != 0 {
+                        return info;
+                    }
+
+                    let lwork = (optimal as usize).max(26 * n).max(1);
+                    let liwork = (optimal_i as usize).max(10 * n).max(1);
+                    let mut work = vec![0. as $type; lwork];
+                    let mut iwork = vec![0; liwork];
+                    unsafe {
+                        [<$prefix syevr _>](
+                            jobz, range, uplo, n_, a.as_mut_ptr(), lda, &vl, &vu, il, iu, &(0. as $type), &mut found,
+                            w.as_mut_ptr(), z.as_mut_ptr(), ldz, isuppz.as_mut_ptr(),
+                            work.as_mut_ptr(), &(lwork as i32), iwork.as_mut_ptr(), &(liwork as i32),
+                            &mut info,
+                        )
+                    };
+                    *m = found as usize;
+                    info
+                }
+
+                fn geev(
+                    jobvl: char,
+                    jobvr: char,
+                    n: usize,
+                    a: &mut [$type],
+                    lda: usize,
+                    wr: &mut [$type],
+                    wi: &mut [$type],
+                    vl: &mut [$type],
+                    ldvl: usize,
+                    vr: &mut [$type],
+                    ldvr: usize,
+                ) -> i32 {
+                    let jobvl: *const i8 = &(jobvl as i8);
+                    let jobvr: *const i8 = &(jobvr as i8);
+                    let n_: *const i32 = &(n as i32);
+                    let lda: *const i32 = &(lda.max(1) as i32);
+                    let ldvl: *const i32 = &(ldvl.max(1) as i32);
+                    let ldvr: *const i32 = &(ldvr.max(1) as i32);
+                    let mut info = 0;
+
+                    // workspace query
+                    let mut optimal: $type = 0.;
+                    unsafe {
+                        [<$prefix geev _>](
+                            jobvl, jobvr, n_, a.as_mut_ptr(), lda, wr.as_mut_ptr(), wi.as_mut_ptr(),
+                            vl.as_mut_ptr(), ldvl, vr.as_mut_ptr(), ldvr, &mut optimal, &-1,
+                            &mut info,
+                        )
+                    };
+                    if info != 0 {
+                        return info;
+                    }
+
+                    let lwork = (optimal as usize).max(4 * n).max(1);
+                    let mut work = vec![0. as $type; lwork];
+                    unsafe {
+                        [<$prefix geev _>](
+                            jobvl, jobvr, n_, a.as_mut_ptr(), lda, wr.as_mut_ptr(), wi.as_mut_ptr(),
+                            vl.as_mut_ptr(), ldvl, vr.as_mut_ptr(), ldvr, work.as_mut_ptr(), &(lwork as i32),
+                            &mut info,
+                        )
+                    };
+                    info
+                }
+
+                fn gees(n: usize, a: &mut [$type], lda: usize, wr: &mut [$type], wi: &mut [$type], vs: &mut [$type], ldvs: usize) -> i32 {
+                    let jobvs: *const i8 = &('V' as i8);
+                    let sort: *const i8 = &('N' as i8);
+                    let n_: *const i32 = &(n as i32);
+                    let lda: *const i32 = &(lda.max(1) as i32);
+                    let ldvs: *const i32 = &(ldvs.max(1) as i32);
+                    let mut sdim = 0;
+                    let mut bwork = [0; 1];
+                    let mut info = 0;
+
+                    // workspace query
+                    let mut optimal: $type = 0.;
+                    unsafe {
+                        [<$prefix gees _>](
+                            jobvs, sort, None, n_, a.as_mut_ptr(), lda, &mut sdim, wr.as_mut_ptr(), wi.as_mut_ptr(),
+                            vs.as_mut_ptr(), ldvs, &mut optimal, &-1, bwork.as_mut_ptr(),
+                            &mut info,
+                        )
+                    };
+                    if info != 0 {
+                        return info;
+                    }
+
+                    let lwork = (optimal as usize).max(3 * n).max(1);
+                    let mut work = vec![0. as $type; lwork];
+                    unsafe {
+                        [<$prefix gees _>](
+                            jobvs, sort, None, n_, a.as_mut_ptr(), lda, &mut sdim, wr.as_mut_ptr(), wi.as_mut_ptr(),
+                            vs.as_mut_ptr(), ldvs, work.as_mut_ptr(), &(lwork as i32), bwork.as_mut_ptr(),
+                            &mut info,
+                        )
+                    };
+                    info
+                }
+
+                fn trsen(
+                    select: &[bool],
+                    n: usize,
+                    t: &mut [$type],
+                    ldt: usize,
+                    q: &mut [$type],
+                    ldq: usize,
+                    wr: &mut [$type],
+                    wi: &mut [$type],
+                    m: &mut usize,
+                ) -> i32 {
+                    let select: Vec<i32> = select.iter().map(|s| *s as i32).collect();
+                    let job: *const i8 = &('N' as i8);
+                    let compq: *const i8 = &('V' as i8);
+                    let (mut s, mut sep): ($type, $type) = (0., 0.);
+                    let mut selected = 0;
+                    let mut info = 0;
+
+                    let lwork = n.max(1);
+                    let mut work = vec![0. as $type; lwork];
+                    let mut iwork = [0; 1];
+                    unsafe {
+                        [<$prefix trsen _>](
+                            job, compq, select.as_ptr(), &(n as i32), t.as_mut_ptr(), &(ldt.max(1) as i32),
+                            q.as_mut_ptr(), &(ldq.max(1) as i32), wr.as_mut_ptr(), wi.as_mut_ptr(), &mut selected,
+                            &mut s, &mut sep, work.as_mut_ptr(), &(lwork as i32), iwork.as_mut_ptr(), &1,
+                            &mut info,
+                        )
+                    };
+                    *m = selected as usize;
+                    info
+                }
+
+                fn gttrf(n: usize, dl: &mut [$type], d: &mut [$type], du: &mut [$type], du2: &mut [$type], ipiv: &mut [i32]) -> i32 {
+                    let mut info = 0;
+                    unsafe {
+                        [<$prefix gttrf _>](
+                            &(n as i32),
+                            dl.as_mut_ptr(),
+                            d.as_mut_ptr(),
+                            du.as_mut_ptr(),
+                            du2.as_mut_ptr(),
+                            ipiv.as_mut_ptr(),
+                            &mut info,
+                        )
+                    };
+                    info
+                }
+
+                fn gttrs(
+                    n: usize,
+                    nrhs: usize,
+                    dl: &[$type],
+                    d: &[$type],
+                    du: &[$type],
+                    du2: &[$type],
+                    ipiv: &[i32],
+                    b: &mut [$type],
+                    ldb: usize,
+                ) -> i32 {
+                    let mut info = 0;
+                    unsafe {
+                        [<$prefix gttrs _>](
+                            &('N' as i8),
+                            &(n as i32),
+                            &(nrhs as i32),
+                            dl.as_ptr(),
+                            d.as_ptr(),
+                            du.as_ptr(),
+                            du2.as_ptr(),
+                            ipiv.as_ptr(),
+                            b.as_mut_ptr(),
+                            &(ldb.max(1) as i32),
+                            &mut info,
+                        )
+                    };
+                    info
+                }
+
+                fn gtsv(n: usize, nrhs: usize, dl: &mut [$type], d: &mut [$type], du: &mut [$type], b: &mut [$type], ldb: usize) -> i32 {
+                    let mut info = 0;
+                    unsafe {
+                        [<$prefix gtsv _>](
+                            &(n as i32),
+                            &(nrhs as i32),
+                            dl.as_mut_ptr(),
+                            d.as_mut_ptr(),
+                            du.as_mut_ptr(),
+                            b.as_mut_ptr(),
+                            &(ldb.max(1) as i32),
+                            &mut info,
+                        )
+                    };
+                    info
+                }
+            }
+        }
+    };
+}
+
+impl_real!(s, f32);
+impl_real!(d, f64);
@@ -0,0 +1,853 @@
+use super::{Backend, BlasScalar, RealBackend, Transpose, Uplo};
+
+use num_complex::Complex;
+use num_traits::{One, Zero};
+
+mod cholesky;
+mod eigen;
+mod qr;
+mod svd;
+mod tridiagonal;
+
+// pure Rust implementation. straightforward loops, no blocking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Native;
+
+macro_rules! impl_macro {
+    ($type: ty, $real: ty) => {
+        impl Backend<$type> for Native {
+            fn axpy(
+                n: usize,
+                alpha: $type,
+                x: &[$type],
+                incx: usize,
+                y: &mut [$type],
+                incy: usize,
+            ) {
+                for i in 0..n {
+                    y[i * incy] += alpha * x[i * incx];
+                }
+            }
+
+            fn scal(n: usize, alpha: $type, x: &mut [$type], incx: usize) {
+                for i in 0..n {
+                    x[i * incx] *= alpha;
+                }
+            }
+
+            fn dot(n: usize, x: &[$type], incx: usize, y: &[$type], incy: usize) -> $type {
+                (0..n).map(|i| x[i * incx] * y[i * incy]).sum()
+            }
+
+            fn dotc(n: usize, x: &[$type], incx: usize, y: &[$type], incy: usize) -> $type {
+                (0..n).map(|i| x[i * incx].conj() * y[i * incy]).sum()
+            }
+
+            fn nrm2(n: usize, x: &[$type], incx: usize) -> $real {
+                // scale * sqrt(ssq) == sqrt(sum of |x_i|^2), as in the reference BLAS.
+                // real and imaginary parts are accumulated separately.
+                let mut scale: $real = 0.;
+                let mut ssq: $real = 1.;
+
+                for i in 0..n {
+                    for part in [x[i * incx].re(), x[i * incx].im()] {
+                        let value = part.abs();
+                        if value == 0. {
+                            continue;
+                        }
+
+                        if scale < value {
+                            ssq = 1. + ssq * (scale / value) * (scale / value);
+                            scale = value;
+                        } else {
+                            ssq += (value / scale) * (value / scale);
+                        }
+                    }
+                }
+
+                scale * ssq.sqrt()
+            }
+
+            fn asum(n: usize, x: &[$type], incx: usize) -> $real {
+                (0..n).map(|i| x[i * incx].abs1()).sum()
+            }
+
+            fn iamax(n: usize, x: &[$type], incx: usize) -> usize {
+                if n == 0 {
+                    return 0;
+                }
+
+                let mut p = 0;
+                for i in 1..n {
+                    if x[i * incx].abs1() > x[p * incx].abs1() {
+                        p = i;
+                    }
+                }
+                p + 1
+            }
+
+            fn gemv(
+                trans: Transpose,
+                m: usize,
+                n: usize,
+                alpha: $type,
+                a: &[$type],
+                lda: usize,
+                x: &[$type],
+                incx: usize,
+                beta: $type,
+                y: &mut [$type],
+                incy: usize,
+            ) {
+                let (len_x, len_y) = match trans {
+                    Transpose::No => (n, m),
+                    _ => (m, n),
+                };
+                let op_a = |row: usize, col: usize| match trans {
+                    Transpose::No => a[row + col * lda],
+                    Transpose::Trans => a[col + row * lda],
+                    Transpose::ConjTrans => a[col + row * lda].conj(),
+                };
+
+                for i in 0..len_y {
+                    let sum: $type = (0..len_x).map(|j| op_a(i, j) * x[j * incx]).sum();
+                    let dest = &mut y[i * incy];
+                    // as in BLAS, y is not read when beta is zero
+                    *dest = if beta.is_zero() {
+                        alpha * sum
+                    } else {
+                        alpha * sum + beta * *dest
+                    };
+                }
+            }
+
+            fn ger(
+                m: usize,
+                n: usize,
+                alpha: $type,
+                x: &[$type],
+                incx: usize,
+                y: &[$type],
+                incy: usize,
+                a: &mut [$type],
+                lda: usize,
+            ) {
+                for col in 0..n {
+                    let factor = alpha * y[col * incy];
+                    for row in 0..m {
+                        a[row + col * lda] += x[row * incx] * factor;
+                    }
+                }
+            }
+
+            fn gemm(
+                trans_a: Transpose,
+                trans_b: Transpose,
+                m: usize,
+                n: usize,
+                k: usize,
+                alpha: $type,
+                a: &[$type],
+                lda: usize,
+                b: &[$type],
+                ldb: usize,
+                beta: $type,
+                c: &mut [$type],
+                ldc: usize,
+            ) {
+                let op_a = |row: usize, col: usize| match trans_a {
+                    Transpose::No => a[row + col * lda],
+                    Transpose::Trans => a[col + row * lda],
+                    Transpose::ConjTrans => a[col + row * lda].conj(),
+                };
+                let op_b = |row: usize, col: usize| match trans_b {
+                    Transpose::No => b[row + col * ldb],
+                    Transpose::Trans => b[col + row * ldb],
+                    Transpose::ConjTrans => b[col + row * ldb].conj(),
+                };
+
+                for col in 0..n {
+                    for row in 0..m {
+                        let sum: $type = (0..k).map(|l| op_a(row, l) * op_b(l, col)).sum();
+                        let dest = &mut c[row + col * ldc];
+                        // as in BLAS, C is not read when beta is zero
+                        *dest = if beta.is_zero() {
+                            alpha * sum
+                        } else {
+                            alpha * sum + beta * *dest
+                        };
+                    }
+                }
+            }
+
+            fn getrf(m: usize, n: usize, a: &mut [$type], lda: usize, ipiv: &mut [i32]) -> i32 {
+                let mut info = 0;
+
+                for j in 0..m.min(n) {
+                    // the first element of the largest abs1, as i?amax
+                    let mut p = j;
+                    for i in j + 1..m {
+                        if a[i + j * lda].abs1() > a[p + j * lda].abs1() {
+                            p = i;
+                        }
+                    }
+                    ipiv[j] = p as i32 + 1;
+
+                    if a[p + j * lda].is_zero() {
+                        if info == 0 {
+                            info = j as i32 + 1;
+                        }
+                        continue;
+                    }
+
+                    if p != j {
+                        for col in 0..n {
+                            a.swap(j + col * lda, p + col * lda);
+                        }
+                    }
+
+                    let pivot = a[j + j * lda];
+                    for i in j + 1..m {
+                        a[i + j * lda] /= pivot;
+                    }
+
+                    for col in j + 1..n {
+                        let u = a[j + col * lda];
+                        for i in j + 1..m {
+                            a[i + col * lda] -= a[i + j * lda] * u;
+                        }
+                    }
+                }
+
+                info
+            }
+
+            fn getrs(
+                trans: Transpose,
+                n: usize,
+                nrhs: usize,
+                a: &[$type],
+                lda: usize,
+                ipiv: &[i32],
+                b: &mut [$type],
+                ldb: usize,
+            ) -> i32 {
+                // element (row, col) of A^T or A^H
+                let op_a = |row: usize, col: usize| match trans {
+                    Transpose::ConjTrans => a[col + row * lda].conj(),
+                    _ => a[col + row * lda],
+                };
+
+                for col in 0..nrhs {
+                    let x = &mut b[col * ldb..col * ldb + n];
+
+                    match trans {
+                        Transpose::No => {
+                            for i in 0..n {
+                                x.swap(i, ipiv[i] as usize - 1);
+                            }
+                            // L is unit lower triangular
+                            for i in 0..n {
+                                for k in 0..i {
+                                    x[i] -= a[i + k * lda] * x[k];
+                                }
+                            }
+                            for i in (0..n).rev() {
+                                for k in i + 1..n {
+                                    x[i] -= a[i + k * lda] * x[k];
+                                }
+                                x[i] /= a[i + i * lda];
+                            }
+                        }
+                        _ => {
+                            for i in 0..n {
+                                for k in 0..i {
+                                    x[i] -= op_a(i, k) * x[k];
+                                }
+                                x[i] /= op_a(i, i);
+                            }
+                            for i in (0..n).rev() {
+                                for k in i + 1..n {
+                                    x[i] -= op_a(i, k) * x[k];
+                                }
+                            }
+                            for i in (0..n).rev() {
+                                x.swap(i, ipiv[i] as usize - 1);
+                            }
+                        }
+                    }
+                }
+
+                0
+            }
+
+            fn getri(n: usize, a: &mut [$type], lda: usize, ipiv: &[i32]) -> i32 {
+                if let Some(i) = (0..n).find(|&i| a[i + i * lda].is_zero()) {
+                    return i as i32 + 1;
+                }
+
+                // solve A * X = I column by column
+                let mut inv = vec![<$type>::zero(); n * n];
+                for i in 0..n {
+                    inv[i + i * n] = <$type>::one();
+                }
+                Self::getrs(Transpose::No, n, n, a, lda, ipiv, &mut inv, n);
+
+                for col in 0..n {
+                    a[col * lda..col * lda + n].copy_from_slice(&inv[col * n..col * n + n]);
+                }
+
+                0
+            }
+        }
+    };
+}
+
+impl_macro!(f32, f32);
+impl_macro!(f64, f64);
+impl_macro!(Complex<f32>, f32);
+impl_macro!(Complex<f64>, f64);
+
+macro_rules! impl_real {
+    ($type: ty) => {
+        impl RealBackend<$type> for Native {
+            fn potrf(uplo: Uplo, n: usize, a: &mut [$type], lda: usize) -> i32 {
+                cholesky::potrf(uplo, n, a, lda)
+            }
+
+            fn potrs(
+                uplo: Uplo,
+                n: usize,
+                nrhs: usize,
+                a: &[$type],
+                lda: usize,
+                b: &mut [$type],
+                ldb: usize,
+            ) -> i32 {
+                cholesky::potrs(uplo, n, nrhs, a, lda, b, ldb)
+            }
+
+            fn geqrf(m: usize, n: usize, a: &mut [$type], lda: usize, tau: &mut [$type]) -> i32 {
+                qr::geqrf(m, n, a, lda, tau)
+            }
+
+            fn orgqr(
+                m: usize,
+                n: usize,
+                k: usize,
+                a: &mut [$type],
+                lda: usize,
+                tau: &[$type],
+            ) -> i32 {
+                qr::orgqr(m, n, k, a, lda, tau)
+            }
+
+            fn ormqr(
+                trans: Transpose,
+                m: usize,
+                n: usize,
+                k: usize,
+                a: &[$type],
+                lda: usize,
+                tau: &[$type],
+                c: &mut [$type],
+                ldc: usize,
+            ) -> i32 {
+                qr::ormqr(trans, m, n, k, a, lda, tau, c, ldc)
+            }
+
+            fn gels(
+                m: usize,
+                n: usize,
+                nrhs: usize,
+                a: &mut [$type],
+                lda: usize,
+                b: &mut [$type],
+                ldb: usize,
+            ) -> i32 {
+                qr::gels(m, n, nrhs, a, lda, b, ldb)
+            }
+
+            fn gesdd(
+                job: char,
+                m: usize,
+                n: usize,
+                a: &mut [$type],
+                lda: usize,
+                s: &mut [$type],
+                u: &mut [$type],
+                ldu: usize,
+                vt: &mut [$type],
+                ldvt: usize,
+            ) -> i32 {
+                svd::gesdd(job, m, n, a, lda, s, u, ldu, vt, ldvt)
+            }
+
+            fn gesvd(
+                job: char,
+                m: usize,
+                n: usize,
+                a: &mut [$type],
+                lda: usize,
+                s: &mut [$type],
+                u: &mut [$type],
+                ldu: usize,
+                vt: &mut [$type],
+                ldvt: usize,
+            ) -> i32 {
+                svd::gesvd(job, m, n, a, lda, s, u, ldu, vt, ldvt)
+            }
+
+            fn syevd(
+                job: char,
+                uplo: Uplo,
+                n: usize,
+                a: &mut [$type],
+                lda: usize,
+                w: &mut [$type],
+            ) -> i32 {
+                eigen::syevd(job, uplo, n, a, lda, w)
+            }
+
+            fn syevr(
+                job: char,
+                range: char,
+                uplo: Uplo,
+                n: usize,
+                a: &mut [$type],
+                lda: usize,
+                vl: $type,
+                vu: $type,
+                il: usize,
+                iu: usize,
+                m: &mut usize,
+                w: &mut [$type],
+                z: &mut [$type],
+                ldz: usize,
+            ) -> i32 {
+                eigen::syevr(job, range, uplo, n, a, lda, vl, vu, il, iu, m, w, z, ldz)
+            }
+
+            fn geev(
+                jobvl: char,
+                jobvr: char,
+                n: usize,
+                a: &mut [$type],
+                lda: usize,
+                wr: &mut [$type],
+                wi: &mut [$type],
+                vl: &mut [$type],
+                ldvl: usize,
+                vr: &mut [$type],
+                ldvr: usize,
+            ) -> i32 {
+                eigen::geev(jobvl, jobvr, n, a, lda, wr, wi, vl, ldvl, vr, ldvr)
+            }
+
+            fn gees(
+                n: usize,
+                a: &mut [$type],
+                lda: usize,
+                wr: &mut [$type],
+                wi: &mut [$type],
+                vs: &mut [$type],
+                ldvs: usize,
+            ) -> i32 {
+                eigen::gees(n, a, lda, wr, wi, vs, ldvs)
+            }
+
+            fn trsen(
+                select: &[bool],
+                n: usize,
+                t: &mut [$type],
+                ldt: usize,
+                q: &mut [$type],
+                ldq: usize,
+                wr: &mut [$type],
+                wi: &mut [$type],
+                m: &mut usize,
+            ) -> i32 {
+                eigen::trsen(select, n, t, ldt, q, ldq, wr, wi, m)
+            }
+
+            fn gttrf(
+                n: usize,
+                dl: &mut [$type],
+                d: &mut [$type],
+                du: &mut [$type],
+                du2: &mut [$type],
+                ipiv: &mut [i32],
+            ) -> i32 {
+                tridiagonal::gttrf(n, dl, d, du, du2, ipiv)
+            }
+
+            fn gttrs(
+                n: usize,
+                nrhs: usize,
+                dl: &[$type],
+                d: &[$type],
+                du: &[$type],
+                du2: &[$type],
+                ipiv: &[i32],
+                b: &mut [$type],
+                ldb: usize,
+            ) -> i32 {
+                tridiagonal::gttrs(n, nrhs, dl, d, du, du2, ipiv, b, ldb)
+            }
+
+            fn gtsv(
+                n: usize,
+                nrhs: usize,
+                dl: &mut [$type],
+                d: &mut [$type],
+                du: &mut [$type],
+                b: &mut [$type],
+                ldb: usize,
+            ) -> i32 {
+                tridiagonal::gtsv(n, nrhs, dl, d, du, b, ldb)
+            }
+        }
+    };
+}
+
+impl_real!(f32);
+impl_real!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gemm_transposed() {
+        // A = [[1, 2], [3, 4]], B = [[5, 6], [7, 8]] (column-major)
+        let a = [1., 3., 2., 4.];
+        let b = [5., 7., 6., 8.];
+        let mut c = [0.; 4];
+
+        <Native as Backend<f64>>::gemm(
+            Transpose::Trans,
+            Transpose::No,
+            2,
+            2,
+            2,
+            1.,
+            &a,
+            2,
+            &b,
+            2,
+            0.,
+            &mut c,
+            2,
+        );
+
+        // A^T * B = [[26, 30], [38, 44]]
+        assert_eq!(c, [26., 38., 30., 44.]);
+    }
+
+    #[test]
+    fn level1() {
+        let x = [3., -4., 0., 4.];
+
+        assert_eq!(
+            <Native as Backend<f64>>::dot(2, &x, 1, &[1., 2., 3., 4.], 2),
+            -9.
+        );
+        assert_eq!(<Native as Backend<f64>>::nrm2(2, &x, 1), 5.);
+        assert_eq!(<Native as Backend<f64>>::asum(4, &x, 1), 11.);
+        // the first one wins a tie
+        assert_eq!(<Native as Backend<f64>>::iamax(4, &x, 1), 2);
+        assert_eq!(<Native as Backend<f64>>::iamax(0, &x, 1), 0);
+
+        // no overflow for large elements
+        let large = [3e300f64, 4e300];
+        assert!((<Native as Backend<f64>>::nrm2(2, &large, 1) / 5e300 - 1.).abs() < 1e-15);
+    }
+
+    #[test]
+    fn gemv_ger() {
+        // A = [[1, 2], [3, 4], [5, 6]]
+        let mut a = [1., 3., 5., 2., 4., 6.];
+
+        let mut y = [0.; 3];
+        <Native as Backend<f64>>::gemv(Transpose::No, 3, 2, 1., &a, 3, &[1., 1.], 1, 0., &mut y, 1);
+        assert_eq!(y, [3., 7., 11.]);
+
+        let mut y = [1.; 2];
+        <Native as Backend<f64>>::gemv(
+            Transpose::Trans,
+            3,
+            2,
+            1.,
+            &a,
+            3,
+            &[1., 0., 1.],
+            1,
+            2.,
+            &mut y,
+            1,
+        );
+        assert_eq!(y, [8., 10.]);
+
+        <Native as Backend<f64>>::ger(3, 2, 1., &[1., 0., 0.], 1, &[-1., -2.], 1, &mut a, 3);
+        assert_eq!(a, [0., 3., 5., 0., 4., 6.]);
+    }
+
+    #[test]
+    fn getrf_getrs() {
+        // A = [[2, 9], [4, 4]]
+        let mut a = [2., 4., 9., 4.];
+        let mut ipiv = [0; 2];
+
+        assert_eq!(
+            <Native as Backend<f64>>::getrf(2, 2, &mut a, 2, &mut ipiv),
+            0
+        );
+        assert_eq!(ipiv, [2, 2]);
+        assert_eq!(a, [4., 0.5, 4., 7.]);
+
+        let mut b = [11., 8.];
+        <Native as Backend<f64>>::getrs(Transpose::No, 2, 1, &a, 2, &ipiv, &mut b, 2);
+        assert_eq!(b, [1., 1.]);
+
+        // A^T * (1, 1) = (6, 13)
+        let mut b = [6., 13.];
+        <Native as Backend<f64>>::getrs(Transpose::Trans, 2, 1, &a, 2, &ipiv, &mut b, 2);
+        assert_eq!(b, [1., 1.]);
+    }
+
+    #[test]
+    fn complex() {
+        let i = Complex::new(0., 1.);
+        let x = [Complex::new(3., 4.), i];
+
+        assert_eq!(
+            <Native as Backend<Complex<f64>>>::dot(2, &x, 1, &x, 1),
+            Complex::new(-8., 24.)
+        );
+        assert_eq!(
+            <Native as Backend<Complex<f64>>>::dotc(2, &x, 1, &x, 1),
+            Complex::new(26., 0.)
+        );
+        assert!((<Native as Backend<Complex<f64>>>::nrm2(2, &x, 1) - 26f64.sqrt()).abs() < 1e-15);
+        assert_eq!(<Native as Backend<Complex<f64>>>::asum(2, &x, 1), 8.);
+        assert_eq!(<Native as Backend<Complex<f64>>>::iamax(2, &x, 1), 1);
+
+        // A = [[1, i], [0, 1]], A^H * (1, 1) = (1, 1 - i)
+        let a = [
+            Complex::new(1., 0.),
+            Complex::new(0., 0.),
+            i,
+            Complex::new(1., 0.),
+        ];
+        let mut y = [Complex::new(0., 0.); 2];
+        <Native as Backend<Complex<f64>>>::gemv(
+            Transpose::ConjTrans,
+            2,
+            2,
+            Complex::new(1., 0.),
+            &a,
+            2,
+            &[Complex::new(1., 0.); 2],
+            1,
+            Complex::new(0., 0.),
+            &mut y,
+            1,
+        );
+        assert_eq!(y, [Complex::new(1., 0.), Complex::new(1., -1.)]);
+
+        // solve A^H * x = (1, 1 - i)
+        let mut lu = a;
+        let mut ipiv = [0; 2];
+        assert_eq!(
+            <Native as Backend<Complex<f64>>>::getrf(2, 2, &mut lu, 2, &mut ipiv),
+            0
+        );
+        <Native as Backend<Complex<f64>>>::getrs(
+            Transpose::ConjTrans,
+            2,
+            1,
+            &lu,
+            2,
+            &ipiv,
+            &mut y,
+            2,
+        );
+        assert_eq!(y, [Complex::new(1., 0.); 2]);
+    }
+
+    #[test]
+    fn getrf_singular() {
+        let mut a = [1., 2., 2., 4.];
+        let mut ipiv = [0; 2];
+
+        assert_eq!(
+            <Native as Backend<f32>>::getrf(2, 2, &mut a, 2, &mut ipiv),
+            2
+        );
+    }
+
+    #[test]
+    fn potrf_potrs() {
+        // A = [[4, 2], [2, 5]]. the other triangle is left untouched.
+        let mut lower = [4., 2., 2., 5.];
+        assert_eq!(
+            <Native as RealBackend<f64>>::potrf(Uplo::Lower, 2, &mut lower, 2),
+            0
+        );
+        assert_eq!(lower, [2., 1., 2., 2.]);
+
+        let mut upper = [4., 2., 2., 5.];
+        assert_eq!(
+            <Native as RealBackend<f64>>::potrf(Uplo::Upper, 2, &mut upper, 2),
+            0
+        );
+        assert_eq!(upper, [2., 2., 1., 2.]);
+
+        let mut b = [6., 7.];
+        <Native as RealBackend<f64>>::potrs(Uplo::Upper, 2, 1, &upper, 2, &mut b, 2);
+        assert_eq!(b, [1., 1.]);
+
+        let mut indefinite = [1., 2., 2., 1.];
+        assert_eq!(
+            <Native as RealBackend<f64>>::potrf(Uplo::Lower, 2, &mut indefinite, 2),
+            2
+        );
+    }
+
+    #[test]
+    fn gttrf_pivoting() {
+        // A = [[0, 1, 0], [1, 1, 1], [0, 1, 1]]
+        let (mut dl, mut d, mut du) = ([1., 1.], [0., 1., 1.], [1., 1.]);
+        let mut du2 = [0.];
+        let mut ipiv = [0; 3];
+
+        assert_eq!(
+            <Native as RealBackend<f64>>::gttrf(3, &mut dl, &mut d, &mut du, &mut du2, &mut ipiv),
+            0
+        );
+        assert_eq!(ipiv, [2, 2, 3]);
+        assert_eq!(du2, [1.]);
+
+        let mut b = [1., 3., 2.];
+        <Native as RealBackend<f64>>::gttrs(3, 1, &dl, &d, &du, &du2, &ipiv, &mut b, 3);
+        assert_eq!(b, [1., 1., 1.]);
+    }
+
+    #[test]
+    fn geqrf_orgqr() {
+        // A = [[1, 2], [3, 4], [5, 6]]
+        let a = [1., 3., 5., 2., 4., 6.];
+        let mut qr = a;
+        let mut tau = [0.; 2];
+        <Native as RealBackend<f64>>::geqrf(3, 2, &mut qr, 3, &mut tau);
+
+        let mut q = qr;
+        <Native as RealBackend<f64>>::orgqr(3, 2, 2, &mut q, 3, &tau);
+
+        for col in 0..2 {
+            for row in 0..3 {
+                let qr: f64 = (0..=col).map(|k| q[row + k * 3] * qr[k + col * 3]).sum();
+                assert!((qr - a[row + col * 3]).abs() < 1e-12);
+            }
+            for other in 0..2 {
+                let dot: f64 = (0..3).map(|i| q[i + col * 3] * q[i + other * 3]).sum();
+                assert!((dot - if col == other { 1. } else { 0. }).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn gesvd_gesdd() {
+        // tall, wide and rank one (the outer product of [1, 2, 3] and [1, -1]).
+        // the singular ones at the end used to leave Jacobi with columns that underflow to subnormals.
+        let cases: [(usize, usize, Vec<f64>); 5] = [
+            (3, 2, vec![1., 3., 5., 2., 4., 7.]),
+            (2, 3, vec![4., 1., -2., 0., 3., 5.]),
+            (3, 2, vec![1., 2., 3., -1., -2., -3.]),
+            (3, 3, vec![1., 0., -1., 0., -2., 0., 2., 1., -2.]),
+            (
+                4,
+                4,
+                vec![
+                    2., -1., -1., -2., -2., 2., 2., 1., 2., 1., 1., 0., 1., -1., -1., 2.,
+                ],
+            ),
+        ];
+
+        for (m, n, a) in cases {
+            let mut values = vec![];
+            for gesvd in [false, true] {
+                let (mut s, mut u, mut vt) = (vec![0.; m.min(n)], vec![0.; m * m], vec![0.; n * n]);
+                let mut work = a.clone();
+                let svd = if gesvd {
+                    <Native as RealBackend<f64>>::gesvd
+                } else {
+                    <Native as RealBackend<f64>>::gesdd
+                };
+                assert_eq!(
+                    svd('A', m, n, &mut work, m, &mut s, &mut u, m, &mut vt, n),
+                    0
+                );
+
+                // A = U S V^T with orthogonal U and V
+                for col in 0..n {
+                    for row in 0..m {
+                        let usv: f64 = (0..m.min(n))
+                            .map(|k| u[row + k * m] * s[k] * vt[k + col * n])
+                            .sum();
+                        assert!((usv - a[row + col * m]).abs() < 1e-12);
+                    }
+                }
+                for (q, size) in [(&u, m), (&vt, n)] {
+                    for i in 0..size {
+                        for j in 0..size {
+                            let dot: f64 =
+                                (0..size).map(|k| q[k + i * size] * q[k + j * size]).sum();
+                            assert!((dot - if i == j { 1. } else { 0. }).abs() < 1e-12);
+                        }
+                    }
+                }
+                values.push(s);
+            }
+
+            for (x, y) in values[0].iter().zip(&values[1]) {
+                assert!((x - y).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn gees_trsen() {
+        // a rotation is already in standard form
+        let mut t = [0., 1., -1., 0.];
+        let mut z = [0.; 4];
+        let (mut wr, mut wi) = ([0.; 2], [0.; 2]);
+        assert_eq!(
+            <Native as RealBackend<f64>>::gees(2, &mut t, 2, &mut wr, &mut wi, &mut z, 2),
+            0
+        );
+        assert_eq!((wr, wi), ([0., 0.], [1., -1.]));
+
+        // A = [[1, 2], [0, 3]] with 3 moved first
+        let mut t = [1., 0., 2., 3.];
+        let mut q = [1., 0., 0., 1.];
+        let mut m = 0;
+        assert_eq!(
+            <Native as RealBackend<f64>>::trsen(
+                &[false, true],
+                2,
+                &mut t,
+                2,
+                &mut q,
+                2,
+                &mut wr,
+                &mut wi,
+                &mut m
+            ),
+            0
+        );
+        assert_eq!(m, 1);
+        assert!((t[0] - 3.).abs() < 1e-12 && (t[3] - 1.).abs() < 1e-12);
+        assert_eq!(t[1], 0.);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::backend::Uplo;
+
+use num_traits::Float;
+
+// element (row, col) of the factor stored in the `uplo` triangle: L for Lower, U^T for Upper
+fn at<T: Float>(uplo: Uplo, a: &[T], lda: usize, row: usize, col: usize) -> T {
+    match uplo {
+        Uplo::Lower => a[row + col * lda],
+        Uplo::Upper => a[col + row * lda],
+    }
+}
+
+fn at_mut<T: Float>(uplo: Uplo, a: &mut [T], lda: usize, row: usize, col: usize) -> &mut T {
+    match uplo {
+        Uplo::Lower => &mut a[row + col * lda],
+        Uplo::Upper => &mut a[col + row * lda],
+    }
+}
+
+// left-looking Cholesky–Banachiewicz on the lower triangle, or on the upper one transposed
+pub fn potrf<T: Float>(uplo: Uplo, n: usize, a: &mut [T], lda: usize) -> i32 {
+    for j in 0..n {
+        let d = (0..j).fold(at(uplo, a, lda, j, j), |acc, k| {
+            let l = at(uplo, a, lda, j, k);
+            acc - l * l
+        });
+        // NaN is not positive definite either
+        #[allow(clippy::neg_cmp_op_on_partial_ord)]
+        if !(d > T::zero()) {
+            *at_mut(uplo, a, lda, j, j) = d;
+            return j as i32 + 1;
+        }
+        let d = d.sqrt();
+        *at_mut(uplo, a, lda, j, j) = d;
+
+        for i in j + 1..n {
+            let v = (0..j).fold(at(uplo, a, lda, i, j), |acc, k| {
+                acc - at(uplo, a, lda, i, k) * at(uplo, a, lda, j, k)
+            });
+            *at_mut(uplo, a, lda, i, j) = v / d;
+        }
+    }
+
+    0
+}
+
+// L * L^T * X = B by a forward and a backward substitution
+#[allow(clippy::too_many_arguments)]
+pub fn potrs<T: Float>(
+    uplo: Uplo,
+    n: usize,
+    nrhs: usize,
+    a: &[T],
+    lda: usize,
+    b: &mut [T],
+    ldb: usize,
+) -> i32 {
+    for col in 0..nrhs {
+        let x = &mut b[col * ldb..col * ldb + n];
+
+        for i in 0..n {
+            let v = (0..i).fold(x[i], |acc, k| acc - at(uplo, a, lda, i, k) * x[k]);
+            x[i] = v / at(uplo, a, lda, i, i);
+        }
+        for i in (0..n).rev() {
+            let v = (i + 1..n).fold(x[i], |acc, k| acc - at(uplo, a, lda, k, i) * x[k]);
+            x[i] = v / at(uplo, a, lda, i, i);
+        }
+    }
+
+    0
+}
@@ -0,0 +1,1021 @@
+use super::qr::{apply_reflector, geqrf, orgqr, reflector};
+use crate::backend::Uplo;
+
+use num_complex::Complex;
+use num_traits::Float;
+
+use core::ops::{Index, IndexMut};
+
+// sweeps of the symmetric Jacobi method before giving up
+const MAX_SWEEPS: usize = 60;
+
+// n x n column-major work matrix
+#[derive(Debug, Clone)]
+struct Square<T> {
+    n: usize,
+    data: Vec<T>,
+}
+
+impl<T: Float> Square<T> {
+    fn identity(n: usize) -> Self {
+        Self {
+            n,
+            data: (0..n * n)
+                .map(|i| if i % n == i / n { T::one() } else { T::zero() })
+                .collect(),
+        }
+    }
+
+    fn from_slice(n: usize, a: &[T], lda: usize) -> Self {
+        Self {
+            n,
+            data: (0..n * n).map(|i| a[i % n + (i / n) * lda]).collect(),
+        }
+    }
+
+    fn write_to(&self, a: &mut [T], lda: usize) {
+        for (i, v) in self.data.iter().enumerate() {
+            a[i % self.n + (i / self.n) * lda] = *v;
+        }
+    }
+
+    // applies the rotation [c -s; s c] to columns p and q (rows `rows`)
+    fn rotate_cols(&mut self, p: usize, q: usize, c: T, s: T, rows: core::ops::Range<usize>) {
+        for i in rows {
+            let (x, y) = (self[(i, p)], self[(i, q)]);
+            self[(i, p)] = c * x + s * y;
+            self[(i, q)] = c * y - s * x;
+        }
+    }
+
+    // applies the transpose of the same rotation to rows p and q (columns `cols`)
+    fn rotate_rows(&mut self, p: usize, q: usize, c: T, s: T, cols: core::ops::Range<usize>) {
+        for j in cols {
+            let (x, y) = (self[(p, j)], self[(q, j)]);
+            self[(p, j)] = c * x + s * y;
+            self[(q, j)] = c * y - s * x;
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Square<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.data[row + col * self.n]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Square<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.data[row + col * self.n]
+    }
+}
+
+fn lit<T: Float>(v: f64) -> T {
+    T::from(v).unwrap()
+}
+
+// Fortran's SIGN(a, b)
+fn sign<T: Float>(a: T, b: T) -> T {
+    if b >= T::zero() {
+        a.abs()
+    } else {
+        -a.abs()
+    }
+}
+
+// cyclic Jacobi on the symmetric matrix held in the `uplo` triangle.
+// returns the eigenvalues in ascending order and the eigenvectors, or None if it did not converge.
+fn symmetric<T: Float>(uplo: Uplo, n: usize, a: &[T], lda: usize) -> Option<(Vec<T>, Square<T>)> {
+    let mut s = Square::identity(n);
+    for col in 0..n {
+        for row in 0..n {
+            let stored = match uplo {
+                Uplo::Lower => row >= col,
+                Uplo::Upper => row <= col,
+            };
+            s[(row, col)] = if stored {
+                a[row + col * lda]
+            } else {
+                a[col + row * lda]
+            };
+        }
+    }
+    let mut v = Square::identity(n);
+
+    let eps = T::epsilon();
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = s[(p, q)];
+                if apq.abs() <= eps * (s[(p, p)].abs() * s[(q, q)].abs()).sqrt()
+                    || apq.abs() < T::min_positive_value()
+                {
+                    continue;
+                }
+                rotated = true;
+
+                // the rotation zeroing s[p, q], as in Rutishauser's formulation
+                let theta = (s[(q, q)] - s[(p, p)]) / (apq + apq);
+                let t = sign(T::one(), theta) / (theta.abs() + theta.hypot(T::one()));
+                let c = t.hypot(T::one()).recip();
+                let sn = t * c;
+
+                s.rotate_cols(p, q, c, -sn, 0..n);
+                s.rotate_rows(p, q, c, -sn, 0..n);
+                v.rotate_cols(p, q, c, -sn, 0..n);
+                s[(p, q)] = T::zero();
+                s[(q, p)] = T::zero();
+            }
+        }
+
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return None;
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+        s[(i, i)]
+            .partial_cmp(&s[(j, j)])
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+
+    let values = order.iter().map(|&i| s[(i, i)]).collect();
+    let mut vectors = Square::identity(n);
+    for (k, &j) in order.iter().enumerate() {
+        for i in 0..n {
+            vectors[(i, k)] = v[(i, j)];
+        }
+    }
+
+    Some((values, vectors))
+}
+
+pub fn syevd<T: Float>(
+    job: char,
+    uplo: Uplo,
+    n: usize,
+    a: &mut [T],
+    lda: usize,
+    w: &mut [T],
+) -> i32 {
+    let (values, vectors) = match symmetric(uplo, n, a, lda) {
+        Some(result) => result,
+        None => return 1,
+    };
+
+    w[..n].copy_from_slice(&values);
+    if job == 'V' {
+        vectors.write_to(a, lda);
+    }
+
+    0
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn syevr<T: Float>(
+    job: char,
+    range: char,
+    uplo: Uplo,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    vl: T,
+    vu: T,
+    il: usize,
+    iu: usize,
+    m: &mut usize,
+    w: &mut [T],
+    z: &mut [T],
+    ldz: usize,
+) -> i32 {
+    let (values, vectors) = match symmetric(uplo, n, a, lda) {
+        Some(result) => result,
+        None => return 1,
+    };
+
+    let selected: Vec<usize> = (0..n)
+        .filter(|&i| match range {
+            'I' => il <= i + 1 && i < iu,
+            'V' => vl < values[i] && values[i] <= vu,
+            _ => true,
+        })
+        .collect();
+
+    *m = selected.len();
+    for (k, &i) in selected.iter().enumerate() {
+        w[k] = values[i];
+        if job == 'V' {
+            for row in 0..n {
+                z[row + k * ldz] = vectors[(row, i)];
+            }
+        }
+    }
+
+    0
+}
+
+// reduces H to upper Hessenberg form by Householder reflectors, accumulating them in Z
+fn hessenberg<T: Float>(h: &mut Square<T>, z: &mut Square<T>) {
+    let n = h.n;
+    for k in 0..n.saturating_sub(2) {
+        let alpha = h[(k + 1, k)];
+        let (beta, tau) = reflector(alpha, &mut h.data[k + 2 + k * n..(k + 1) * n]);
+        let v: Vec<T> = h.data[k + 1 + k * n..(k + 1) * n].to_vec();
+
+        // H = P * H * P and Z = Z * P, where P = I - tau * v * v^T acts on k + 1..n
+        apply_reflector(&v, tau, &mut h.data, n, k + 1, k + 1..n);
+        for mat in [&mut *h, &mut *z] {
+            for i in 0..n {
+                let dot = (0..v.len()).fold(T::zero(), |acc, j| {
+                    acc + mat[(i, k + 1 + j)] * if j == 0 { T::one() } else { v[j] }
+                });
+                for j in 0..v.len() {
+                    let vj = if j == 0 { T::one() } else { v[j] };
+                    mat[(i, k + 1 + j)] = mat[(i, k + 1 + j)] - tau * dot * vj;
+                }
+            }
+        }
+
+        h[(k + 1, k)] = beta;
+        for i in k + 2..n {
+            h[(i, k)] = T::zero();
+        }
+    }
+}
+
+// standardizes the 2x2 block [a b; c d] as ?lanv2: either upper triangular, or a = d and b * c < 0
+// for a complex conjugate pair. returns the block and the rotation (cs, sn).
+#[allow(clippy::many_single_char_names)]
+fn lanv2<T: Float>(a: T, b: T, c: T, d: T) -> ((T, T, T, T), (T, T)) {
+    let (zero, one, half) = (T::zero(), T::one(), lit::<T>(0.5));
+    let (mut a, mut b, mut c, mut d) = (a, b, c, d);
+    let (mut cs, mut sn);
+
+    if c == zero {
+        cs = one;
+        sn = zero;
+    } else if b == zero {
+        // swap rows and columns
+        cs = zero;
+        sn = one;
+        core::mem::swap(&mut a, &mut d);
+        b = -c;
+        c = zero;
+    } else if a - d == zero && sign(one, b) != sign(one, c) {
+        cs = one;
+        sn = zero;
+    } else {
+        let temp = a - d;
+        let p = half * temp;
+        let bcmax = b.abs().max(c.abs());
+        let bcmis = b.abs().min(c.abs()) * sign(one, b) * sign(one, c);
+        let scale = p.abs().max(bcmax);
+        let z = (p / scale) * p + (bcmax / scale) * bcmis;
+
+        if z >= lit::<T>(4.) * T::epsilon() {
+            // real eigenvalues
+            let z = p + sign(scale.sqrt() * z.sqrt(), p);
+            a = d + z;
+            d = d - (bcmax / z) * bcmis;
+            let tau = c.hypot(z);
+            cs = z / tau;
+            sn = c / tau;
+            b = b - c;
+            c = zero;
+        } else {
+            // complex or almost equal real eigenvalues: make the diagonal elements equal
+            let sigma = b + c;
+            let tau = sigma.hypot(temp);
+            cs = (half * (one + sigma.abs() / tau)).sqrt();
+            sn = -(p / (tau * cs)) * sign(one, sigma);
+
+            let (aa, bb) = (a * cs + b * sn, -a * sn + b * cs);
+            let (cc, dd) = (c * cs + d * sn, -c * sn + d * cs);
+            a = aa * cs + cc * sn;
+            b = bb * cs + dd * sn;
+            c = -aa * sn + cc * cs;
+            d = -bb * sn + dd * cs;
+
+            let temp = half * (a + d);
+            a = temp;
+            d = temp;
+
+            if c != zero {
+                if b != zero {
+                    if sign(one, b) == sign(one, c) {
+                        // real eigenvalues: reduce to upper triangular form
+                        let sab = b.abs().sqrt();
+                        let sac = c.abs().sqrt();
+                        let p = sign(sab * sac, c);
+                        let tau = (b + c).abs().sqrt().recip();
+                        a = temp + p;
+                        d = temp - p;
+                        b = b - c;
+                        c = zero;
+                        let (cs1, sn1) = (sab * tau, sac * tau);
+                        let temp = cs * cs1 - sn * sn1;
+                        sn = cs * sn1 + sn * cs1;
+                        cs = temp;
+                    }
+                } else {
+                    b = -c;
+                    c = zero;
+                    let temp = cs;
+                    cs = -sn;
+                    sn = temp;
+                }
+            }
+        }
+    }
+
+    ((a, b, c, d), (cs, sn))
+}
+
+// standardizes the 2x2 diagonal block of T at p and applies the rotation to the rest of T and to Z
+fn standardize<T: Float>(t: &mut Square<T>, z: &mut Square<T>, p: usize) {
+    let n = t.n;
+    let q = p + 1;
+    let ((a, b, c, d), (cs, sn)) = lanv2(t[(p, p)], t[(p, q)], t[(q, p)], t[(q, q)]);
+
+    t[(p, p)] = a;
+    t[(p, q)] = b;
+    t[(q, p)] = c;
+    t[(q, q)] = d;
+    t.rotate_rows(p, q, cs, sn, q + 1..n);
+    t.rotate_cols(p, q, cs, sn, 0..p);
+    z.rotate_cols(p, q, cs, sn, 0..n);
+}
+
+// reduces the Hessenberg matrix H to real Schur form by the Francis double shift QR iteration,
+// accumulating the transformations in Z. after hqr2 of EISPACK as in JAMA, with the 2x2 blocks
+// standardized as in ?lahqr. returns the 1-based index of the eigenvalue that did not converge.
+#[allow(clippy::many_single_char_names)]
+fn schur<T: Float>(h: &mut Square<T>, z: &mut Square<T>) -> Result<(), i32> {
+    let nn = h.n;
+    let eps = T::epsilon();
+    let max_iter = 30 * nn.max(10);
+
+    let mut norm = T::zero();
+    for i in 0..nn {
+        for j in i.saturating_sub(1)..nn {
+            norm = norm + h[(i, j)].abs();
+        }
+    }
+
+    let mut exshift = T::zero();
+    let mut iter = 0;
+    let mut n = nn;
+    while n > 0 {
+        let last = n - 1;
+
+        // look for a single small sub-diagonal element
+        let mut l = last;
+        while l > 0 {
+            let mut s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+            if s.is_zero() {
+                s = norm;
+            }
+            if h[(l, l - 1)].abs() <= eps * s {
+                break;
+            }
+            l -= 1;
+        }
+        if l > 0 {
+            h[(l, l - 1)] = T::zero();
+        }
+
+        if l == last {
+            // one root found
+            h[(last, last)] = h[(last, last)] + exshift;
+            n -= 1;
+            iter = 0;
+            continue;
+        }
+        if l + 1 == last {
+            // two roots found
+            h[(last - 1, last - 1)] = h[(last - 1, last - 1)] + exshift;
+            h[(last, last)] = h[(last, last)] + exshift;
+            standardize(h, z, last - 1);
+            n -= 2;
+            iter = 0;
+            continue;
+        }
+
+        if iter >= max_iter {
+            return Err(last as i32 + 1);
+        }
+
+        // form shift
+        let mut x = h[(last, last)];
+        let mut y = h[(last - 1, last - 1)];
+        let mut w = h[(last, last - 1)] * h[(last - 1, last)];
+
+        // Wilkinson's original ad hoc shift
+        if iter == 10 {
+            exshift = exshift + x;
+            for i in 0..=last {
+                h[(i, i)] = h[(i, i)] - x;
+            }
+            let s = h[(last, last - 1)].abs() + h[(last - 1, last - 2)].abs();
+            x = lit::<T>(0.75) * s;
+            y = x;
+            w = lit::<T>(-0.4375) * s * s;
+        }
+
+        // MATLAB's ad hoc shift
+        if iter == 30 {
+            let half = (y - x) / lit(2.);
+            let s = half * half + w;
+            if s > T::zero() {
+                let s = if y < x { -s.sqrt() } else { s.sqrt() };
+                let s = x - w / (half + s);
+                for i in 0..=last {
+                    h[(i, i)] = h[(i, i)] - s;
+                }
+                exshift = exshift + s;
+                x = lit(0.964);
+                y = x;
+                w = x;
+            }
+        }
+
+        iter += 1;
+
+        // look for two consecutive small sub-diagonal elements
+        let mut m = last - 2;
+        let (mut p, mut q, mut r);
+        loop {
+            let zz = h[(m, m)];
+            let rr = x - zz;
+            let ss = y - zz;
+            p = (rr * ss - w) / h[(m + 1, m)] + h[(m, m + 1)];
+            q = h[(m + 1, m + 1)] - zz - rr - ss;
+            r = h[(m + 2, m + 1)];
+            let s = p.abs() + q.abs() + r.abs();
+            p = p / s;
+            q = q / s;
+            r = r / s;
+            if m == l {
+                break;
+            }
+            if h[(m, m - 1)].abs() * (q.abs() + r.abs())
+                < eps * (p.abs() * (h[(m - 1, m - 1)].abs() + zz.abs() + h[(m + 1, m + 1)].abs()))
+            {
+                break;
+            }
+            m -= 1;
+        }
+
+        for i in m + 2..=last {
+            h[(i, i - 2)] = T::zero();
+            if i > m + 2 {
+                h[(i, i - 3)] = T::zero();
+            }
+        }
+
+        // double QR step involving rows l..=last and columns m..=last
+        for k in m..last {
+            let notlast = k != last - 1;
+            if k != m {
+                p = h[(k, k - 1)];
+                q = h[(k + 1, k - 1)];
+                r = if notlast {
+                    h[(k + 2, k - 1)]
+                } else {
+                    T::zero()
+                };
+                x = p.abs() + q.abs() + r.abs();
+                if x.is_zero() {
+                    continue;
+                }
+                p = p / x;
+                q = q / x;
+                r = r / x;
+            }
+
+            let s = sign((p * p + q * q + r * r).sqrt(), p);
+            if s.is_zero() {
+                continue;
+            }
+            if k != m {
+                h[(k, k - 1)] = -s * x;
+            } else if l != m {
+                h[(k, k - 1)] = -h[(k, k - 1)];
+            }
+            p = p + s;
+            x = p / s;
+            y = q / s;
+            let zz = r / s;
+            q = q / p;
+            r = r / p;
+
+            // row modification
+            for j in k..nn {
+                let mut p = h[(k, j)] + q * h[(k + 1, j)];
+                if notlast {
+                    p = p + r * h[(k + 2, j)];
+                    h[(k + 2, j)] = h[(k + 2, j)] - p * zz;
+                }
+                h[(k, j)] = h[(k, j)] - p * x;
+                h[(k + 1, j)] = h[(k + 1, j)] - p * y;
+            }
+
+            // column modification
+            for i in 0..=last.min(k + 3) {
+                let mut p = x * h[(i, k)] + y * h[(i, k + 1)];
+                if notlast {
+                    p = p + zz * h[(i, k + 2)];
+                    h[(i, k + 2)] = h[(i, k + 2)] - p * r;
+                }
+                h[(i, k)] = h[(i, k)] - p;
+                h[(i, k + 1)] = h[(i, k + 1)] - p * q;
+            }
+
+            // accumulate transformations
+            for i in 0..nn {
+                let mut p = x * z[(i, k)] + y * z[(i, k + 1)];
+                if notlast {
+                    p = p + zz * z[(i, k + 2)];
+                    z[(i, k + 2)] = z[(i, k + 2)] - p * r;
+                }
+                z[(i, k)] = z[(i, k)] - p;
+                z[(i, k + 1)] = z[(i, k + 1)] - p * q;
+            }
+        }
+    }
+
+    // clear the rounding errors left below the sub-diagonal by the bulge chasing
+    for j in 0..nn {
+        for i in j + 2..nn {
+            h[(i, j)] = T::zero();
+        }
+    }
+
+    Ok(())
+}
+
+// (start, size) of the diagonal blocks of a real Schur form
+fn blocks<T: Float>(t: &Square<T>) -> Vec<(usize, usize)> {
+    let mut ret = Vec::new();
+    let mut i = 0;
+    while i < t.n {
+        let size = if i + 1 < t.n && !t[(i + 1, i)].is_zero() {
+            2
+        } else {
+            1
+        };
+        ret.push((i, size));
+        i += size;
+    }
+    ret
+}
+
+fn values<T: Float>(t: &Square<T>, wr: &mut [T], wi: &mut [T]) {
+    for (start, size) in blocks(t) {
+        wr[start] = t[(start, start)];
+        wi[start] = T::zero();
+        if size == 2 {
+            let q = start + 1;
+            let im = t[(start, q)].abs().sqrt() * t[(q, start)].abs().sqrt();
+            wr[q] = t[(q, q)];
+            wi[start] = im;
+            wi[q] = -im;
+        }
+    }
+}
+
+// the real Schur form of a general matrix, A = Z * T * Z^T
+fn schur_form<T: Float>(n: usize, a: &[T], lda: usize) -> Result<(Square<T>, Square<T>), i32> {
+    let mut t = Square::from_slice(n, a, lda);
+    let mut z = Square::identity(n);
+
+    hessenberg(&mut t, &mut z);
+    schur(&mut t, &mut z)?;
+
+    Ok((t, z))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn gees<T: Float>(
+    n: usize,
+    a: &mut [T],
+    lda: usize,
+    wr: &mut [T],
+    wi: &mut [T],
+    vs: &mut [T],
+    ldvs: usize,
+) -> i32 {
+    let (t, z) = match schur_form(n, a, lda) {
+        Ok(result) => result,
+        Err(info) => return info,
+    };
+
+    values(&t, wr, wi);
+    t.write_to(a, lda);
+    z.write_to(vs, ldvs);
+
+    0
+}
+
+// solves (B - lambda * I) * x = rhs for a 1x1 or 2x2 block B, perturbing a near singular system by smin
+fn solve_block<T: Float>(
+    b: &[[Complex<T>; 2]; 2],
+    size: usize,
+    lambda: Complex<T>,
+    rhs: [Complex<T>; 2],
+    smin: T,
+) -> [Complex<T>; 2] {
+    let zero = Complex::new(T::zero(), T::zero());
+    let perturb = |d: Complex<T>, min: T| {
+        if d.norm() < min {
+            Complex::new(min, T::zero())
+        } else {
+            d
+        }
+    };
+
+    if size == 1 {
+        return [rhs[0] / perturb(b[0][0] - lambda, smin), zero];
+    }
+
+    let (m00, m01, m10, m11) = (b[0][0] - lambda, b[0][1], b[1][0], b[1][1] - lambda);
+    let det = perturb(m00 * m11 - m01 * m10, smin * smin);
+    [
+        (rhs[0] * m11 - m01 * rhs[1]) / det,
+        (m00 * rhs[1] - m10 * rhs[0]) / det,
+    ]
+}
+
+// an eigenvector of T (or of T^T if `transposed`) for the eigenvalue lambda of the block `block`,
+// by substitution through the other blocks as ?trevc
+fn block_vector<T: Float>(
+    t: &Square<T>,
+    blocks: &[(usize, usize)],
+    block: usize,
+    lambda: Complex<T>,
+    transposed: bool,
+    smin: T,
+) -> Vec<Complex<T>> {
+    let n = t.n;
+    let at = |i: usize, j: usize| {
+        Complex::new(if transposed { t[(j, i)] } else { t[(i, j)] }, T::zero())
+    };
+    let zero = Complex::new(T::zero(), T::zero());
+
+    let mut x = vec![zero; n];
+    let (start, size) = blocks[block];
+    if size == 1 {
+        x[start] = Complex::new(T::one(), T::zero());
+    } else {
+        // a null vector of the singular 2x2 block B - lambda * I, from its larger row
+        let (m00, m01) = (at(start, start) - lambda, at(start, start + 1));
+        let (m10, m11) = (at(start + 1, start), at(start + 1, start + 1) - lambda);
+        if m00.norm() + m01.norm() >= m10.norm() + m11.norm() {
+            x[start] = m01;
+            x[start + 1] = -m00;
+        } else {
+            x[start] = m11;
+            x[start + 1] = -m10;
+        }
+    }
+
+    // T is upper quasi triangular, so T^T is solved forwards
+    let others: Vec<usize> = if transposed {
+        (block + 1..blocks.len()).collect()
+    } else {
+        (0..block).rev().collect()
+    };
+    for other in others {
+        let (s, size) = blocks[other];
+        let mut rhs = [zero; 2];
+        let mut b = [[zero; 2]; 2];
+        for (r, rhs) in rhs.iter_mut().enumerate().take(size) {
+            *rhs = -(0..n)
+                .filter(|j| !(s..s + size).contains(j))
+                .fold(zero, |acc, j| acc + at(s + r, j) * x[j]);
+            for (c, b) in b[r].iter_mut().enumerate().take(size) {
+                *b = at(s + r, s + c);
+            }
+        }
+
+        let solved = solve_block(&b, size, lambda, rhs, smin);
+        x[s..s + size].copy_from_slice(&solved[..size]);
+    }
+
+    x
+}
+
+// v = Z * x normalized to norm 1 with its largest component real, as ?geev
+fn normalized<T: Float>(z: &Square<T>, x: &[Complex<T>]) -> Vec<Complex<T>> {
+    let n = z.n;
+    let mut v: Vec<Complex<T>> = (0..n)
+        .map(|i| {
+            (0..n).fold(Complex::new(T::zero(), T::zero()), |acc, k| {
+                acc + x[k] * z[(i, k)]
+            })
+        })
+        .collect();
+
+    let largest = v
+        .iter()
+        .copied()
+        .fold(Complex::new(T::zero(), T::zero()), |acc, c| {
+            if c.norm() > acc.norm() {
+                c
+            } else {
+                acc
+            }
+        });
+    if largest.norm().is_zero() {
+        return v;
+    }
+    let phase = largest.conj() / largest.norm();
+    let norm = v.iter().fold(T::zero(), |acc, c| acc.hypot(c.norm()));
+    for c in v.iter_mut() {
+        *c = *c * phase / norm;
+    }
+
+    v
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn geev<T: Float>(
+    jobvl: char,
+    jobvr: char,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    wr: &mut [T],
+    wi: &mut [T],
+    vl: &mut [T],
+    ldvl: usize,
+    vr: &mut [T],
+    ldvr: usize,
+) -> i32 {
+    let (t, z) = match schur_form(n, a, lda) {
+        Ok(result) => result,
+        Err(info) => return info,
+    };
+    values(&t, wr, wi);
+
+    if jobvl != 'V' && jobvr != 'V' {
+        return 0;
+    }
+
+    let norm = t.data.iter().fold(T::zero(), |acc, v| acc + v.abs());
+    let smin = (T::epsilon() * norm).max(T::min_positive_value());
+    let blocks = blocks(&t);
+
+    for (b, &(start, _)) in blocks.iter().enumerate() {
+        let lambda = Complex::new(wr[start], wi[start]);
+        let pair = !wi[start].is_zero();
+
+        for (job, transposed, out, ld) in [
+            (jobvr, false, &mut *vr, ldvr),
+            (jobvl, true, &mut *vl, ldvl),
+        ] {
+            if job != 'V' {
+                continue;
+            }
+
+            // a left eigenvector u of A satisfies A^T * conj(u) = lambda * conj(u)
+            let mut x = block_vector(&t, &blocks, b, lambda, transposed, smin);
+            if transposed {
+                x.iter_mut().for_each(|c| *c = c.conj());
+            }
+            let v = normalized(&z, &x);
+
+            for (i, c) in v.iter().enumerate() {
+                out[i + start * ld] = c.re;
+                if pair {
+                    out[i + (start + 1) * ld] = c.im;
+                }
+            }
+        }
+    }
+
+    0
+}
+
+// solves A11 * X - X * A22 = A12 (n1 x n2, at most 2x2 each) by Gaussian elimination with complete
+// pivoting on the Kronecker form, perturbing pivots smaller than smin as ?lasy2
+fn sylvester<T: Float>(d: &Square<T>, n1: usize, n2: usize, smin: T) -> Vec<T> {
+    let size = n1 * n2;
+    // unknown X[i, j] is i + j * n1
+    let mut k = vec![vec![T::zero(); size + 1]; size];
+    for j in 0..n2 {
+        for i in 0..n1 {
+            let row = i + j * n1;
+            for l in 0..n1 {
+                k[row][l + j * n1] = k[row][l + j * n1] + d[(i, l)];
+            }
+            for l in 0..n2 {
+                k[row][i + l * n1] = k[row][i + l * n1] - d[(n1 + l, n1 + j)];
+            }
+            k[row][size] = d[(i, n1 + j)];
+        }
+    }
+
+    let mut order: Vec<usize> = (0..size).collect();
+    for col in 0..size {
+        // complete pivoting
+        let (mut pr, mut pc) = (col, col);
+        for r in col..size {
+            for c in col..size {
+                if k[r][c].abs() > k[pr][pc].abs() {
+                    pr = r;
+                    pc = c;
+                }
+            }
+        }
+        k.swap(col, pr);
+        for row in k.iter_mut() {
+            row.swap(col, pc);
+        }
+        order.swap(col, pc);
+
+        if k[col][col].abs() < smin {
+            k[col][col] = smin;
+        }
+        let (pivot_row, below) = k.split_at_mut(col + 1);
+        let pivot_row = &pivot_row[col];
+        for row in below.iter_mut() {
+            let f = row[col] / pivot_row[col];
+            for (value, pivot) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *value = *value - f * *pivot;
+            }
+        }
+    }
+
+    let mut y = vec![T::zero(); size];
+    for r in (0..size).rev() {
+        let v = (r + 1..size).fold(k[r][size], |acc, c| acc - k[r][c] * y[c]);
+        y[r] = v / k[r][r];
+    }
+
+    let mut x = vec![T::zero(); size];
+    for (i, &o) in order.iter().enumerate() {
+        x[o] = y[i];
+    }
+    x
+}
+
+// swaps the adjacent diagonal blocks of sizes n1 and n2 at j1 of the Schur form (T, Q), as ?laexc.
+// returns false, leaving (T, Q) untouched, if the swap would be too inaccurate.
+fn swap_blocks<T: Float>(
+    t: &mut Square<T>,
+    q: &mut Square<T>,
+    j1: usize,
+    n1: usize,
+    n2: usize,
+) -> bool {
+    let n = t.n;
+    let nb = n1 + n2;
+
+    let mut d = Square::identity(nb);
+    for col in 0..nb {
+        for row in 0..nb {
+            d[(row, col)] = t[(j1 + row, j1 + col)];
+        }
+    }
+    let dnorm = d.data.iter().fold(T::zero(), |acc, v| acc.max(v.abs()));
+    let eps = T::epsilon();
+    let smin = (eps * dnorm).max(T::min_positive_value());
+    let thresh = (lit::<T>(10.) * eps * dnorm).max(T::min_positive_value());
+
+    // the columns of (-X, I) span the invariant subspace of the A22 eigenvalues.
+    // Q0 from their QR factorization moves that subspace to the front.
+    let x = sylvester(&d, n1, n2, smin);
+    let mut q0 = vec![T::zero(); nb * nb];
+    for j in 0..n2 {
+        for i in 0..n1 {
+            q0[i + j * nb] = -x[i + j * n1];
+        }
+        q0[n1 + j + j * nb] = T::one();
+    }
+    let mut tau = vec![T::zero(); n2];
+    geqrf(nb, n2, &mut q0, nb, &mut tau);
+    orgqr(nb, nb, n2, &mut q0, nb, &tau);
+    let q0 = Square { n: nb, data: q0 };
+
+    // D' = Q0^T * D * Q0 must be block upper triangular again
+    let mut swapped = Square::identity(nb);
+    for col in 0..nb {
+        for row in 0..nb {
+            swapped[(row, col)] = (0..nb).fold(T::zero(), |acc, i| {
+                acc + q0[(i, row)]
+                    * (0..nb).fold(T::zero(), |acc, j| acc + d[(i, j)] * q0[(j, col)])
+            });
+        }
+    }
+    for col in 0..n2 {
+        for row in n2..nb {
+            if swapped[(row, col)].abs() > thresh {
+                return false;
+            }
+        }
+    }
+
+    // T = Q0^T * T * Q0 on rows and columns j1..j1 + nb, and Q = Q * Q0
+    let rows: Vec<Vec<T>> = (0..nb)
+        .map(|i| (0..n).map(|j| t[(j1 + i, j)]).collect())
+        .collect();
+    for i in 0..nb {
+        for j in j1..n {
+            t[(j1 + i, j)] = (0..nb).fold(T::zero(), |acc, k| acc + q0[(k, i)] * rows[k][j]);
+        }
+    }
+    for mat in [&mut *t, &mut *q] {
+        let cols: Vec<Vec<T>> = (0..nb)
+            .map(|j| (0..n).map(|i| mat[(i, j1 + j)]).collect())
+            .collect();
+        for j in 0..nb {
+            for i in 0..n {
+                mat[(i, j1 + j)] = (0..nb).fold(T::zero(), |acc, k| acc + cols[k][i] * q0[(k, j)]);
+            }
+        }
+    }
+    for col in 0..n2 {
+        for row in n2..nb {
+            t[(j1 + row, j1 + col)] = T::zero();
+        }
+    }
+
+    if n2 == 2 {
+        standardize(t, q, j1);
+    }
+    if n1 == 2 {
+        standardize(t, q, j1 + n2);
+    }
+
+    true
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn trsen<T: Float>(
+    select: &[bool],
+    n: usize,
+    t: &mut [T],
+    ldt: usize,
+    q: &mut [T],
+    ldq: usize,
+    wr: &mut [T],
+    wi: &mut [T],
+    m: &mut usize,
+) -> i32 {
+    let mut schur = Square::from_slice(n, t, ldt);
+    let mut z = Square::from_slice(n, q, ldq);
+    let mut info = 0;
+
+    // the number of selected eigenvalues; selecting either of a pair selects both
+    *m = 0;
+    for (start, size) in blocks(&schur) {
+        if select[start..start + size].iter().any(|s| *s) {
+            *m += size;
+        }
+    }
+
+    // moves each selected block up to the end of the leading block, ks
+    let mut ks = 0;
+    let mut k = 0;
+    'outer: while k < n {
+        let size = if k + 1 < n && !schur[(k + 1, k)].is_zero() {
+            2
+        } else {
+            1
+        };
+        if select[k..k + size].iter().any(|s| *s) {
+            let mut here = k;
+            while here > ks {
+                let above = if here >= 2 && !schur[(here - 1, here - 2)].is_zero() {
+                    2
+                } else {
+                    1
+                };
+                if !swap_blocks(&mut schur, &mut z, here - above, above, size) {
+                    info = 1;
+                    break 'outer;
+                }
+                here -= above;
+            }
+            ks += size;
+        }
+        k += size;
+    }
+
+    values(&schur, wr, wi);
+    schur.write_to(t, ldt);
+    z.write_to(q, ldq);
+
+    info
+}
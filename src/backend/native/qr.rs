@@ -0,0 +1,175 @@
+use crate::backend::Transpose;
+
+use num_traits::Float;
+
+// elementary reflector H = I - tau * v * v^T with v = (1, x) such that H * (alpha, x) = (beta, 0), as ?larfg.
+// x is overwritten with the tail of v. returns (beta, tau).
+pub fn reflector<T: Float>(alpha: T, x: &mut [T]) -> (T, T) {
+    let norm = x.iter().fold(T::zero(), |acc, v| acc.hypot(*v));
+    if norm.is_zero() {
+        return (alpha, T::zero());
+    }
+
+    let beta = alpha.hypot(norm);
+    let beta = if alpha >= T::zero() { -beta } else { beta };
+    let scale = (alpha - beta).recip();
+    for v in x.iter_mut() {
+        *v = *v * scale;
+    }
+
+    (beta, (beta - alpha) / beta)
+}
+
+// applies H = I - tau * v * v^T from the left to rows first..first + v.len() of the columns `cols` of C.
+// v[0] is taken to be 1 whatever is stored there.
+pub fn apply_reflector<T: Float>(
+    v: &[T],
+    tau: T,
+    c: &mut [T],
+    ldc: usize,
+    first: usize,
+    cols: core::ops::Range<usize>,
+) {
+    if tau.is_zero() {
+        return;
+    }
+
+    let len = v.len();
+    let v = |i: usize| if i == 0 { T::one() } else { v[i] };
+    for col in cols {
+        let column = &mut c[first + col * ldc..first + col * ldc + len];
+
+        let dot = (0..len).fold(T::zero(), |acc, i| acc + v(i) * column[i]);
+        let factor = tau * dot;
+        for (i, value) in column.iter_mut().enumerate() {
+            *value = *value - factor * v(i);
+        }
+    }
+}
+
+// Householder QR, as ?geqr2
+pub fn geqrf<T: Float>(m: usize, n: usize, a: &mut [T], lda: usize, tau: &mut [T]) -> i32 {
+    for j in 0..m.min(n) {
+        let column = j * lda;
+        let alpha = a[j + column];
+        let (beta, t) = reflector(alpha, &mut a[j + 1 + column..m + column]);
+        tau[j] = t;
+
+        // v = (1, a[j + 1..m, j]) with a[j, j] holding beta afterwards
+        let v: Vec<T> = a[j + column..m + column].to_vec();
+        apply_reflector(&v, t, a, lda, j, j + 1..n);
+        a[j + column] = beta;
+    }
+
+    0
+}
+
+// Q = H(0) * H(1) * ... * H(k - 1) accumulated backwards, as ?org2r
+pub fn orgqr<T: Float>(m: usize, n: usize, k: usize, a: &mut [T], lda: usize, tau: &[T]) -> i32 {
+    // columns k..n start as those of the identity
+    for j in k..n {
+        for i in 0..m {
+            a[i + j * lda] = if i == j { T::one() } else { T::zero() };
+        }
+    }
+
+    for i in (0..k).rev() {
+        let column = i * lda;
+        let v: Vec<T> = a[i + column..m + column].to_vec();
+        apply_reflector(&v, tau[i], a, lda, i, i + 1..n);
+
+        for row in i + 1..m {
+            a[row + column] = -tau[i] * a[row + column];
+        }
+        a[i + column] = T::one() - tau[i];
+        for row in 0..i {
+            a[row + column] = T::zero();
+        }
+    }
+
+    0
+}
+
+// C = Q * C or Q^T * C, as ?orm2r with side 'L'
+#[allow(clippy::too_many_arguments)]
+pub fn ormqr<T: Float>(
+    trans: Transpose,
+    m: usize,
+    n: usize,
+    k: usize,
+    a: &[T],
+    lda: usize,
+    tau: &[T],
+    c: &mut [T],
+    ldc: usize,
+) -> i32 {
+    let apply = |i: usize, c: &mut [T]| {
+        let v = &a[i + i * lda..m + i * lda];
+        apply_reflector(v, tau[i], c, ldc, i, 0..n);
+    };
+
+    // Q^T = H(k - 1) * ... * H(0), each H(i) being symmetric
+    match trans {
+        Transpose::No => (0..k).rev().for_each(|i| apply(i, c)),
+        _ => (0..k).for_each(|i| apply(i, c)),
+    }
+
+    0
+}
+
+// back substitution with the upper triangle R (n x n) of A
+fn solve_upper<T: Float>(n: usize, a: &[T], lda: usize, x: &mut [T]) {
+    for i in (0..n).rev() {
+        let v = (i + 1..n).fold(x[i], |acc, k| acc - a[i + k * lda] * x[k]);
+        x[i] = v / a[i + i * lda];
+    }
+}
+
+// A = Q * R for m >= n, and A^T = Q * R (the LQ factorization of A) otherwise, as ?gels
+#[allow(clippy::too_many_arguments)]
+pub fn gels<T: Float>(
+    m: usize,
+    n: usize,
+    nrhs: usize,
+    a: &mut [T],
+    lda: usize,
+    b: &mut [T],
+    ldb: usize,
+) -> i32 {
+    let k = m.min(n);
+    let mut tau = vec![T::zero(); k];
+
+    if m >= n {
+        geqrf(m, n, a, lda, &mut tau);
+        if let Some(i) = (0..k).find(|&i| a[i + i * lda].is_zero()) {
+            return i as i32 + 1;
+        }
+
+        // R * X = (Q^T * B)[..n]. the rest of Q^T * B is the residual.
+        ormqr(Transpose::Trans, m, nrhs, k, a, lda, &tau, b, ldb);
+        for col in 0..nrhs {
+            solve_upper(n, a, lda, &mut b[col * ldb..col * ldb + n]);
+        }
+    } else {
+        let mut at: Vec<T> = (0..m * n).map(|i| a[i / n + (i % n) * lda]).collect();
+        geqrf(n, m, &mut at, n, &mut tau);
+        if let Some(i) = (0..k).find(|&i| at[i + i * n].is_zero()) {
+            return i as i32 + 1;
+        }
+
+        // A = R^T * Q^T, so X = Q * (R^{-T} * B, 0)
+        for col in 0..nrhs {
+            let x = &mut b[col * ldb..col * ldb + n];
+            for i in 0..m {
+                let v = (0..i).fold(x[i], |acc, l| acc - at[l + i * n] * x[l]);
+                x[i] = v / at[i + i * n];
+            }
+            for v in x[m..].iter_mut() {
+                *v = T::zero();
+            }
+        }
+        ormqr(Transpose::No, n, nrhs, k, &at, n, &tau, b, ldb);
+    }
+
+    0
+}
@@ -0,0 +1,577 @@
+use num_traits::Float;
+
+// sweeps of the Jacobi method before giving up. it usually converges in less than 10.
+const MAX_SWEEPS: usize = 60;
+
+// one-sided Jacobi (Hestenes) on the m x n matrix W (m >= n), column-major with leading dimension m.
+// rotates pairs of columns until they are mutually orthogonal, accumulating the rotations in V (n x n).
+// returns false if it did not converge.
+fn orthogonalize<T: Float>(m: usize, n: usize, w: &mut [T], v: &mut [T]) -> bool {
+    let eps = T::epsilon();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+                for i in 0..m {
+                    let (x, y) = (w[i + p * m], w[i + q * m]);
+                    alpha = alpha + x * x;
+                    beta = beta + y * y;
+                    gamma = gamma + x * y;
+                }
+
+                // the floor keeps columns that underflow to subnormals from rotating forever
+                if gamma.abs() <= (eps * alpha.sqrt() * beta.sqrt()).max(T::min_positive_value()) {
+                    continue;
+                }
+                rotated = true;
+
+                let two = T::one() + T::one();
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = zeta.signum() / (zeta.abs() + zeta.hypot(T::one()));
+                let c = t.hypot(T::one()).recip();
+                let s = c * t;
+
+                for (mat, rows) in [(&mut *w, m), (&mut *v, n)] {
+                    for i in 0..rows {
+                        let (x, y) = (mat[i + p * rows], mat[i + q * rows]);
+                        mat[i + p * rows] = c * x - s * y;
+                        mat[i + q * rows] = s * x + c * y;
+                    }
+                }
+            }
+        }
+
+        if !rotated {
+            return true;
+        }
+    }
+
+    false
+}
+
+// replaces the columns of the m x cols matrix Q (leading dimension m) not marked `valid` so that
+// all columns are orthonormal. the valid ones must be orthonormal already.
+fn complete_basis<T: Float>(m: usize, cols: usize, q: &mut [T], valid: &[bool]) {
+    let mut basis: Vec<usize> = (0..cols).filter(|&j| valid[j]).collect();
+
+    for j in 0..cols {
+        if valid[j] {
+            continue;
+        }
+
+        // the unit vector with the largest remainder after two passes of Gram-Schmidt
+        let mut best: Option<(T, Vec<T>)> = None;
+        for e in 0..m {
+            let mut x = vec![T::zero(); m];
+            x[e] = T::one();
+            for _ in 0..2 {
+                for &b in &basis {
+                    let dot = (0..m).fold(T::zero(), |acc, i| acc + q[i + b * m] * x[i]);
+                    for (i, value) in x.iter_mut().enumerate() {
+                        *value = *value - dot * q[i + b * m];
+                    }
+                }
+            }
+            let norm = x.iter().fold(T::zero(), |acc, v| acc.hypot(*v));
+            if best.as_ref().map_or(true, |(best, _)| norm > *best) {
+                best = Some((norm, x));
+            }
+        }
+
+        let (norm, x) = best.expect("m is not zero");
+        for (i, value) in x.into_iter().enumerate() {
+            q[i + j * m] = value / norm;
+        }
+        basis.push(j);
+    }
+}
+
+// SVD of a tall m x n matrix (m >= n). U and V are column-major with leading dimensions m and n.
+struct Tall<T> {
+    // in descending order
+    s: Vec<T>,
+    // the first n columns of U
+    u: Vec<T>,
+    // the columns of U that are set
+    valid: Vec<bool>,
+    // n x n
+    v: Vec<T>,
+}
+
+type Factor<T> = fn(usize, usize, Vec<T>) -> Option<Tall<T>>;
+
+// one-sided Jacobi. the columns of U for zero singular values are left unset.
+fn jacobi<T: Float>(m: usize, n: usize, mut w: Vec<T>) -> Option<Tall<T>> {
+    let mut v: Vec<T> = (0..n * n)
+        .map(|i| if i % n == i / n { T::one() } else { T::zero() })
+        .collect();
+
+    if !orthogonalize(m, n, &mut w, &mut v) {
+        return None;
+    }
+
+    let norms: Vec<T> = (0..n)
+        .map(|j| {
+            w[j * m..(j + 1) * m]
+                .iter()
+                .fold(T::zero(), |acc, x| acc.hypot(*x))
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+        norms[j]
+            .partial_cmp(&norms[i])
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+
+    let s: Vec<T> = order.iter().map(|&j| norms[j]).collect();
+    let mut u = vec![T::zero(); m * n];
+    // columns below the floor of orthogonalize() are not orthogonal to each other
+    let floor = (T::min_positive_value() / T::epsilon()).sqrt();
+    let mut valid = vec![false; n];
+    for (k, &j) in order.iter().enumerate() {
+        if s[k] > floor {
+            for i in 0..m {
+                u[i + k * m] = w[i + j * m] / s[k];
+            }
+            valid[k] = true;
+        }
+    }
+
+    let v_sorted: Vec<T> = order
+        .iter()
+        .flat_map(|&j| v[j * n..(j + 1) * n].iter().copied())
+        .collect();
+
+    Some(Tall {
+        s,
+        u,
+        valid,
+        v: v_sorted,
+    })
+}
+
+// Householder bidiagonalization (Golub-Kahan) followed by implicit shifted QR on the bidiagonal
+// (Golub-Reinsch), after JAMA's SingularValueDecomposition. gives up after 6 * n^2 QR steps.
+#[allow(clippy::many_single_char_names)]
+fn golub_kahan<T: Float>(m: usize, n: usize, mut a: Vec<T>) -> Option<Tall<T>> {
+    if n == 0 {
+        return Some(Tall {
+            s: Vec::new(),
+            u: Vec::new(),
+            valid: Vec::new(),
+            v: Vec::new(),
+        });
+    }
+
+    let zero = T::zero();
+    let one = T::one();
+    let mut s = vec![zero; n];
+    let mut e = vec![zero; n];
+    let mut u = vec![zero; m * n];
+    let mut v = vec![zero; n * n];
+    let mut work = vec![zero; m];
+
+    // reduce A to bidiagonal form, storing the diagonal in s and the super diagonal in e
+    let nct = (m - 1).min(n);
+    let nrt = n.saturating_sub(2).min(m);
+    for k in 0..nct.max(nrt) {
+        if k < nct {
+            // the k-th column transformation, with the k-th diagonal element in s[k]
+            s[k] = (k..m).fold(zero, |acc, i| acc.hypot(a[i + k * m]));
+            if !s[k].is_zero() {
+                if a[k + k * m] < zero {
+                    s[k] = -s[k];
+                }
+                for i in k..m {
+                    a[i + k * m] = a[i + k * m] / s[k];
+                }
+                a[k + k * m] = a[k + k * m] + one;
+            }
+            s[k] = -s[k];
+        }
+        for j in k + 1..n {
+            if k < nct && !s[k].is_zero() {
+                let t = (k..m).fold(zero, |acc, i| acc + a[i + k * m] * a[i + j * m]);
+                let t = -t / a[k + k * m];
+                for i in k..m {
+                    a[i + j * m] = a[i + j * m] + t * a[i + k * m];
+                }
+            }
+            // the k-th row of A, to compute the row transformation
+            e[j] = a[k + j * m];
+        }
+        if k < nct {
+            for i in k..m {
+                u[i + k * m] = a[i + k * m];
+            }
+        }
+        if k < nrt {
+            // the k-th row transformation, with the k-th super diagonal element in e[k]
+            e[k] = (k + 1..n).fold(zero, |acc, i| acc.hypot(e[i]));
+            if !e[k].is_zero() {
+                if e[k + 1] < zero {
+                    e[k] = -e[k];
+                }
+                for i in k + 1..n {
+                    e[i] = e[i] / e[k];
+                }
+                e[k + 1] = e[k + 1] + one;
+            }
+            e[k] = -e[k];
+            if k + 1 < m && !e[k].is_zero() {
+                for value in work[k + 1..].iter_mut() {
+                    *value = zero;
+                }
+                for j in k + 1..n {
+                    for i in k + 1..m {
+                        work[i] = work[i] + e[j] * a[i + j * m];
+                    }
+                }
+                for j in k + 1..n {
+                    let t = -e[j] / e[k + 1];
+                    for i in k + 1..m {
+                        a[i + j * m] = a[i + j * m] + t * work[i];
+                    }
+                }
+            }
+            for i in k + 1..n {
+                v[i + k * n] = e[i];
+            }
+        }
+    }
+
+    // the final bidiagonal matrix of order n
+    if nct < n {
+        s[nct] = a[nct + nct * m];
+    }
+    if nrt + 1 < n {
+        e[nrt] = a[nrt + (n - 1) * m];
+    }
+    e[n - 1] = zero;
+
+    // U from the column transformations
+    for j in nct..n {
+        for i in 0..m {
+            u[i + j * m] = zero;
+        }
+        u[j + j * m] = one;
+    }
+    for k in (0..nct).rev() {
+        if !s[k].is_zero() {
+            for j in k + 1..n {
+                let t = (k..m).fold(zero, |acc, i| acc + u[i + k * m] * u[i + j * m]);
+                let t = -t / u[k + k * m];
+                for i in k..m {
+                    u[i + j * m] = u[i + j * m] + t * u[i + k * m];
+                }
+            }
+            for i in k..m {
+                u[i + k * m] = -u[i + k * m];
+            }
+            u[k + k * m] = one + u[k + k * m];
+            for i in 0..k {
+                u[i + k * m] = zero;
+            }
+        } else {
+            for i in 0..m {
+                u[i + k * m] = zero;
+            }
+            u[k + k * m] = one;
+        }
+    }
+
+    // V from the row transformations
+    for k in (0..n).rev() {
+        if k < nrt && !e[k].is_zero() {
+            for j in k + 1..n {
+                let t = (k + 1..n).fold(zero, |acc, i| acc + v[i + k * n] * v[i + j * n]);
+                let t = -t / v[k + 1 + k * n];
+                for i in k + 1..n {
+                    v[i + j * n] = v[i + j * n] + t * v[i + k * n];
+                }
+            }
+        }
+        for i in 0..n {
+            v[i + k * n] = zero;
+        }
+        v[k + k * n] = one;
+    }
+
+    // rotations (c, s) of columns j and k: (x_j, x_k) = (c x_j + s x_k, -s x_j + c x_k)
+    let rotate = |mat: &mut [T], rows: usize, j: usize, k: usize, cs: T, sn: T| {
+        for i in 0..rows {
+            let t = cs * mat[i + j * rows] + sn * mat[i + k * rows];
+            mat[i + k * rows] = -sn * mat[i + j * rows] + cs * mat[i + k * rows];
+            mat[i + j * rows] = t;
+        }
+    };
+
+    let eps = T::epsilon();
+    let tiny = T::min_positive_value() / eps;
+    let max_steps = 6 * n * n;
+    let mut steps = 0;
+    let mut p = n;
+    while p > 0 {
+        // k is the first index of the unreduced block s[k..p], preceded by a negligible e[k - 1]
+        let mut k = p - 1;
+        while k > 0 && e[k - 1].abs() > tiny + eps * (s[k - 1].abs() + s[k].abs()) {
+            k -= 1;
+        }
+        if k > 0 {
+            e[k - 1] = zero;
+        }
+
+        if k == p - 1 {
+            // s[k] converged. make it positive and move it into place.
+            if s[k] <= zero {
+                s[k] = if s[k] < zero { -s[k] } else { zero };
+                for i in 0..n {
+                    v[i + k * n] = -v[i + k * n];
+                }
+            }
+            while k + 1 < n && s[k] < s[k + 1] {
+                s.swap(k, k + 1);
+                for i in 0..n {
+                    v.swap(i + k * n, i + (k + 1) * n);
+                }
+                for i in 0..m {
+                    u.swap(i + k * m, i + (k + 1) * m);
+                }
+                k += 1;
+            }
+            p -= 1;
+            continue;
+        }
+
+        steps += 1;
+        if steps > max_steps {
+            return None;
+        }
+
+        // a negligible s[ks] in the block splits it
+        let negligible = (k..p).rev().find(|&ks| {
+            let t = if ks + 1 < p { e[ks].abs() } else { zero }
+                + if ks > k { e[ks - 1].abs() } else { zero };
+            s[ks].abs() <= tiny + eps * t
+        });
+
+        match negligible {
+            Some(ks) if ks == p - 1 => {
+                // deflate the negligible s[p - 1]
+                s[p - 1] = zero;
+                let mut f = e[p - 2];
+                e[p - 2] = zero;
+                for j in (k..p - 1).rev() {
+                    let t = s[j].hypot(f);
+                    let (cs, sn) = (s[j] / t, f / t);
+                    s[j] = t;
+                    if j != k {
+                        f = -sn * e[j - 1];
+                        e[j - 1] = cs * e[j - 1];
+                    }
+                    rotate(&mut v, n, j, p - 1, cs, sn);
+                }
+            }
+            Some(ks) => {
+                // split at the negligible s[ks]
+                s[ks] = zero;
+                let mut f = e[ks];
+                e[ks] = zero;
+                for j in ks + 1..p {
+                    let t = s[j].hypot(f);
+                    let (cs, sn) = (s[j] / t, f / t);
+                    s[j] = t;
+                    f = -sn * e[j];
+                    e[j] = cs * e[j];
+                    rotate(&mut u, m, j, ks, cs, sn);
+                }
+            }
+            None => {
+                // one implicit QR step, shifted by the eigenvalue of the trailing 2 x 2 block of B^T B
+                // closer to its last element
+                let scale = [s[p - 1], s[p - 2], e[p - 2], s[k], e[k]]
+                    .iter()
+                    .fold(zero, |acc, x| acc.max(x.abs()));
+                let sp = s[p - 1] / scale;
+                let spm1 = s[p - 2] / scale;
+                let epm1 = e[p - 2] / scale;
+                let sk = s[k] / scale;
+                let ek = e[k] / scale;
+                let two = one + one;
+                let b = ((spm1 + sp) * (spm1 - sp) + epm1 * epm1) / two;
+                let c = (sp * epm1) * (sp * epm1);
+                let mut shift = zero;
+                if !b.is_zero() || !c.is_zero() {
+                    shift = (b * b + c).sqrt();
+                    if b < zero {
+                        shift = -shift;
+                    }
+                    shift = c / (b + shift);
+                }
+                let mut f = (sk + sp) * (sk - sp) + shift;
+                let mut g = sk * ek;
+
+                // chase the bulge
+                for j in k..p - 1 {
+                    let t = f.hypot(g);
+                    let (cs, sn) = (f / t, g / t);
+                    if j != k {
+                        e[j - 1] = t;
+                    }
+                    f = cs * s[j] + sn * e[j];
+                    e[j] = cs * e[j] - sn * s[j];
+                    g = sn * s[j + 1];
+                    s[j + 1] = cs * s[j + 1];
+                    rotate(&mut v, n, j, j + 1, cs, sn);
+
+                    let t = f.hypot(g);
+                    let (cs, sn) = (f / t, g / t);
+                    s[j] = t;
+                    f = cs * e[j] + sn * s[j + 1];
+                    s[j + 1] = -sn * e[j] + cs * s[j + 1];
+                    g = sn * e[j + 1];
+                    e[j + 1] = cs * e[j + 1];
+                    rotate(&mut u, m, j, j + 1, cs, sn);
+                }
+                e[p - 2] = f;
+            }
+        }
+    }
+
+    Some(Tall {
+        s,
+        u,
+        valid: vec![true; n],
+        v,
+    })
+}
+
+// thin or full SVD of the m x n matrix A (m >= n): A = U * diag(s) * V^T with U m x cols (cols is n or m),
+// s in descending order and V n x n. U and V are column-major with leading dimensions m and n.
+fn svd_tall<T: Float>(
+    m: usize,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    cols: usize,
+    factor: Factor<T>,
+) -> Option<(Vec<T>, Vec<T>, Vec<T>)> {
+    let w: Vec<T> = (0..m * n).map(|i| a[i % m + (i / m) * lda]).collect();
+    let Tall {
+        s,
+        mut u,
+        mut valid,
+        v,
+    } = factor(m, n, w)?;
+
+    u.resize(m * cols, T::zero());
+    valid.resize(cols, false);
+    if m > 0 {
+        complete_basis(m, cols, &mut u, &valid);
+    }
+
+    Some((s, u, v))
+}
+
+// the SVD of ?gesdd and ?gesvd by `factor`. a wide A is handled as A^T = V * S * U^T.
+#[allow(clippy::too_many_arguments)]
+fn svd<T: Float>(
+    factor: Factor<T>,
+    job: char,
+    m: usize,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    s: &mut [T],
+    u: &mut [T],
+    ldu: usize,
+    vt: &mut [T],
+    ldvt: usize,
+) -> i32 {
+    let k = m.min(n);
+    let full = job == 'A';
+
+    let result = if m >= n {
+        svd_tall(m, n, a, lda, if full { m } else { n }, factor)
+    } else {
+        let at: Vec<T> = (0..n * m).map(|i| a[i / n + (i % n) * lda]).collect();
+        svd_tall(n, m, &at, n, if full { n } else { m }, factor)
+    };
+    let (values, left, right) = match result {
+        Some(result) => result,
+        None => return 1,
+    };
+    s[..k].copy_from_slice(&values[..k]);
+
+    if job == 'N' {
+        return 0;
+    }
+
+    // columns of U and rows of V^T
+    let (ucols, vtrows) = if full { (m, n) } else { (k, k) };
+    if m >= n {
+        // U = left, V^T = right^T
+        for j in 0..ucols {
+            for i in 0..m {
+                u[i + j * ldu] = left[i + j * m];
+            }
+        }
+        for j in 0..n {
+            for i in 0..vtrows {
+                vt[i + j * ldvt] = right[j + i * n];
+            }
+        }
+    } else {
+        // U = right, V^T = left^T
+        for j in 0..ucols {
+            for i in 0..m {
+                u[i + j * ldu] = right[i + j * m];
+            }
+        }
+        for j in 0..n {
+            for i in 0..vtrows {
+                vt[i + j * ldvt] = left[j + i * n];
+            }
+        }
+    }
+
+    0
+}
+
+// ?gesdd by one-sided Jacobi, which is accurate for the small singular values as well
+#[allow(clippy::too_many_arguments)]
+pub fn gesdd<T: Float>(
+    job: char,
+    m: usize,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    s: &mut [T],
+    u: &mut [T],
+    ldu: usize,
+    vt: &mut [T],
+    ldvt: usize,
+) -> i32 {
+    svd(jacobi, job, m, n, a, lda, s, u, ldu, vt, ldvt)
+}
+
+// ?gesvd by Golub-Kahan-Reinsch, a different method to fall back on when gesdd does not converge
+#[allow(clippy::too_many_arguments)]
+pub fn gesvd<T: Float>(
+    job: char,
+    m: usize,
+    n: usize,
+    a: &[T],
+    lda: usize,
+    s: &mut [T],
+    u: &mut [T],
+    ldu: usize,
+    vt: &mut [T],
+    ldvt: usize,
+) -> i32 {
+    svd(golub_kahan, job, m, n, a, lda, s, u, ldu, vt, ldvt)
+}
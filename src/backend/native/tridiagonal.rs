@@ -0,0 +1,109 @@
+use num_traits::Float;
+
+// Gaussian elimination with partial pivoting, as ?gttrf.
+// an interchange of rows i and i + 1 fills in U's second super diagonal at du2[i].
+pub fn gttrf<T: Float>(
+    n: usize,
+    dl: &mut [T],
+    d: &mut [T],
+    du: &mut [T],
+    du2: &mut [T],
+    ipiv: &mut [i32],
+) -> i32 {
+    for (i, p) in ipiv.iter_mut().enumerate().take(n) {
+        *p = i as i32 + 1;
+    }
+    for v in du2.iter_mut().take(n.saturating_sub(2)) {
+        *v = T::zero();
+    }
+
+    for i in 0..n.saturating_sub(1) {
+        if d[i].abs() >= dl[i].abs() {
+            // no row interchange
+            if !d[i].is_zero() {
+                let fact = dl[i] / d[i];
+                dl[i] = fact;
+                d[i + 1] = d[i + 1] - fact * du[i];
+            }
+        } else {
+            // interchange rows i and i + 1
+            let fact = d[i] / dl[i];
+            d[i] = dl[i];
+            dl[i] = fact;
+
+            let temp = du[i];
+            du[i] = d[i + 1];
+            d[i + 1] = temp - fact * d[i + 1];
+            if i + 2 < n {
+                du2[i] = du[i + 1];
+                du[i + 1] = -fact * du[i + 1];
+            }
+            ipiv[i] = i as i32 + 2;
+        }
+    }
+
+    match (0..n).find(|&i| d[i].is_zero()) {
+        Some(i) => i as i32 + 1,
+        None => 0,
+    }
+}
+
+// solves A * X = B with the factorization from gttrf
+#[allow(clippy::too_many_arguments)]
+pub fn gttrs<T: Float>(
+    n: usize,
+    nrhs: usize,
+    dl: &[T],
+    d: &[T],
+    du: &[T],
+    du2: &[T],
+    ipiv: &[i32],
+    b: &mut [T],
+    ldb: usize,
+) -> i32 {
+    for col in 0..nrhs {
+        let x = &mut b[col * ldb..col * ldb + n];
+
+        // L * y = P * b
+        for i in 0..n.saturating_sub(1) {
+            let p = ipiv[i] as usize - 1;
+            let other = if p == i { i + 1 } else { i };
+            let temp = x[other] - dl[i] * x[p];
+            x[i] = x[p];
+            x[i + 1] = temp;
+        }
+
+        // U * x = y
+        for i in (0..n).rev() {
+            let mut v = x[i];
+            if i + 1 < n {
+                v = v - du[i] * x[i + 1];
+            }
+            if i + 2 < n {
+                v = v - du2[i] * x[i + 2];
+            }
+            x[i] = v / d[i];
+        }
+    }
+
+    0
+}
+
+pub fn gtsv<T: Float>(
+    n: usize,
+    nrhs: usize,
+    dl: &mut [T],
+    d: &mut [T],
+    du: &mut [T],
+    b: &mut [T],
+    ldb: usize,
+) -> i32 {
+    let mut du2 = vec![T::zero(); n.saturating_sub(2)];
+    let mut ipiv = vec![0; n];
+
+    let info = gttrf(n, dl, d, du, &mut du2, &mut ipiv);
+    if info != 0 {
+        return info;
+    }
+    gttrs(n, nrhs, dl, d, du, &du2, &ipiv, b, ldb)
+}
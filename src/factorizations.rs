@@ -1 +1,5 @@
+pub mod cholesky;
+pub mod eigen;
 pub mod lu;
+pub mod qr;
+pub mod svd;
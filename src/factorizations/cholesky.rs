@@ -0,0 +1,65 @@
+use crate::matrix::AsMatrix;
+
+pub use crate::backend::Uplo;
+
+pub trait CholeskyFormat<Base>
+where
+    Self: Sized,
+{
+    // require methods
+    fn new_with(mt: Base, uplo: Uplo) -> Self;
+    fn data_ref(&self) -> (&Base, &Uplo);
+    fn data_mut(&mut self) -> (&mut Base, &mut Uplo);
+
+    fn new(mt: Base) -> Self {
+        Self::new_with(mt, Default::default())
+    }
+}
+
+pub trait AsCholeskyError {
+    fn as_lapack_into_mut(&mut self) -> &mut i32;
+    fn as_lapack_into(&self) -> &i32;
+
+    fn is_invalid(&self) -> bool {
+        self.as_lapack_into() < &0
+    }
+    fn is_error(&self) -> bool {
+        self.as_lapack_into() != &0
+    }
+
+    // order of the leading minor which is not positive definite
+    fn leading_minor(&self) -> Option<usize> {
+        let info = *self.as_lapack_into();
+        if info > 0 {
+            Some(info as usize)
+        } else {
+            None
+        }
+    }
+}
+
+// following trait will be implemented for Matrix
+pub trait AsCholesky<const S: usize, Inner, CholeskyError>
+where
+    Self: AsMatrix<S, S, Inner> + Sized,
+    CholeskyError: AsCholeskyError,
+{
+    type Cholesky: CholeskyFormat<Self>;
+
+    // require methods
+    fn fact_internal(dest: &mut Self::Cholesky) -> CholeskyError;
+
+    // provide methods
+    fn cholesky_with(self, uplo: Uplo) -> Result<Self::Cholesky, CholeskyError> {
+        let mut dest = Self::Cholesky::new_with(self, uplo);
+        let err = Self::fact_internal(&mut dest);
+        if err.is_error() {
+            Err(err)
+        } else {
+            Ok(dest)
+        }
+    }
+    fn cholesky(self) -> Result<Self::Cholesky, CholeskyError> {
+        self.cholesky_with(Default::default())
+    }
+}
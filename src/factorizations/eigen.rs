@@ -0,0 +1,68 @@
+use crate::matrix::AsMatrix;
+
+// subset of eigenvalues to be computed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EigenRange<Inner> {
+    All,
+    // eigenvalues start..end in ascending order (0-based, end exclusive)
+    Index(usize, usize),
+    // eigenvalues in the half-open interval (lower, upper]
+    Value(Inner, Inner),
+}
+
+pub trait AsEigenError {
+    fn as_lapack_into_mut(&mut self) -> &mut i32;
+    fn as_lapack_into(&self) -> &i32;
+
+    fn is_invalid(&self) -> bool {
+        self.as_lapack_into() < &0
+    }
+    fn is_error(&self) -> bool {
+        self.as_lapack_into() != &0
+    }
+}
+
+// following trait will be implemented for symmetric Matrix.
+// only the lower triangle is referenced.
+pub trait AsSymmetricEigen<const S: usize, Inner, EigenError>
+where
+    Self: AsMatrix<S, S, Inner> + Sized,
+    EigenError: AsEigenError,
+{
+    // all eigenvalues in ascending order
+    type Values;
+    // all eigenvalues in ascending order and orthonormal eigenvectors
+    type Eigen;
+    // eigenvalues in the range and their eigenvectors
+    type RangeEigen;
+
+    // require methods
+    fn symmetric_eigenvalues(&self) -> Result<Self::Values, EigenError>;
+    fn symmetric_eigen(&self) -> Result<Self::Eigen, EigenError>;
+    fn symmetric_eigenvalues_range(
+        &self,
+        range: EigenRange<Inner>,
+    ) -> Result<Vec<Inner>, EigenError>;
+    fn symmetric_eigen_range(&self, range: EigenRange<Inner>)
+        -> Result<Self::RangeEigen, EigenError>;
+}
+
+// following trait will be implemented for square Matrix.
+// eigenvalues of real matrices are returned as complex numbers; conjugate pairs are adjacent.
+pub trait AsGeneralEigen<const S: usize, Inner, EigenError>
+where
+    Self: AsMatrix<S, S, Inner> + Sized,
+    EigenError: AsEigenError,
+{
+    // eigenvalues
+    type Values;
+    // eigenvalues and, if requested, left and right eigenvectors
+    type Eigen;
+    // real Schur decomposition A = Z * T * Z^T
+    type Schur;
+
+    // require methods
+    fn eigenvalues(&self) -> Result<Self::Values, EigenError>;
+    fn eigen(&self, left: bool, right: bool) -> Result<Self::Eigen, EigenError>;
+    fn schur(&self) -> Result<Self::Schur, EigenError>;
+}
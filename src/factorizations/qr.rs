@@ -0,0 +1,55 @@
+use crate::matrix::AsMatrix;
+
+pub trait QrFormat<Base, Tau>
+where
+    Self: Sized,
+    Tau: Default,
+{
+    // require methods
+    fn new_with(mt: Base, tau: Tau) -> Self;
+    fn data_ref(&self) -> (&Base, &Tau);
+    fn data_mut(&mut self) -> (&mut Base, &mut Tau);
+
+    fn new(mt: Base) -> Self
+    where
+        Tau: Default,
+    {
+        Self::new_with(mt, Default::default())
+    }
+}
+
+pub trait AsQrError {
+    fn as_lapack_into_mut(&mut self) -> &mut i32;
+    fn as_lapack_into(&self) -> &i32;
+
+    fn is_invalid(&self) -> bool {
+        self.as_lapack_into() < &0
+    }
+    fn is_error(&self) -> bool {
+        self.as_lapack_into() != &0
+    }
+}
+
+// following trait will be implemented for Matrix
+pub trait AsQr<const H: usize, const W: usize, Inner, Tau, QrError>
+where
+    Self: AsMatrix<H, W, Inner> + Sized,
+    QrError: AsQrError,
+    Tau: Default,
+{
+    type Qr: QrFormat<Self, Tau>;
+
+    // require methods
+    fn fact_internal(dest: &mut Self::Qr) -> QrError;
+
+    // provide methods
+    fn qr(self) -> Result<Self::Qr, QrError> {
+        let mut dest = Self::Qr::new(self);
+        let err = Self::fact_internal(&mut dest);
+        if err.is_error() {
+            Err(err)
+        } else {
+            Ok(dest)
+        }
+    }
+}
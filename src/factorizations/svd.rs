@@ -0,0 +1,32 @@
+use crate::matrix::AsMatrix;
+
+pub trait AsSvdError {
+    fn as_lapack_into_mut(&mut self) -> &mut i32;
+    fn as_lapack_into(&self) -> &i32;
+
+    fn is_invalid(&self) -> bool {
+        self.as_lapack_into() < &0
+    }
+    fn is_error(&self) -> bool {
+        self.as_lapack_into() != &0
+    }
+}
+
+// following trait will be implemented for Matrix
+pub trait AsSvd<const H: usize, const W: usize, Inner, SvdError>
+where
+    Self: AsMatrix<H, W, Inner> + Sized,
+    SvdError: AsSvdError,
+{
+    // singular values only, in descending order
+    type Values;
+    // U (H x min(H, W)), singular values and V^T (min(H, W) x W)
+    type Thin;
+    // U (H x H), singular values and V^T (W x W)
+    type Full;
+
+    // require methods
+    fn singular_values(&self) -> Result<Self::Values, SvdError>;
+    fn svd_thin(&self) -> Result<Self::Thin, SvdError>;
+    fn svd_full(&self) -> Result<Self::Full, SvdError>;
+}
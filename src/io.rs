@@ -0,0 +1,5 @@
+pub mod delimited;
+pub mod matrix_market;
+pub mod npy;
+#[cfg(feature = "serde")]
+pub mod serialize;
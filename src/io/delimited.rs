@@ -0,0 +1,391 @@
+use crate::matrix::general::GeneralMatrix;
+use crate::matrix::AsMatrix;
+
+use std::fmt::{Display, Formatter, Result};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+// delimited text: one line per row (or per column), CSV or whitespace-separated.
+// lines and columns in errors are 1-based; columns count fields, not characters.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelimitedLayout {
+    // each line is a row of the matrix
+    RowMajor,
+    // each line is a column of the matrix
+    ColumnMajor,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelimitedOptions {
+    // None splits on runs of whitespace and writes a single space
+    pub delimiter: Option<char>,
+    // lines skipped at the beginning, e.g. a header row. not written.
+    pub skip_lines: usize,
+    // lines starting with this character are ignored, as are blank lines
+    pub comment: Option<char>,
+    pub layout: DelimitedLayout,
+}
+
+impl DelimitedOptions {
+    #[allow(unused)]
+    pub fn csv() -> Self {
+        Self {
+            delimiter: Some(','),
+            skip_lines: 0,
+            comment: None,
+            layout: DelimitedLayout::RowMajor,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn whitespace() -> Self {
+        Self {
+            delimiter: None,
+            skip_lines: 0,
+            comment: Some('#'),
+            layout: DelimitedLayout::RowMajor,
+        }
+    }
+}
+
+impl Default for DelimitedOptions {
+    fn default() -> Self {
+        Self::csv()
+    }
+}
+
+#[derive(Debug)]
+pub enum DelimitedError {
+    Io(io::Error),
+    // a field that is not a number of the element type
+    Parse {
+        line: usize,
+        column: usize,
+        field: String,
+    },
+    // a line with the wrong number of fields
+    ColumnCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    // the number of data lines does not match the matrix
+    RowCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for DelimitedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Parse {
+                line,
+                column,
+                field,
+            } => write!(
+                f,
+                "line {}, column {}: invalid number `{}`",
+                line, column, field
+            ),
+            Self::ColumnCount {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} fields, but found {}",
+                line, expected, found
+            ),
+            Self::RowCount { expected, found } => write!(
+                f,
+                "Expected {} data lines, but the input contains {}.",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for DelimitedError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+pub trait DelimitedElement: Clone + FromStr {
+    // locale-free text that parses back to the same value
+    fn to_field(&self) -> String;
+}
+
+macro_rules! impl_macro {
+    ($type: ty, $fmt: expr) => {
+        impl DelimitedElement for $type {
+            fn to_field(&self) -> String {
+                format!($fmt, self)
+            }
+        }
+    };
+}
+
+// Debug of floats is the shortest round-trip representation, switching to
+// exponent notation for very large and small magnitudes
+impl_macro!(f32, "{:?}");
+impl_macro!(f64, "{:?}");
+impl_macro!(i32, "{}");
+impl_macro!(i64, "{}");
+
+pub trait Delimited: Sized {
+    fn read_delimited<R: BufRead>(
+        reader: R,
+        options: &DelimitedOptions,
+    ) -> core::result::Result<Self, DelimitedError>;
+
+    fn write_delimited<W: Write>(&self, writer: W, options: &DelimitedOptions) -> io::Result<()>;
+
+    #[allow(unused)]
+    fn load_delimited(
+        path: impl AsRef<Path>,
+        options: &DelimitedOptions,
+    ) -> core::result::Result<Self, DelimitedError> {
+        Self::read_delimited(BufReader::new(File::open(path)?), options)
+    }
+
+    #[allow(unused)]
+    fn save_delimited(&self, path: impl AsRef<Path>, options: &DelimitedOptions) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_delimited(&mut writer, options)?;
+        writer.flush()
+    }
+}
+
+impl<const H: usize, const W: usize, Inner> Delimited for GeneralMatrix<H, W, Inner>
+where
+    Inner: DelimitedElement,
+{
+    fn read_delimited<R: BufRead>(
+        reader: R,
+        options: &DelimitedOptions,
+    ) -> core::result::Result<Self, DelimitedError> {
+        // (lines, fields per line) of the file
+        let (lines, fields) = match options.layout {
+            DelimitedLayout::RowMajor => (H, W),
+            DelimitedLayout::ColumnMajor => (W, H),
+        };
+
+        let mut data = Vec::with_capacity(H * W);
+        let mut found = 0;
+        for (i, text) in reader.lines().enumerate().skip(options.skip_lines) {
+            let text = text?;
+            let line = i + 1;
+
+            let trimmed = text.trim();
+            if trimmed.is_empty() || options.comment.map_or(false, |c| trimmed.starts_with(c)) {
+                continue;
+            }
+            found += 1;
+            if found > lines {
+                // keep counting for the error
+                continue;
+            }
+
+            let values: Vec<&str> = match options.delimiter {
+                Some(d) => trimmed.split(d).map(str::trim).collect(),
+                None => trimmed.split_whitespace().collect(),
+            };
+            if values.len() != fields {
+                return Err(DelimitedError::ColumnCount {
+                    line,
+                    expected: fields,
+                    found: values.len(),
+                });
+            }
+
+            for (column, field) in values.into_iter().enumerate() {
+                let value: Inner = field.parse().map_err(|_| DelimitedError::Parse {
+                    line,
+                    column: column + 1,
+                    field: field.to_string(),
+                })?;
+                data.push(value);
+            }
+        }
+
+        if found != lines {
+            return Err(DelimitedError::RowCount {
+                expected: lines,
+                found,
+            });
+        }
+
+        Ok(match options.layout {
+            DelimitedLayout::RowMajor => {
+                GeneralMatrix::by_f(|col, row| data[row * W + col].clone())
+            }
+            DelimitedLayout::ColumnMajor => {
+                GeneralMatrix::by_f(|col, row| data[col * H + row].clone())
+            }
+        })
+    }
+
+    fn write_delimited<Wr: Write>(
+        &self,
+        mut writer: Wr,
+        options: &DelimitedOptions,
+    ) -> io::Result<()> {
+        let delimiter = options.delimiter.unwrap_or(' ').to_string();
+        let (lines, fields) = match options.layout {
+            DelimitedLayout::RowMajor => (H, W),
+            DelimitedLayout::ColumnMajor => (W, H),
+        };
+
+        for i in 0..lines {
+            let line: Vec<String> = (0..fields)
+                .map(|j| match options.layout {
+                    DelimitedLayout::RowMajor => self.at(i, j).to_field(),
+                    DelimitedLayout::ColumnMajor => self.at(j, i).to_field(),
+                })
+                .collect();
+            writeln!(writer, "{}", line.join(&delimiter))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write<T: Delimited>(m: &T, options: &DelimitedOptions) -> String {
+        let mut out = Vec::new();
+        m.write_delimited(&mut out, options).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn read_csv() {
+        let options = DelimitedOptions {
+            skip_lines: 1,
+            comment: Some('#'),
+            ..DelimitedOptions::csv()
+        };
+        let m: GeneralMatrix<2, 3, f64> = GeneralMatrix::read_delimited(
+            "a,b,c\n# comment\n1, 2.5 ,-3e2\n\n4,5,6\n".as_bytes(),
+            &options,
+        )
+        .unwrap();
+
+        assert_eq!(
+            m,
+            GeneralMatrix::new_row_major([[1., 2.5, -300.], [4., 5., 6.]])
+        );
+    }
+
+    #[test]
+    fn read_whitespace() {
+        let text = "  1\t2   3\n# comment\n4 5 6\n";
+        let m: GeneralMatrix<2, 3, i32> =
+            GeneralMatrix::read_delimited(text.as_bytes(), &DelimitedOptions::whitespace())
+                .unwrap();
+        assert_eq!(m, GeneralMatrix::new_row_major([[1, 2, 3], [4, 5, 6]]));
+
+        // the same lines as columns
+        let options = DelimitedOptions {
+            layout: DelimitedLayout::ColumnMajor,
+            ..DelimitedOptions::whitespace()
+        };
+        let t: GeneralMatrix<3, 2, i32> =
+            GeneralMatrix::read_delimited(text.as_bytes(), &options).unwrap();
+        assert_eq!(t, m.transposed());
+    }
+
+    #[test]
+    fn errors() {
+        let options = DelimitedOptions::csv();
+        type M = GeneralMatrix<2, 2, f64>;
+
+        match M::read_delimited("1,2\n3,x\n".as_bytes(), &options) {
+            Err(DelimitedError::Parse {
+                line,
+                column,
+                field,
+            }) => {
+                assert_eq!((line, column, field.as_str()), (2, 2, "x"))
+            }
+            other => panic!("{:?}", other),
+        }
+        // a decimal comma is not a number
+        assert!(matches!(
+            M::read_delimited(
+                "1;2\n3;4,5\n".as_bytes(),
+                &DelimitedOptions {
+                    delimiter: Some(';'),
+                    ..DelimitedOptions::csv()
+                }
+            ),
+            Err(DelimitedError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            M::read_delimited("1,2\n3,4,5\n".as_bytes(), &options),
+            Err(DelimitedError::ColumnCount {
+                line: 2,
+                expected: 2,
+                found: 3
+            })
+        ));
+        assert!(matches!(
+            M::read_delimited("1,2\n".as_bytes(), &options),
+            Err(DelimitedError::RowCount {
+                expected: 2,
+                found: 1
+            })
+        ));
+        assert!(matches!(
+            M::read_delimited("1,2\n3,4\n5,6\n7,8\n".as_bytes(), &options),
+            Err(DelimitedError::RowCount {
+                expected: 2,
+                found: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn round_trip() {
+        let m: GeneralMatrix<2, 3, f64> =
+            GeneralMatrix::new_row_major([[0.1, 1. / 3., -0.], [f64::MAX, 5e-324, 1e300]]);
+
+        for options in [DelimitedOptions::csv(), DelimitedOptions::whitespace()] {
+            let text = write(&m, &options);
+            let back: GeneralMatrix<2, 3, f64> =
+                GeneralMatrix::read_delimited(text.as_bytes(), &options).unwrap();
+            let bits = |m: &GeneralMatrix<2, 3, f64>| {
+                m.as_slice().iter().map(|v| v.to_bits()).collect::<Vec<_>>()
+            };
+            assert_eq!(bits(&back), bits(&m));
+        }
+
+        assert_eq!(
+            write(&m, &DelimitedOptions::csv()),
+            "0.1,0.3333333333333333,-0.0\n1.7976931348623157e308,5e-324,1e300\n"
+        );
+
+        let i: GeneralMatrix<2, 2, i64> = GeneralMatrix::new_row_major([[1, -2], [3, i64::MIN]]);
+        let options = DelimitedOptions {
+            layout: DelimitedLayout::ColumnMajor,
+            ..DelimitedOptions::whitespace()
+        };
+        assert_eq!(write(&i, &options), format!("1 3\n-2 {}\n", i64::MIN));
+        assert_eq!(
+            GeneralMatrix::<2, 2, i64>::read_delimited(write(&i, &options).as_bytes(), &options)
+                .unwrap(),
+            i
+        );
+    }
+}
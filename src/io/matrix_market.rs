@@ -0,0 +1,1042 @@
+use crate::matrix::dynamic::DynMatrix;
+use crate::matrix::general::hermitian::Conjugate;
+use crate::matrix::general::GeneralMatrix;
+use crate::matrix::tridiagonal::TridiagonalMatrix;
+use crate::matrix::{AsMatrix, Complex};
+
+use num_traits::{One, Zero};
+
+use std::fmt::{Display, Formatter, Result};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::ops::{AddAssign, Neg};
+use std::path::Path;
+
+// Matrix Market exchange format (https://math.nist.gov/MatrixMarket/formats.html).
+// indices in files and in errors are 1-based.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixMarketFormat {
+    // "i j value" lines for the stored entries only
+    Coordinate,
+    // every value, column-major
+    Array,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixMarketField {
+    Real,
+    Integer,
+    Complex,
+    // coordinate only. every listed entry is one.
+    Pattern,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixMarketSymmetry {
+    General,
+    // only the lower triangle is stored
+    Symmetric,
+    // only the strictly lower triangle is stored
+    SkewSymmetric,
+    // complex only. the lower triangle is stored and mirrored conjugated.
+    Hermitian,
+}
+
+impl MatrixMarketFormat {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "coordinate" => Some(Self::Coordinate),
+            "array" => Some(Self::Array),
+            _ => None,
+        }
+    }
+}
+
+impl MatrixMarketField {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "real" | "double" => Some(Self::Real),
+            "integer" => Some(Self::Integer),
+            "complex" => Some(Self::Complex),
+            "pattern" => Some(Self::Pattern),
+            _ => None,
+        }
+    }
+
+    // number of value tokens per entry
+    fn value_count(self) -> usize {
+        match self {
+            Self::Complex => 2,
+            Self::Pattern => 0,
+            _ => 1,
+        }
+    }
+}
+
+impl MatrixMarketSymmetry {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "general" => Some(Self::General),
+            "symmetric" => Some(Self::Symmetric),
+            "skew-symmetric" => Some(Self::SkewSymmetric),
+            "hermitian" => Some(Self::Hermitian),
+            _ => None,
+        }
+    }
+
+    // the first stored row of column `col`
+    fn first_row(self, col: usize) -> usize {
+        match self {
+            Self::General => 0,
+            Self::Symmetric | Self::Hermitian => col,
+            Self::SkewSymmetric => col + 1,
+        }
+    }
+}
+
+impl Display for MatrixMarketFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Coordinate => write!(f, "coordinate"),
+            Self::Array => write!(f, "array"),
+        }
+    }
+}
+
+impl Display for MatrixMarketField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Real => write!(f, "real"),
+            Self::Integer => write!(f, "integer"),
+            Self::Complex => write!(f, "complex"),
+            Self::Pattern => write!(f, "pattern"),
+        }
+    }
+}
+
+impl Display for MatrixMarketSymmetry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::General => write!(f, "general"),
+            Self::Symmetric => write!(f, "symmetric"),
+            Self::SkewSymmetric => write!(f, "skew-symmetric"),
+            Self::Hermitian => write!(f, "hermitian"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum MatrixMarketError {
+    Io(io::Error),
+    // malformed file
+    Parse {
+        line: usize,
+        message: String,
+    },
+    // the element type cannot hold values of this field, e.g. complex into f64
+    UnsupportedField(MatrixMarketField),
+    // (height, width) of the file do not match the shape of the matrix type
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    // a nonzero entry outside the three bands of a tridiagonal matrix
+    OutOfBand {
+        line: usize,
+        row: usize,
+        col: usize,
+    },
+}
+
+impl Display for MatrixMarketError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            Self::UnsupportedField(field) => {
+                write!(f, "{} values cannot be stored in this element type", field)
+            }
+            Self::DimensionMismatch { expected, found } => write!(
+                f,
+                "Expected a {}x{} matrix, but the file contains a {}x{} matrix.",
+                expected.0, expected.1, found.0, found.1
+            ),
+            Self::OutOfBand { line, row, col } => write!(
+                f,
+                "line {}: entry ({}, {}) is outside the tridiagonal band",
+                line, row, col
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for MatrixMarketError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+fn parse_error(line: usize, message: impl Into<String>) -> MatrixMarketError {
+    MatrixMarketError::Parse {
+        line,
+        message: message.into(),
+    }
+}
+
+// element types that can be read from and written to Matrix Market files
+pub trait MatrixMarketElement:
+    Clone + Zero + One + AddAssign + Neg<Output = Self> + Conjugate
+{
+    // field written to the header
+    const FIELD: MatrixMarketField;
+
+    // whether values of `field` can be read into Self
+    fn accepts(field: MatrixMarketField) -> bool;
+
+    // the value tokens of one entry: two for complex, one otherwise
+    fn parse_tokens(tokens: &[&str]) -> Option<Self>;
+
+    fn to_tokens(&self) -> String;
+}
+
+macro_rules! impl_float {
+    ($type: ty) => {
+        impl MatrixMarketElement for $type {
+            const FIELD: MatrixMarketField = MatrixMarketField::Real;
+
+            fn accepts(field: MatrixMarketField) -> bool {
+                field != MatrixMarketField::Complex
+            }
+
+            fn parse_tokens(tokens: &[&str]) -> Option<Self> {
+                match tokens {
+                    [v] => v.parse().ok(),
+                    _ => None,
+                }
+            }
+
+            // the shortest representation that parses back to the same value
+            fn to_tokens(&self) -> String {
+                format!("{:e}", self)
+            }
+        }
+
+        impl MatrixMarketElement for Complex<$type> {
+            const FIELD: MatrixMarketField = MatrixMarketField::Complex;
+
+            fn accepts(_: MatrixMarketField) -> bool {
+                true
+            }
+
+            fn parse_tokens(tokens: &[&str]) -> Option<Self> {
+                match tokens {
+                    [re] => Some(Complex::new(re.parse().ok()?, <$type>::zero())),
+                    [re, im] => Some(Complex::new(re.parse().ok()?, im.parse().ok()?)),
+                    _ => None,
+                }
+            }
+
+            fn to_tokens(&self) -> String {
+                format!("{:e} {:e}", self.re, self.im)
+            }
+        }
+    };
+}
+
+macro_rules! impl_integer {
+    ($type: ty) => {
+        impl MatrixMarketElement for $type {
+            const FIELD: MatrixMarketField = MatrixMarketField::Integer;
+
+            fn accepts(field: MatrixMarketField) -> bool {
+                matches!(
+                    field,
+                    MatrixMarketField::Integer | MatrixMarketField::Pattern
+                )
+            }
+
+            fn parse_tokens(tokens: &[&str]) -> Option<Self> {
+                match tokens {
+                    [v] => v.parse().ok(),
+                    _ => None,
+                }
+            }
+
+            fn to_tokens(&self) -> String {
+                format!("{}", self)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+impl_integer!(i32);
+impl_integer!(i64);
+
+// one value of the file, 0-based. mirrored entries carry the line of their origin.
+struct Entry<Inner> {
+    line: usize,
+    row: usize,
+    col: usize,
+    value: Inner,
+}
+
+struct Parsed<Inner> {
+    // line of `rows columns [entries]`
+    size_line: usize,
+    height: usize,
+    width: usize,
+    entries: Vec<Entry<Inner>>,
+}
+
+impl<Inner> Parsed<Inner>
+where
+    Inner: MatrixMarketElement,
+{
+    // duplicated coordinate entries are summed
+    // the allocation can still fail for sizes that passed parse()
+    fn into_dyn(self) -> core::result::Result<DynMatrix<Inner>, MatrixMarketError> {
+        let len = self.height * self.width;
+        let mut inner = Vec::new();
+        inner.try_reserve_exact(len).map_err(|_| {
+            parse_error(
+                self.size_line,
+                format!(
+                    "a {}x{} matrix does not fit in memory",
+                    self.height, self.width
+                ),
+            )
+        })?;
+        inner.resize(len, Inner::zero());
+
+        let mut ret = DynMatrix::new_col_major(self.height, self.width, inner)
+            .expect("length is height * width");
+        for e in self.entries {
+            *ret.at_mut(e.row, e.col) += e.value;
+        }
+        Ok(ret)
+    }
+}
+
+// lines of the file, skipping comments and blank lines
+struct Lines<R> {
+    reader: R,
+    line: usize,
+}
+
+impl<R> Lines<R>
+where
+    R: BufRead,
+{
+    fn next_raw(&mut self) -> io::Result<Option<String>> {
+        let mut buf = String::new();
+        if self.reader.read_line(&mut buf)? == 0 {
+            return Ok(None);
+        }
+        self.line += 1;
+        Ok(Some(buf))
+    }
+
+    fn next_data(&mut self) -> io::Result<Option<(usize, String)>> {
+        while let Some(text) = self.next_raw()? {
+            let trimmed = text.trim();
+            if !trimmed.is_empty() && !trimmed.starts_with('%') {
+                return Ok(Some((self.line, trimmed.to_string())));
+            }
+        }
+        Ok(None)
+    }
+}
+
+// `expected` is the shape of fixed-size targets, checked before any entry is read
+fn parse<Inner, R>(
+    reader: R,
+    expected: Option<(usize, usize)>,
+) -> core::result::Result<Parsed<Inner>, MatrixMarketError>
+where
+    Inner: MatrixMarketElement,
+    R: BufRead,
+{
+    use MatrixMarketField as Field;
+    use MatrixMarketFormat as Format;
+    use MatrixMarketSymmetry as Symmetry;
+
+    let mut lines = Lines { reader, line: 0 };
+
+    let header = lines
+        .next_raw()?
+        .ok_or_else(|| parse_error(1, "missing header"))?;
+    let tokens: Vec<String> = header
+        .split_whitespace()
+        .map(|t| t.to_ascii_lowercase())
+        .collect();
+    let tokens: Vec<&str> = tokens.iter().map(String::as_str).collect();
+    let (format, field, symmetry) = match tokens[..] {
+        ["%%matrixmarket", "matrix", format, field, symmetry] => (
+            Format::parse(format)
+                .ok_or_else(|| parse_error(1, format!("unknown format `{}`", format)))?,
+            Field::parse(field)
+                .ok_or_else(|| parse_error(1, format!("unknown field `{}`", field)))?,
+            Symmetry::parse(symmetry)
+                .ok_or_else(|| parse_error(1, format!("unknown symmetry `{}`", symmetry)))?,
+        ),
+        ["%%matrixmarket", object, ..] if object != "matrix" => {
+            return Err(parse_error(1, format!("unsupported object `{}`", object)))
+        }
+        _ => {
+            return Err(parse_error(
+                1,
+                "expected `%%MatrixMarket matrix <format> <field> <symmetry>`",
+            ))
+        }
+    };
+    if format == Format::Array && field == Field::Pattern {
+        return Err(parse_error(1, "pattern is only valid for coordinate"));
+    }
+    if symmetry == Symmetry::Hermitian && field != Field::Complex {
+        return Err(parse_error(1, "hermitian is only valid for complex"));
+    }
+    if !Inner::accepts(field) {
+        return Err(MatrixMarketError::UnsupportedField(field));
+    }
+
+    let (line, size) = lines
+        .next_data()?
+        .ok_or_else(|| parse_error(lines.line + 1, "missing size line"))?;
+    let size: Vec<usize> = size
+        .split_whitespace()
+        .map(str::parse)
+        .collect::<core::result::Result<_, _>>()
+        .map_err(|_| parse_error(line, "invalid size line"))?;
+    let (height, width, count) = match (format, &size[..]) {
+        (Format::Coordinate, &[height, width, count]) => (height, width, Some(count)),
+        (Format::Array, &[height, width]) => {
+            let count = match symmetry {
+                Symmetry::General => height.checked_mul(width),
+                Symmetry::Symmetric | Symmetry::Hermitian => height
+                    .checked_add(1)
+                    .and_then(|n| n.checked_mul(height))
+                    .map(|n| n / 2),
+                Symmetry::SkewSymmetric => {
+                    height.checked_mul(height.saturating_sub(1)).map(|n| n / 2)
+                }
+            };
+            (height, width, count)
+        }
+        (Format::Coordinate, _) => {
+            return Err(parse_error(line, "expected `rows columns entries`"))
+        }
+        (Format::Array, _) => return Err(parse_error(line, "expected `rows columns`")),
+    };
+    // the matrix is stored densely, so height * width elements must fit in an allocation as well
+    let bytes = height
+        .checked_mul(width)
+        .and_then(|n| n.checked_mul(core::mem::size_of::<Inner>()))
+        .filter(|&n| n <= isize::MAX as usize);
+    let count = match (count, bytes) {
+        (Some(count), Some(_)) => count,
+        _ => {
+            return Err(parse_error(
+                line,
+                format!("a {}x{} matrix is too large", height, width),
+            ))
+        }
+    };
+    if let Some(expected) = expected {
+        if (height, width) != expected {
+            return Err(MatrixMarketError::DimensionMismatch {
+                expected,
+                found: (height, width),
+            });
+        }
+    }
+    if symmetry != Symmetry::General && height != width {
+        return Err(parse_error(
+            line,
+            format!("a {} matrix must be square", symmetry),
+        ));
+    }
+
+    // array files list the stored positions column by column
+    let mut positions =
+        (0..width).flat_map(|col| (symmetry.first_row(col)..height).map(move |row| (row, col)));
+
+    // `count` comes from the file and is not trusted for preallocation
+    let mut entries = Vec::new();
+    for found in 0..count {
+        let (line, text) = lines.next_data()?.ok_or_else(|| {
+            parse_error(
+                lines.line + 1,
+                format!(
+                    "expected {} entries, but the file ends after {}",
+                    count, found
+                ),
+            )
+        })?;
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+
+        let (row, col, values) = match format {
+            Format::Coordinate => {
+                if tokens.len() < 2 {
+                    return Err(parse_error(line, "expected `row column [value]`"));
+                }
+                let index = |s: &str| {
+                    s.parse::<usize>()
+                        .map_err(|_| parse_error(line, format!("invalid index `{}`", s)))
+                };
+                let (row, col) = (index(tokens[0])?, index(tokens[1])?);
+                if row == 0 || row > height || col == 0 || col > width {
+                    return Err(parse_error(
+                        line,
+                        format!(
+                            "index ({}, {}) is out of range for a {}x{} matrix",
+                            row, col, height, width
+                        ),
+                    ));
+                }
+                (row - 1, col - 1, &tokens[2..])
+            }
+            Format::Array => {
+                let (row, col) = positions.next().unwrap();
+                (row, col, &tokens[..])
+            }
+        };
+
+        if values.len() != field.value_count() {
+            return Err(parse_error(
+                line,
+                format!(
+                    "expected {} value(s), found {}",
+                    field.value_count(),
+                    values.len()
+                ),
+            ));
+        }
+        let value = if field == Field::Pattern {
+            Inner::one()
+        } else {
+            Inner::parse_tokens(values)
+                .ok_or_else(|| parse_error(line, format!("invalid {} value", field)))?
+        };
+
+        match symmetry {
+            Symmetry::Symmetric | Symmetry::Hermitian if row < col => {
+                return Err(parse_error(
+                    line,
+                    format!("a {} matrix stores only the lower triangle", symmetry),
+                ))
+            }
+            Symmetry::SkewSymmetric if row <= col => {
+                return Err(parse_error(
+                    line,
+                    "a skew-symmetric matrix stores only the strictly lower triangle",
+                ))
+            }
+            _ => {}
+        }
+
+        if row != col {
+            let mirrored = match symmetry {
+                Symmetry::General => None,
+                Symmetry::Symmetric => Some(value.clone()),
+                Symmetry::SkewSymmetric => Some(-value.clone()),
+                Symmetry::Hermitian => Some(value.conjugate()),
+            };
+            if let Some(value) = mirrored {
+                entries.push(Entry {
+                    line,
+                    row: col,
+                    col: row,
+                    value,
+                });
+            }
+        }
+        entries.push(Entry {
+            line,
+            row,
+            col,
+            value,
+        });
+    }
+
+    if let Some((line, _)) = lines.next_data()? {
+        return Err(parse_error(
+            line,
+            format!("more than the declared {} entries", count),
+        ));
+    }
+
+    Ok(Parsed {
+        size_line: line,
+        height,
+        width,
+        entries,
+    })
+}
+
+fn write_header<Inner>(writer: &mut impl Write, format: MatrixMarketFormat) -> io::Result<()>
+where
+    Inner: MatrixMarketElement,
+{
+    writeln!(
+        writer,
+        "%%MatrixMarket matrix {} {} general",
+        format,
+        Inner::FIELD
+    )
+}
+
+fn write_array<'a, Inner>(
+    mut writer: impl Write,
+    height: usize,
+    width: usize,
+    at: impl Fn(usize, usize) -> &'a Inner,
+) -> io::Result<()>
+where
+    Inner: MatrixMarketElement + 'a,
+{
+    write_header::<Inner>(&mut writer, MatrixMarketFormat::Array)?;
+    writeln!(writer, "{} {}", height, width)?;
+    for col in 0..width {
+        for row in 0..height {
+            writeln!(writer, "{}", at(row, col).to_tokens())?;
+        }
+    }
+    Ok(())
+}
+
+// `entries` are 0-based
+fn write_coordinate<Inner>(
+    mut writer: impl Write,
+    height: usize,
+    width: usize,
+    entries: Vec<(usize, usize, &Inner)>,
+) -> io::Result<()>
+where
+    Inner: MatrixMarketElement,
+{
+    write_header::<Inner>(&mut writer, MatrixMarketFormat::Coordinate)?;
+    writeln!(writer, "{} {} {}", height, width, entries.len())?;
+    for (row, col, value) in entries {
+        writeln!(writer, "{} {} {}", row + 1, col + 1, value.to_tokens())?;
+    }
+    Ok(())
+}
+
+pub trait MatrixMarket: Sized {
+    fn read_matrix_market<R: BufRead>(reader: R) -> core::result::Result<Self, MatrixMarketError>;
+
+    // writes a general matrix. coordinate files list the nonzero (for tridiagonal, the banded) entries.
+    fn write_matrix_market<W: Write>(
+        &self,
+        writer: W,
+        format: MatrixMarketFormat,
+    ) -> io::Result<()>;
+
+    #[allow(unused)]
+    fn load_matrix_market(path: impl AsRef<Path>) -> core::result::Result<Self, MatrixMarketError> {
+        Self::read_matrix_market(BufReader::new(File::open(path)?))
+    }
+
+    #[allow(unused)]
+    fn save_matrix_market(
+        &self,
+        path: impl AsRef<Path>,
+        format: MatrixMarketFormat,
+    ) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_matrix_market(&mut writer, format)?;
+        writer.flush()
+    }
+}
+
+impl<Inner> MatrixMarket for DynMatrix<Inner>
+where
+    Inner: MatrixMarketElement,
+{
+    fn read_matrix_market<R: BufRead>(reader: R) -> core::result::Result<Self, MatrixMarketError> {
+        parse::<Inner, R>(reader, None)?.into_dyn()
+    }
+
+    fn write_matrix_market<W: Write>(
+        &self,
+        writer: W,
+        format: MatrixMarketFormat,
+    ) -> io::Result<()> {
+        let (height, width) = self.size();
+        match format {
+            MatrixMarketFormat::Array => {
+                write_array(writer, height, width, |row, col| self.at(row, col))
+            }
+            MatrixMarketFormat::Coordinate => {
+                let entries = (0..width)
+                    .flat_map(|col| (0..height).map(move |row| (row, col, self.at(row, col))))
+                    .filter(|(_, _, v)| !v.is_zero())
+                    .collect();
+                write_coordinate(writer, height, width, entries)
+            }
+        }
+    }
+}
+
+impl<const H: usize, const W: usize, Inner> MatrixMarket for GeneralMatrix<H, W, Inner>
+where
+    Inner: MatrixMarketElement,
+{
+    fn read_matrix_market<R: BufRead>(reader: R) -> core::result::Result<Self, MatrixMarketError> {
+        let parsed = parse::<Inner, R>(reader, Some((H, W)))?;
+
+        let dense = parsed.into_dyn()?;
+        Ok(GeneralMatrix::by_f(|col, row| dense.at(row, col).clone()))
+    }
+
+    fn write_matrix_market<Wr: Write>(
+        &self,
+        writer: Wr,
+        format: MatrixMarketFormat,
+    ) -> io::Result<()> {
+        match format {
+            MatrixMarketFormat::Array => write_array(writer, H, W, |row, col| self.at(row, col)),
+            MatrixMarketFormat::Coordinate => {
+                let entries = (0..W)
+                    .flat_map(|col| (0..H).map(move |row| (row, col, self.at(row, col))))
+                    .filter(|(_, _, v)| !v.is_zero())
+                    .collect();
+                write_coordinate(writer, H, W, entries)
+            }
+        }
+    }
+}
+
+impl<const S: usize, Inner> MatrixMarket for TridiagonalMatrix<S, Inner>
+where
+    Inner: MatrixMarketElement,
+    [(); S - 1]:,
+{
+    // zero entries outside the bands are accepted
+    fn read_matrix_market<R: BufRead>(reader: R) -> core::result::Result<Self, MatrixMarketError> {
+        let parsed = parse::<Inner, R>(reader, Some((S, S)))?;
+
+        let mut ret = Self::by(Inner::zero());
+        for e in parsed.entries {
+            if e.row == e.col {
+                *ret.at_diagonal_mut(e.row) += e.value;
+            } else if e.row + 1 == e.col {
+                *ret.at_upper_mut(e.row) += e.value;
+            } else if e.row == e.col + 1 {
+                *ret.at_lower_mut(e.col) += e.value;
+            } else if !e.value.is_zero() {
+                return Err(MatrixMarketError::OutOfBand {
+                    line: e.line,
+                    row: e.row + 1,
+                    col: e.col + 1,
+                });
+            }
+        }
+        Ok(ret)
+    }
+
+    fn write_matrix_market<W: Write>(
+        &self,
+        writer: W,
+        format: MatrixMarketFormat,
+    ) -> io::Result<()> {
+        match format {
+            MatrixMarketFormat::Array => write_array(writer, S, S, |row, col| self.at(row, col)),
+            MatrixMarketFormat::Coordinate => {
+                let entries = (0..S)
+                    .flat_map(|col| {
+                        (col.saturating_sub(1)..(col + 2).min(S))
+                            .map(move |row| (row, col, self.at(row, col)))
+                    })
+                    .collect();
+                write_coordinate(writer, S, S, entries)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read<T: MatrixMarket>(s: &str) -> core::result::Result<T, MatrixMarketError> {
+        T::read_matrix_market(s.as_bytes())
+    }
+
+    fn write<T: MatrixMarket>(m: &T, format: MatrixMarketFormat) -> String {
+        let mut out = Vec::new();
+        m.write_matrix_market(&mut out, format).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    fn parse_error_line<T: MatrixMarket + core::fmt::Debug>(s: &str) -> usize {
+        match read::<T>(s) {
+            Err(MatrixMarketError::Parse { line, .. }) => line,
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn coordinate_general() {
+        let m: GeneralMatrix<2, 3, f64> = read(
+            "%%MatrixMarket matrix coordinate real general\n\
+             % a comment\n\
+             \n\
+             2 3 4\n\
+             1 1 1.5\n\
+             2 3 -2e-3\n\
+             1 2 4\n\
+             1 2 1\n",
+        )
+        .unwrap();
+
+        // duplicates are summed
+        assert_eq!(
+            m,
+            GeneralMatrix::new_row_major([[1.5, 5., 0.], [0., 0., -2e-3]])
+        );
+    }
+
+    #[test]
+    fn array_general() {
+        let m: DynMatrix<i32> =
+            read("%%MatrixMarket matrix array integer general\n2 2\n1\n2\n3\n4\n").unwrap();
+        assert_eq!(m, DynMatrix::new_row_major(2, 2, vec![1, 3, 2, 4]).unwrap());
+
+        // integers into a float matrix, case-insensitive header
+        let f: GeneralMatrix<2, 2, f32> =
+            read("%%MatrixMarket MATRIX Array Integer General\n2 2\n1\n2\n3\n4\n").unwrap();
+        assert_eq!(f, GeneralMatrix::new_row_major([[1., 3.], [2., 4.]]));
+    }
+
+    #[test]
+    fn symmetry() {
+        let s: GeneralMatrix<3, 3, f64> =
+            read("%%MatrixMarket matrix coordinate real symmetric\n3 3 3\n1 1 1\n3 1 2\n3 2 3\n")
+                .unwrap();
+        assert_eq!(
+            s,
+            GeneralMatrix::new_row_major([[1., 0., 2.], [0., 0., 3.], [2., 3., 0.]])
+        );
+
+        let k: GeneralMatrix<3, 3, i64> =
+            read("%%MatrixMarket matrix array integer skew-symmetric\n3 3\n1\n2\n3\n").unwrap();
+        assert_eq!(
+            k,
+            GeneralMatrix::new_row_major([[0, -1, -2], [1, 0, -3], [2, 3, 0]])
+        );
+
+        let a: GeneralMatrix<2, 2, f64> =
+            read("%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n3\n").unwrap();
+        assert_eq!(a, GeneralMatrix::new_row_major([[1., 2.], [2., 3.]]));
+    }
+
+    #[test]
+    fn complex_and_pattern() {
+        let h: GeneralMatrix<2, 2, Complex<f64>> =
+            read("%%MatrixMarket matrix coordinate complex hermitian\n2 2 2\n1 1 2 0\n2 1 1 1\n")
+                .unwrap();
+        assert!(h.is_hermitian());
+        assert_eq!(*h.at(0, 1), Complex::new(1., -1.));
+
+        // real values into a complex matrix
+        let c: GeneralMatrix<1, 2, Complex<f32>> =
+            read("%%MatrixMarket matrix array real general\n1 2\n1\n-2\n").unwrap();
+        assert_eq!(
+            c,
+            GeneralMatrix::new_row_major([[Complex::new(1., 0.), Complex::new(-2., 0.)]])
+        );
+
+        let p: GeneralMatrix<2, 2, f32> =
+            read("%%MatrixMarket matrix coordinate pattern general\n2 2 2\n1 2\n2 1\n").unwrap();
+        assert_eq!(p, GeneralMatrix::new_row_major([[0., 1.], [1., 0.]]));
+    }
+
+    #[test]
+    fn errors() {
+        type M = GeneralMatrix<2, 2, f64>;
+
+        assert_eq!(parse_error_line::<M>(""), 1);
+        assert_eq!(
+            parse_error_line::<M>("%%MatrixMarket matrix array pattern general\n"),
+            1
+        );
+        assert_eq!(
+            parse_error_line::<M>("%%MatrixMarket matrix coordinate real general\n%\n2 2\n"),
+            3
+        );
+        assert_eq!(
+            parse_error_line::<M>(
+                "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n1 x 2\n"
+            ),
+            4
+        );
+        assert_eq!(
+            parse_error_line::<M>("%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1\n"),
+            3
+        );
+        assert_eq!(
+            parse_error_line::<M>(
+                "%%MatrixMarket matrix coordinate real symmetric\n2 2 1\n1 2 1\n"
+            ),
+            3
+        );
+        assert_eq!(
+            parse_error_line::<M>("%%MatrixMarket matrix array real general\n2 2\n1\n2\n3\n"),
+            6
+        );
+        assert_eq!(
+            parse_error_line::<GeneralMatrix<1, 1, f64>>(
+                "%%MatrixMarket matrix array real general\n1 1\n1\n2\n"
+            ),
+            4
+        );
+        assert_eq!(
+            parse_error_line::<M>("%%MatrixMarket matrix array real general\n2 2\n1\n2 2\n3\n4\n"),
+            4
+        );
+
+        assert!(matches!(
+            read::<M>("%%MatrixMarket matrix array complex general\n2 2\n1 0\n2 0\n3 0\n4 0\n"),
+            Err(MatrixMarketError::UnsupportedField(
+                MatrixMarketField::Complex
+            ))
+        ));
+        assert!(matches!(
+            read::<GeneralMatrix<2, 2, i32>>(
+                "%%MatrixMarket matrix array real general\n2 2\n1\n2\n3\n4\n"
+            ),
+            Err(MatrixMarketError::UnsupportedField(MatrixMarketField::Real))
+        ));
+        assert!(matches!(
+            read::<GeneralMatrix<3, 2, f64>>(
+                "%%MatrixMarket matrix array real general\n2 2\n1\n2\n3\n4\n"
+            ),
+            Err(MatrixMarketError::DimensionMismatch {
+                expected: (3, 2),
+                found: (2, 2)
+            })
+        ));
+    }
+
+    #[test]
+    fn untrusted_sizes() {
+        // the entry count is not preallocated, the file just ends early
+        assert_eq!(
+            parse_error_line::<GeneralMatrix<2, 2, f64>>(
+                "%%MatrixMarket matrix coordinate real general\n2 2 18446744073709551615\n1 1 1\n"
+            ),
+            4
+        );
+        assert_eq!(
+            parse_error_line::<DynMatrix<f64>>(
+                "%%MatrixMarket matrix array real general\n4294967296 4294967296\n"
+            ),
+            2
+        );
+        // height * width fits in usize, but not the bytes of the f64 elements
+        assert_eq!(
+            parse_error_line::<DynMatrix<f64>>(
+                "%%MatrixMarket matrix coordinate real general\n4294967296 1073741824 0\n"
+            ),
+            2
+        );
+        assert_eq!(
+            parse_error_line::<DynMatrix<f64>>(
+                "%%MatrixMarket matrix array real symmetric\n18446744073709551615 18446744073709551615\n"
+            ),
+            2
+        );
+        assert_eq!(
+            parse_error_line::<DynMatrix<f64>>(
+                "%%MatrixMarket matrix coordinate real general\n18446744073709551615 2 0\n"
+            ),
+            2
+        );
+
+        // the shape of fixed-size targets is checked before the entries
+        assert!(matches!(
+            read::<GeneralMatrix<2, 2, f64>>(
+                "%%MatrixMarket matrix array real general\n1000000 1000000\n"
+            ),
+            Err(MatrixMarketError::DimensionMismatch {
+                expected: (2, 2),
+                found: (1000000, 1000000)
+            })
+        ));
+        assert!(matches!(
+            read::<TridiagonalMatrix<3, f64>>(
+                "%%MatrixMarket matrix coordinate real general\n2 2 18446744073709551615\n"
+            ),
+            Err(MatrixMarketError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn tridiagonal() {
+        let t: TridiagonalMatrix<3, f64> = read(
+            "%%MatrixMarket matrix coordinate real symmetric\n3 3 4\n1 1 1\n2 1 2\n2 2 3\n3 1 0\n",
+        )
+        .unwrap();
+        assert_eq!(t, TridiagonalMatrix::new([2., 0.], [1., 3., 0.], [2., 0.]));
+
+        assert!(matches!(
+            read::<TridiagonalMatrix<3, f64>>(
+                "%%MatrixMarket matrix coordinate real general\n3 3 2\n1 1 1\n3 1 5\n"
+            ),
+            Err(MatrixMarketError::OutOfBand {
+                line: 4,
+                row: 3,
+                col: 1
+            })
+        ));
+        assert!(matches!(
+            read::<TridiagonalMatrix<3, f64>>(
+                "%%MatrixMarket matrix coordinate real general\n3 2 0\n"
+            ),
+            Err(MatrixMarketError::DimensionMismatch { .. })
+        ));
+
+        assert_eq!(
+            write(&t, MatrixMarketFormat::Coordinate),
+            "%%MatrixMarket matrix coordinate real general\n3 3 7\n\
+             1 1 1e0\n2 1 2e0\n1 2 2e0\n2 2 3e0\n3 2 0e0\n2 3 0e0\n3 3 0e0\n"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let m: GeneralMatrix<2, 3, f64> =
+            GeneralMatrix::new_row_major([[0.1, 0., -1e-300], [f64::MAX, 1. / 3., 0.]]);
+        for format in [MatrixMarketFormat::Array, MatrixMarketFormat::Coordinate] {
+            let s = write(&m, format);
+            assert_eq!(read::<GeneralMatrix<2, 3, f64>>(&s).unwrap(), m);
+            assert_eq!(
+                read::<DynMatrix<f64>>(&s).unwrap(),
+                DynMatrix::from(m.clone())
+            );
+        }
+
+        let c: DynMatrix<Complex<f32>> =
+            DynMatrix::new_col_major(1, 2, vec![Complex::new(0.1, -2.), Complex::new(0., 0.)])
+                .unwrap();
+        let s = write(&c, MatrixMarketFormat::Coordinate);
+        assert_eq!(
+            s,
+            "%%MatrixMarket matrix coordinate complex general\n1 2 1\n1 1 1e-1 -2e0\n"
+        );
+        assert_eq!(read::<DynMatrix<Complex<f32>>>(&s).unwrap(), c);
+
+        let t: TridiagonalMatrix<4, i32> =
+            TridiagonalMatrix::new([1, 2, 3], [4, 5, 6, 7], [8, 9, 10]);
+        for format in [MatrixMarketFormat::Array, MatrixMarketFormat::Coordinate] {
+            assert_eq!(
+                read::<TridiagonalMatrix<4, i32>>(&write(&t, format)).unwrap(),
+                t
+            );
+        }
+    }
+}
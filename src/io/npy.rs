@@ -0,0 +1,696 @@
+use crate::matrix::general::GeneralMatrix;
+use crate::matrix::AsMatrix;
+
+use num_traits::Zero;
+use zip::result::ZipError;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use std::fmt::{Display, Formatter, Result};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, Write};
+use std::path::Path;
+
+// NumPy .npy files (https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html)
+// and .npz archives of them.
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NpyOrder {
+    // column-major, the layout of GeneralMatrix
+    #[default]
+    Fortran,
+    // row-major, the numpy default
+    C,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NpyEndian {
+    #[default]
+    Little,
+    Big,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NpyOptions {
+    pub order: NpyOrder,
+    pub endian: NpyEndian,
+}
+
+#[derive(Debug)]
+pub enum NpyError {
+    Io(io::Error),
+    Zip(ZipError),
+    // not a .npy file, or a header that cannot be parsed
+    Header(String),
+    // the dtype of the file is not the element type, e.g. '<f4' into f64
+    Dtype {
+        expected: String,
+        found: String,
+    },
+    // the shape of the file does not match (H, W)
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: Vec<usize>,
+    },
+    // no array of this name in the .npz archive
+    MissingArray(String),
+}
+
+impl Display for NpyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Zip(e) => write!(f, "{}", e),
+            Self::Header(message) => write!(f, "invalid .npy header: {}", message),
+            Self::Dtype { expected, found } => {
+                write!(
+                    f,
+                    "Expected dtype {}, but the file contains {}.",
+                    expected, found
+                )
+            }
+            Self::DimensionMismatch { expected, found } => write!(
+                f,
+                "Expected shape ({}, {}), but the file contains shape {:?}.",
+                expected.0, expected.1, found
+            ),
+            Self::MissingArray(name) => write!(f, "no array named `{}` in the archive", name),
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ZipError> for NpyError {
+    fn from(e: ZipError) -> Self {
+        match e {
+            ZipError::Io(e) => Self::Io(e),
+            e => Self::Zip(e),
+        }
+    }
+}
+
+fn header_error(message: impl Into<String>) -> NpyError {
+    NpyError::Header(message.into())
+}
+
+// element types with a fixed-size numpy dtype
+pub trait NpyElement: Copy + Zero {
+    // type code without the byte order, e.g. "f8"
+    const DTYPE: &'static str;
+
+    // `bytes` has the size of Self
+    fn from_bytes(bytes: &[u8], endian: NpyEndian) -> Self;
+
+    fn write_bytes(&self, writer: &mut impl Write, endian: NpyEndian) -> io::Result<()>;
+}
+
+macro_rules! impl_macro {
+    ($type: ty, $dtype: expr) => {
+        impl NpyElement for $type {
+            const DTYPE: &'static str = $dtype;
+
+            fn from_bytes(bytes: &[u8], endian: NpyEndian) -> Self {
+                let bytes = bytes.try_into().unwrap();
+                match endian {
+                    NpyEndian::Little => <$type>::from_le_bytes(bytes),
+                    NpyEndian::Big => <$type>::from_be_bytes(bytes),
+                }
+            }
+
+            fn write_bytes(&self, writer: &mut impl Write, endian: NpyEndian) -> io::Result<()> {
+                match endian {
+                    NpyEndian::Little => writer.write_all(&self.to_le_bytes()),
+                    NpyEndian::Big => writer.write_all(&self.to_be_bytes()),
+                }
+            }
+        }
+    };
+}
+
+impl_macro!(f32, "f4");
+impl_macro!(f64, "f8");
+impl_macro!(i32, "i4");
+impl_macro!(i64, "i8");
+
+struct Header {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+// the subset of Python literals that appears in .npy headers
+enum Literal {
+    Str(String),
+    Bool(bool),
+    Tuple(Vec<usize>),
+}
+
+struct HeaderParser<'a> {
+    s: &'a str,
+}
+
+impl<'a> HeaderParser<'a> {
+    fn skip_whitespace(&mut self) {
+        self.s = self.s.trim_start();
+    }
+
+    // consumes `c` after optional whitespace
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        match self.s.strip_prefix(c) {
+            Some(rest) => {
+                self.s = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn expect(&mut self, c: char) -> core::result::Result<(), NpyError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(header_error(format!("expected `{}`", c)))
+        }
+    }
+
+    fn string(&mut self) -> core::result::Result<String, NpyError> {
+        self.skip_whitespace();
+        let quote = match self.s.chars().next() {
+            Some(q @ ('\'' | '"')) => q,
+            _ => return Err(header_error("expected a string")),
+        };
+        let rest = &self.s[1..];
+        let end = rest
+            .find(quote)
+            .ok_or_else(|| header_error("unterminated string"))?;
+        self.s = &rest[end + 1..];
+        Ok(rest[..end].to_string())
+    }
+
+    fn literal(&mut self) -> core::result::Result<Literal, NpyError> {
+        self.skip_whitespace();
+        for (word, value) in [("True", true), ("False", false)] {
+            if let Some(rest) = self.s.strip_prefix(word) {
+                self.s = rest;
+                return Ok(Literal::Bool(value));
+            }
+        }
+
+        if self.eat('(') {
+            let mut items = Vec::new();
+            while !self.eat(')') {
+                self.skip_whitespace();
+                let end = self
+                    .s
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(self.s.len());
+                let item = self.s[..end]
+                    .parse()
+                    .map_err(|_| header_error("expected a dimension"))?;
+                items.push(item);
+                self.s = &self.s[end..];
+                if !self.eat(',') {
+                    self.expect(')')?;
+                    break;
+                }
+            }
+            return Ok(Literal::Tuple(items));
+        }
+
+        self.string().map(Literal::Str)
+    }
+
+    fn parse(mut self) -> core::result::Result<Header, NpyError> {
+        let (mut descr, mut fortran_order, mut shape) = (None, None, None);
+
+        self.expect('{')?;
+        while !self.eat('}') {
+            let key = self.string()?;
+            self.expect(':')?;
+            match (key.as_str(), self.literal()?) {
+                ("descr", Literal::Str(v)) => descr = Some(v),
+                ("fortran_order", Literal::Bool(v)) => fortran_order = Some(v),
+                ("shape", Literal::Tuple(v)) => shape = Some(v),
+                (key, _) => return Err(header_error(format!("unexpected value for `{}`", key))),
+            }
+            if !self.eat(',') {
+                self.expect('}')?;
+                break;
+            }
+        }
+
+        Ok(Header {
+            descr: descr.ok_or_else(|| header_error("missing `descr`"))?,
+            fortran_order: fortran_order.ok_or_else(|| header_error("missing `fortran_order`"))?,
+            shape: shape.ok_or_else(|| header_error("missing `shape`"))?,
+        })
+    }
+}
+
+fn read_header(reader: &mut impl Read) -> core::result::Result<Header, NpyError> {
+    let mut prefix = [0u8; 8];
+    reader.read_exact(&mut prefix)?;
+    if &prefix[..6] != MAGIC {
+        return Err(header_error("not a .npy file"));
+    }
+
+    // 1.0 has a u16 header length, 2.0 and 3.0 a u32
+    let len = match prefix[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            reader.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        major => {
+            return Err(header_error(format!(
+                "unsupported version {}.{}",
+                major, prefix[7]
+            )))
+        }
+    };
+
+    let mut header = vec![0u8; len];
+    reader.read_exact(&mut header)?;
+    let header = String::from_utf8(header).map_err(|_| header_error("not ASCII"))?;
+    HeaderParser { s: &header }.parse()
+}
+
+fn write_header(writer: &mut impl Write, dict: &str) -> io::Result<()> {
+    // magic, version, length, dict and the terminating newline are padded to 64 bytes
+    let padded = |prefix: usize| {
+        let len = prefix + dict.len() + 1;
+        dict.len() + 1 + (64 - len % 64) % 64
+    };
+
+    writer.write_all(MAGIC)?;
+    let len = if padded(10) <= u16::MAX as usize {
+        let len = padded(10);
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&(len as u16).to_le_bytes())?;
+        len
+    } else {
+        let len = padded(12);
+        writer.write_all(&[2, 0])?;
+        writer.write_all(&(len as u32).to_le_bytes())?;
+        len
+    };
+    writeln!(writer, "{:width$}", dict, width = len - 1)
+}
+
+pub trait Npy: Sized {
+    fn read_npy<R: Read>(reader: R) -> core::result::Result<Self, NpyError>;
+
+    fn write_npy<W: Write>(&self, writer: W, options: NpyOptions) -> io::Result<()>;
+
+    #[allow(unused)]
+    fn load_npy(path: impl AsRef<Path>) -> core::result::Result<Self, NpyError> {
+        Self::read_npy(BufReader::new(File::open(path)?))
+    }
+
+    #[allow(unused)]
+    fn save_npy(&self, path: impl AsRef<Path>, options: NpyOptions) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_npy(&mut writer, options)?;
+        writer.flush()
+    }
+}
+
+impl<const H: usize, const W: usize, Inner> Npy for GeneralMatrix<H, W, Inner>
+where
+    Inner: NpyElement,
+{
+    fn read_npy<R: Read>(mut reader: R) -> core::result::Result<Self, NpyError> {
+        let header = read_header(&mut reader)?;
+
+        let endian = match header.descr.split_at(header.descr.len().min(1)) {
+            ("<", dtype) if dtype == Inner::DTYPE => NpyEndian::Little,
+            (">", dtype) if dtype == Inner::DTYPE => NpyEndian::Big,
+            ("=", dtype) if dtype == Inner::DTYPE && cfg!(target_endian = "little") => {
+                NpyEndian::Little
+            }
+            ("=", dtype) if dtype == Inner::DTYPE => NpyEndian::Big,
+            _ => {
+                return Err(NpyError::Dtype {
+                    expected: Inner::DTYPE.to_string(),
+                    found: header.descr,
+                })
+            }
+        };
+
+        // vectors may be stored one-dimensional
+        let fits = match header.shape[..] {
+            [h, w] => (h, w) == (H, W),
+            [n] => (H == 1 && n == W) || (W == 1 && n == H),
+            _ => false,
+        };
+        if !fits {
+            return Err(NpyError::DimensionMismatch {
+                expected: (H, W),
+                found: header.shape,
+            });
+        }
+
+        let size = core::mem::size_of::<Inner>();
+        let mut data = vec![0u8; H * W * size];
+        reader.read_exact(&mut data)?;
+        let values = data
+            .chunks_exact(size)
+            .map(|b| Inner::from_bytes(b, endian));
+
+        if header.fortran_order {
+            // the layout of `inner`
+            let mut ret = GeneralMatrix::<H, W, Inner>::zero();
+            for (dst, v) in ret.as_mut_slice().iter_mut().zip(values) {
+                *dst = v;
+            }
+            Ok(ret)
+        } else {
+            // the column-major storage of the transpose is the row-major storage of the matrix
+            let mut rows = GeneralMatrix::<W, H, Inner>::zero();
+            for (dst, v) in rows.as_mut_slice().iter_mut().zip(values) {
+                *dst = v;
+            }
+
+            #[cfg(feature = "on_heap")]
+            return Ok(GeneralMatrix::new_row_major_box(rows.into_inner_box()));
+            #[cfg(not(feature = "on_heap"))]
+            return Ok(GeneralMatrix::new_row_major(*rows.inner()));
+        }
+    }
+
+    fn write_npy<Wr: Write>(&self, mut writer: Wr, options: NpyOptions) -> io::Result<()> {
+        let endian = match options.endian {
+            NpyEndian::Little => '<',
+            NpyEndian::Big => '>',
+        };
+        let fortran_order = match options.order {
+            NpyOrder::Fortran => "True",
+            NpyOrder::C => "False",
+        };
+        let dict = format!(
+            "{{'descr': '{}{}', 'fortran_order': {}, 'shape': ({}, {}), }}",
+            endian,
+            Inner::DTYPE,
+            fortran_order,
+            H,
+            W
+        );
+        write_header(&mut writer, &dict)?;
+
+        match options.order {
+            NpyOrder::Fortran => {
+                for v in self.as_slice() {
+                    v.write_bytes(&mut writer, options.endian)?;
+                }
+            }
+            NpyOrder::C => {
+                for row in 0..H {
+                    for col in 0..W {
+                        self.at(row, col).write_bytes(&mut writer, options.endian)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// named arrays of a .npz archive, stored as `<name>.npy`
+pub struct NpzReader<R>
+where
+    R: Read + Seek,
+{
+    archive: ZipArchive<R>,
+}
+
+impl<R> NpzReader<R>
+where
+    R: Read + Seek,
+{
+    #[allow(unused)]
+    pub fn new(reader: R) -> core::result::Result<Self, NpyError> {
+        Ok(Self {
+            archive: ZipArchive::new(reader)?,
+        })
+    }
+
+    #[allow(unused)]
+    pub fn names(&self) -> Vec<String> {
+        self.archive
+            .file_names()
+            .map(|name| name.strip_suffix(".npy").unwrap_or(name).to_string())
+            .collect()
+    }
+
+    #[allow(unused)]
+    pub fn read<T: Npy>(&mut self, name: &str) -> core::result::Result<T, NpyError> {
+        match self.archive.by_name(&format!("{}.npy", name)) {
+            Ok(file) => T::read_npy(file),
+            Err(ZipError::FileNotFound) => Err(NpyError::MissingArray(name.to_string())),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+impl NpzReader<BufReader<File>> {
+    #[allow(unused)]
+    pub fn open(path: impl AsRef<Path>) -> core::result::Result<Self, NpyError> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+pub struct NpzWriter<W>
+where
+    W: Write + Seek,
+{
+    zip: ZipWriter<W>,
+    file_options: FileOptions,
+    options: NpyOptions,
+}
+
+impl<W> NpzWriter<W>
+where
+    W: Write + Seek,
+{
+    // uncompressed, as numpy.savez
+    #[allow(unused)]
+    pub fn new(writer: W, options: NpyOptions) -> Self {
+        Self {
+            zip: ZipWriter::new(writer),
+            file_options: FileOptions::default().compression_method(CompressionMethod::Stored),
+            options,
+        }
+    }
+
+    // deflated, as numpy.savez_compressed
+    #[allow(unused)]
+    pub fn new_compressed(writer: W, options: NpyOptions) -> Self {
+        Self {
+            zip: ZipWriter::new(writer),
+            file_options: FileOptions::default().compression_method(CompressionMethod::Deflated),
+            options,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn add<T: Npy>(&mut self, name: &str, matrix: &T) -> core::result::Result<(), NpyError> {
+        self.zip
+            .start_file(format!("{}.npy", name), self.file_options)?;
+        matrix.write_npy(&mut self.zip, self.options)?;
+        Ok(())
+    }
+
+    // writes the central directory and returns the underlying writer
+    #[allow(unused)]
+    pub fn finish(mut self) -> core::result::Result<W, NpyError> {
+        Ok(self.zip.finish()?)
+    }
+}
+
+impl NpzWriter<BufWriter<File>> {
+    #[allow(unused)]
+    pub fn create(
+        path: impl AsRef<Path>,
+        options: NpyOptions,
+    ) -> core::result::Result<Self, NpyError> {
+        Ok(Self::new(BufWriter::new(File::create(path)?), options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+
+    fn npy(version: u8, dict: &str, data: &[u8]) -> Vec<u8> {
+        let mut ret = MAGIC.to_vec();
+        ret.extend([version, 0]);
+        if version == 1 {
+            ret.extend((dict.len() as u16).to_le_bytes());
+        } else {
+            ret.extend((dict.len() as u32).to_le_bytes());
+        }
+        ret.extend(dict.as_bytes());
+        ret.extend(data);
+        ret
+    }
+
+    fn write<T: Npy>(m: &T, options: NpyOptions) -> Vec<u8> {
+        let mut out = Vec::new();
+        m.write_npy(&mut out, options).unwrap();
+        out
+    }
+
+    #[test]
+    fn read_c_order() {
+        // as numpy.save(f, numpy.array([[1., 2., 3.], [4., 5., 6.]]))
+        let dict = "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }";
+        let dict = format!("{:117}\n", dict);
+        let data: Vec<u8> = [1f64, 2., 3., 4., 5., 6.]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+
+        let m: GeneralMatrix<2, 3, f64> =
+            GeneralMatrix::read_npy(&npy(1, &dict, &data)[..]).unwrap();
+        assert_eq!(
+            m,
+            GeneralMatrix::new_row_major([[1., 2., 3.], [4., 5., 6.]])
+        );
+    }
+
+    #[test]
+    fn read_fortran_order() {
+        let dict = "{\"descr\": \">i4\", \"fortran_order\": True, \"shape\": (2, 2)}\n";
+        let data: Vec<u8> = [1i32, 2, 3, 4]
+            .iter()
+            .flat_map(|v| v.to_be_bytes())
+            .collect();
+
+        let m: GeneralMatrix<2, 2, i32> =
+            GeneralMatrix::read_npy(&npy(2, dict, &data)[..]).unwrap();
+        assert_eq!(m, GeneralMatrix::new_row_major([[1, 3], [2, 4]]));
+
+        // one-dimensional arrays as vectors
+        let dict = "{'descr': '<i8', 'fortran_order': False, 'shape': (3,), }\n";
+        let data: Vec<u8> = [1i64, 2, 3].iter().flat_map(|v| v.to_le_bytes()).collect();
+        let v: GeneralMatrix<3, 1, i64> =
+            GeneralMatrix::read_npy(&npy(1, dict, &data)[..]).unwrap();
+        assert_eq!(v, GeneralMatrix::new_row_major([[1], [2], [3]]));
+    }
+
+    #[test]
+    fn errors() {
+        let data = [0u8; 16];
+
+        let dict = "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 2), }\n";
+        assert!(matches!(
+            GeneralMatrix::<2, 2, f64>::read_npy(&npy(1, dict, &data)[..]),
+            Err(NpyError::Dtype { .. })
+        ));
+
+        let dict = "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 2), }\n";
+        assert!(matches!(
+            GeneralMatrix::<1, 4, f32>::read_npy(&npy(1, dict, &data)[..]),
+            Err(NpyError::DimensionMismatch {
+                expected: (1, 4),
+                ..
+            })
+        ));
+
+        let dict = "{'descr': '<f4', 'fortran_order': False}\n";
+        assert!(matches!(
+            GeneralMatrix::<2, 2, f32>::read_npy(&npy(1, dict, &data)[..]),
+            Err(NpyError::Header(_))
+        ));
+        assert!(matches!(
+            GeneralMatrix::<2, 2, f32>::read_npy(&b"PK\x03\x04"[..]),
+            Err(NpyError::Io(_))
+        ));
+        assert!(matches!(
+            GeneralMatrix::<2, 2, f32>::read_npy(&b"\x93NUMPX\x01\x00\x00\x00"[..]),
+            Err(NpyError::Header(_))
+        ));
+
+        // truncated data
+        let dict = "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 2), }\n";
+        assert!(matches!(
+            GeneralMatrix::<2, 2, f32>::read_npy(&npy(1, dict, &data[..8])[..]),
+            Err(NpyError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn round_trip() {
+        let m: GeneralMatrix<2, 3, f32> =
+            GeneralMatrix::new_row_major([[0.1, -2., 3.], [4., 5.5, f32::MAX]]);
+        let i: GeneralMatrix<3, 2, i64> =
+            GeneralMatrix::new_row_major([[1, -2], [i64::MAX, 4], [5, i64::MIN]]);
+
+        for order in [NpyOrder::Fortran, NpyOrder::C] {
+            for endian in [NpyEndian::Little, NpyEndian::Big] {
+                let options = NpyOptions { order, endian };
+
+                let bytes = write(&m, options);
+                // the header is aligned to 64 bytes
+                assert_eq!((bytes.len() - 6 * 4) % 64, 0);
+                assert_eq!(GeneralMatrix::<2, 3, f32>::read_npy(&bytes[..]).unwrap(), m);
+                assert_eq!(
+                    GeneralMatrix::<3, 2, i64>::read_npy(&write(&i, options)[..]).unwrap(),
+                    i
+                );
+            }
+        }
+
+        // the Fortran-order payload is the column-major storage
+        let bytes = write(&m, NpyOptions::default());
+        let payload: Vec<u8> = m.as_slice().iter().flat_map(|v| v.to_le_bytes()).collect();
+        assert!(bytes.ends_with(&payload));
+        assert!(std::str::from_utf8(&bytes[10..bytes.len() - payload.len()])
+            .unwrap()
+            .starts_with("{'descr': '<f4', 'fortran_order': True, 'shape': (2, 3), }"));
+    }
+
+    #[test]
+    fn npz() {
+        let a: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[1., 2.], [3., 4.]]);
+        let b: GeneralMatrix<1, 3, i32> = GeneralMatrix::new_row_major([[5, 6, 7]]);
+
+        for compressed in [false, true] {
+            let cursor = Cursor::new(Vec::new());
+            let mut writer = if compressed {
+                NpzWriter::new_compressed(cursor, NpyOptions::default())
+            } else {
+                NpzWriter::new(cursor, NpyOptions::default())
+            };
+            writer.add("a", &a).unwrap();
+            writer.add("b", &b).unwrap();
+            let bytes = writer.finish().unwrap().into_inner();
+
+            let mut reader = NpzReader::new(Cursor::new(bytes)).unwrap();
+            let mut names = reader.names();
+            names.sort();
+            assert_eq!(names, ["a", "b"]);
+            assert_eq!(reader.read::<GeneralMatrix<2, 2, f64>>("a").unwrap(), a);
+            assert_eq!(reader.read::<GeneralMatrix<1, 3, i32>>("b").unwrap(), b);
+            assert!(matches!(
+                reader.read::<GeneralMatrix<1, 3, i32>>("c"),
+                Err(NpyError::MissingArray(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::matrix::general::lu::{GeneralLuFormat, GeneralLuPivot};
+use crate::matrix::general::GeneralMatrix;
+use crate::matrix::tridiagonal::TridiagonalMatrix;
+
+use num_traits::Zero;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// representations, shown as JSON:
+//
+//   GeneralMatrix<2, 3, f64>     {"height": 2, "width": 3, "data": [a00, a10, a01, a11, a02, a12]}
+//                                data is column-major, the layout of `inner`.
+//   TridiagonalMatrix<3, f64>    {"size": 3, "upper": [a01, a12], "diagonal": [a00, a11, a22], "lower": [a10, a21]}
+//   GeneralLuPivot<2>            {"pivot": [2, 2]}
+//                                1-based row interchanges as returned by ?getrf: pivot[i] is in i+1..=H.
+//                                H is only known, and checked, as part of a GeneralLuFormat.
+//   GeneralLuFormat              {"internal_matrix": <GeneralMatrix>, "pivot": <GeneralLuPivot>}
+//
+// deserializing fails unless the sizes match the const parameters.
+
+#[derive(Serialize)]
+struct GeneralRef<'a, Inner> {
+    height: usize,
+    width: usize,
+    data: &'a [Inner],
+}
+
+#[derive(Deserialize)]
+struct GeneralRepr<Inner> {
+    height: usize,
+    width: usize,
+    data: Vec<Inner>,
+}
+
+#[derive(Serialize)]
+struct TridiagonalRef<'a, Inner> {
+    size: usize,
+    upper: &'a [Inner],
+    diagonal: &'a [Inner],
+    lower: &'a [Inner],
+}
+
+#[derive(Deserialize)]
+struct TridiagonalRepr<Inner> {
+    size: usize,
+    upper: Vec<Inner>,
+    diagonal: Vec<Inner>,
+    lower: Vec<Inner>,
+}
+
+#[derive(Serialize)]
+struct PivotRef<'a> {
+    pivot: &'a [i32],
+}
+
+#[derive(Deserialize)]
+struct PivotRepr {
+    pivot: Vec<i32>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LuRepr<M, P> {
+    internal_matrix: M,
+    pivot: P,
+}
+
+fn check_len<E: Error>(name: &str, found: usize, expected: usize) -> Result<(), E> {
+    if found == expected {
+        Ok(())
+    } else {
+        Err(E::custom(format!(
+            "expected {} elements in `{}`, found {}",
+            expected, name, found
+        )))
+    }
+}
+
+// rows interchanged by a pivot must exist in the factorized H x W matrix
+trait CheckPivotRows {
+    fn check_rows<E: Error>(&self, height: usize) -> Result<(), E>;
+}
+
+impl<Pivot> CheckPivotRows for Pivot {
+    default fn check_rows<E: Error>(&self, _: usize) -> Result<(), E> {
+        Ok(())
+    }
+}
+
+impl<const S: usize> CheckPivotRows for GeneralLuPivot<S> {
+    fn check_rows<E: Error>(&self, height: usize) -> Result<(), E> {
+        match self.pivot.iter().position(|&p| p as usize > height) {
+            Some(i) => Err(E::custom(format!(
+                "pivot[{}] = {} is out of range {}..={}",
+                i,
+                self.pivot[i],
+                i + 1,
+                height
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<const H: usize, const W: usize, Inner> Serialize for GeneralMatrix<H, W, Inner>
+where
+    Inner: Clone + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GeneralRef {
+            height: H,
+            width: W,
+            data: self.as_slice(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, const H: usize, const W: usize, Inner> Deserialize<'de> for GeneralMatrix<H, W, Inner>
+where
+    Inner: Clone + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = GeneralRepr::<Inner>::deserialize(deserializer)?;
+        if (repr.height, repr.width) != (H, W) {
+            return Err(D::Error::custom(format!(
+                "expected a {}x{} matrix, found {}x{}",
+                H, W, repr.height, repr.width
+            )));
+        }
+        check_len("data", repr.data.len(), H * W)?;
+
+        Ok(GeneralMatrix::by_f(|col, row| {
+            repr.data[col * H + row].clone()
+        }))
+    }
+}
+
+impl<const S: usize, Inner> Serialize for TridiagonalMatrix<S, Inner>
+where
+    Inner: Clone + Zero + Serialize,
+    [(); S - 1]:,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let (upper, diagonal, lower) = self.inner();
+        TridiagonalRef {
+            size: S,
+            upper,
+            diagonal,
+            lower,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, const S: usize, Inner> Deserialize<'de> for TridiagonalMatrix<S, Inner>
+where
+    Inner: Clone + Zero + Deserialize<'de>,
+    [(); S - 1]:,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = TridiagonalRepr::<Inner>::deserialize(deserializer)?;
+        if repr.size != S {
+            return Err(D::Error::custom(format!(
+                "expected a {0}x{0} tridiagonal matrix, found {1}x{1}",
+                S, repr.size
+            )));
+        }
+        check_len("upper", repr.upper.len(), S - 1)?;
+        check_len("diagonal", repr.diagonal.len(), S)?;
+        check_len("lower", repr.lower.len(), S - 1)?;
+
+        Ok(TridiagonalMatrix::by_f(
+            |i| repr.upper[i].clone(),
+            |i| repr.diagonal[i].clone(),
+            |i| repr.lower[i].clone(),
+        ))
+    }
+}
+
+impl<const S: usize> Serialize for GeneralLuPivot<S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        PivotRef { pivot: &self.pivot }.serialize(serializer)
+    }
+}
+
+impl<'de, const S: usize> Deserialize<'de> for GeneralLuPivot<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = PivotRepr::deserialize(deserializer)?;
+        check_len("pivot", repr.pivot.len(), S)?;
+        // also rejects zero and negative entries
+        if let Some(i) = (0..S).find(|&i| repr.pivot[i] <= i as i32) {
+            return Err(D::Error::custom(format!(
+                "pivot[{}] = {} is out of range {}..",
+                i,
+                repr.pivot[i],
+                i + 1
+            )));
+        }
+
+        let mut ret = Self::default();
+        ret.pivot.copy_from_slice(&repr.pivot);
+        Ok(ret)
+    }
+}
+
+impl<const H: usize, const W: usize, Inner, Pivot> Serialize for GeneralLuFormat<H, W, Inner, Pivot>
+where
+    Inner: Clone + Serialize,
+    Pivot: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LuRepr {
+            internal_matrix: &self.internal_matrix,
+            pivot: &self.pivot,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, const H: usize, const W: usize, Inner, Pivot> Deserialize<'de>
+    for GeneralLuFormat<H, W, Inner, Pivot>
+where
+    Inner: Clone + Deserialize<'de>,
+    Pivot: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = LuRepr::<GeneralMatrix<H, W, Inner>, Pivot>::deserialize(deserializer)?;
+        repr.pivot.check_rows(H)?;
+        Ok(GeneralLuFormat::new(repr.internal_matrix, repr.pivot))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_matrix_approx_eq;
+    use crate::factorizations::lu::AsLu;
+    use crate::matrix::Complex;
+    use crate::solve::LuSolve;
+
+    #[test]
+    fn general() {
+        let m: GeneralMatrix<2, 3, f64> =
+            GeneralMatrix::new_row_major([[1., 2., 3.], [4., 5., 6.]]);
+        let json = serde_json::to_string(&m).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"height":2,"width":3,"data":[1.0,4.0,2.0,5.0,3.0,6.0]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<GeneralMatrix<2, 3, f64>>(&json).unwrap(),
+            m
+        );
+
+        // the shape is checked against the type
+        assert!(serde_json::from_str::<GeneralMatrix<3, 2, f64>>(&json).is_err());
+        assert!(serde_json::from_str::<GeneralMatrix<1, 2, i32>>(
+            r#"{"height":1,"width":2,"data":[1]}"#
+        )
+        .is_err());
+
+        let c: GeneralMatrix<1, 1, Complex<f32>> =
+            GeneralMatrix::new_row_major([[Complex::new(1., -1.)]]);
+        let json = serde_json::to_string(&c).unwrap();
+        assert_eq!(
+            serde_json::from_str::<GeneralMatrix<1, 1, Complex<f32>>>(&json).unwrap(),
+            c
+        );
+    }
+
+    #[test]
+    fn tridiagonal() {
+        let t: TridiagonalMatrix<3, i32> = TridiagonalMatrix::new([1, 2], [3, 4, 5], [6, 7]);
+        let json = serde_json::to_string(&t).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"size":3,"upper":[1,2],"diagonal":[3,4,5],"lower":[6,7]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<TridiagonalMatrix<3, i32>>(&json).unwrap(),
+            t
+        );
+
+        assert!(serde_json::from_str::<TridiagonalMatrix<4, i32>>(&json).is_err());
+        assert!(serde_json::from_str::<TridiagonalMatrix<3, i32>>(
+            r#"{"size":3,"upper":[1,2,0],"diagonal":[3,4,5],"lower":[6,7]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn lu() {
+        let a: GeneralMatrix<3, 3, f64> =
+            GeneralMatrix::new_row_major([[1., 2., 3.], [4., 5., 6.], [7., 8., 10.]]);
+        let (lu, _) = a.lu().unwrap();
+
+        let json = serde_json::to_string(&lu).unwrap();
+        let restored: GeneralLuFormat<3, 3, f64, GeneralLuPivot<3>> =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, lu);
+
+        // the restored factorization solves A x = b for x = (1, 2, 3)
+        let x = restored
+            .solve(GeneralMatrix::new_col_major([[14., 32., 53.]]))
+            .unwrap();
+        assert_matrix_approx_eq!(x, GeneralMatrix::new_col_major([[1., 2., 3.]]), epsilon = 1e-12);
+
+        assert!(serde_json::from_str::<GeneralLuPivot<2>>(r#"{"pivot":[3,3,3]}"#).is_err());
+    }
+
+    #[test]
+    fn lu_pivot_range() {
+        let pivot = serde_json::from_str::<GeneralLuPivot<2>>;
+        assert_eq!(pivot(r#"{"pivot":[2,2]}"#).unwrap().pivot, [2, 2]);
+        assert!(pivot(r#"{"pivot":[0,2]}"#).is_err());
+        assert!(pivot(r#"{"pivot":[-1,2]}"#).is_err());
+        // row 1 was already eliminated
+        assert!(pivot(r#"{"pivot":[2,1]}"#).is_err());
+
+        let matrix = serde_json::to_string(&GeneralMatrix::<3, 2, f64>::zero()).unwrap();
+        let lu = |pivot: &str| {
+            serde_json::from_str::<GeneralLuFormat<3, 2, f64, GeneralLuPivot<2>>>(&format!(
+                r#"{{"internal_matrix":{},"pivot":{{"pivot":{}}}}}"#,
+                matrix, pivot
+            ))
+        };
+        // a tall matrix interchanges with rows below min(H, W)
+        assert_eq!(lu("[3,3]").unwrap().pivot.pivot, [3, 3]);
+        assert!(lu("[4,2]").is_err());
+        assert!(lu("[1,2147483647]").is_err());
+    }
+}
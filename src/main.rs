@@ -5,8 +5,11 @@
 #![feature(concat_idents)]
 #![feature(box_syntax)]
 
+pub mod backend;
 pub mod factorizations;
+pub mod io;
 pub mod matrix;
+pub mod solve;
 
 use matrix::general::GeneralMatrix;
 use matrix::tridiagonal::TridiagonalMatrix;
@@ -19,7 +22,11 @@ fn main() {
 
     let mut rng = rand::thread_rng();
 
+    // the native backend has no blocking, so it gets a smaller problem
+    #[cfg(feature = "lapack")]
     const SIZE: usize = 10000;
+    #[cfg(not(feature = "lapack"))]
+    const SIZE: usize = 1000;
     let mut inner = Box::new([[0.0; SIZE]; SIZE]);
 
     for i in 0..SIZE {
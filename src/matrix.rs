@@ -1,6 +1,10 @@
+pub mod approx;
+pub mod dynamic;
 pub mod general;
 pub mod tridiagonal;
 
+pub use num_complex::Complex;
+
 use num_traits::Zero;
 
 use core::fmt::{self, Display, Formatter};
@@ -16,6 +20,117 @@ pub trait AsMatrix<const H: usize, const W: usize, Inner> {
     }
 }
 
+// rows and columns beyond DISPLAY_LIMIT are elided, keeping DISPLAY_EDGE on each side
+const DISPLAY_LIMIT: usize = 10;
+const DISPLAY_EDGE: usize = 4;
+
+// the indices to print. None marks the elided run.
+fn display_indices(n: usize) -> Vec<Option<usize>> {
+    if n > DISPLAY_LIMIT {
+        (0..DISPLAY_EDGE)
+            .map(Some)
+            .chain([None])
+            .chain((n - DISPLAY_EDGE..n).map(Some))
+            .collect()
+    } else {
+        (0..n).map(Some).collect()
+    }
+}
+
+// one element with the precision, sign and alternate flags of `f`
+fn format_element<Inner>(v: &Inner, f: &Formatter<'_>) -> String
+where
+    Inner: Display,
+{
+    match (f.precision(), f.sign_plus(), f.alternate()) {
+        (Some(p), false, false) => format!("{:.*}", p, v),
+        (Some(p), true, false) => format!("{:+.*}", p, v),
+        (Some(p), false, true) => format!("{:#.*}", p, v),
+        (Some(p), true, true) => format!("{:+#.*}", p, v),
+        (None, false, false) => format!("{}", v),
+        (None, true, false) => format!("{:+}", v),
+        (None, false, true) => format!("{:#}", v),
+        (None, true, true) => format!("{:+#}", v),
+    }
+}
+
+// pads to `width` with the fill, alignment (right by default) and zero flag of `f`
+fn pad(s: &str, width: usize, f: &Formatter<'_>) -> String {
+    let n = width.saturating_sub(s.chars().count());
+    if f.sign_aware_zero_pad() {
+        let digits = s.trim_start_matches(['+', '-']);
+        let sign = &s[..s.len() - digits.len()];
+        return format!("{}{}{}", sign, "0".repeat(n), digits);
+    }
+
+    let fill = |n: usize| f.fill().to_string().repeat(n);
+    match f.align() {
+        Some(fmt::Alignment::Left) => format!("{}{}", s, fill(n)),
+        Some(fmt::Alignment::Center) => format!("{}{}{}", fill(n / 2), s, fill(n - n / 2)),
+        _ => format!("{}{}", fill(n), s),
+    }
+}
+
+// row-major, one line per row. columns are separated by a space and aligned to their
+// widest element (at least the width of `f`). large matrices are elided with `...`.
+pub fn print_display_with<'a, Inner>(
+    height: usize,
+    width: usize,
+    at: impl Fn(usize, usize) -> &'a Inner,
+    f: &mut Formatter<'_>,
+) -> fmt::Result
+where
+    Inner: Display + 'a,
+{
+    let rows = display_indices(height);
+    let cols = display_indices(width);
+
+    // only the printed elements are formatted
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .flatten()
+        .map(|&row| {
+            cols.iter()
+                .map(|col| match col {
+                    Some(col) => format_element(at(row, *col), f),
+                    None => "...".to_string(),
+                })
+                .collect()
+        })
+        .collect();
+    let widths: Vec<usize> = (0..cols.len())
+        .map(|j| {
+            cells
+                .iter()
+                .map(|cells| cells[j].chars().count())
+                .fold(f.width().unwrap_or(0), usize::max)
+        })
+        .collect();
+
+    let mut cells = cells.iter();
+    for row in &rows {
+        if row.is_none() {
+            writeln!(f, "...")?;
+            continue;
+        }
+
+        let line: Vec<String> = cells
+            .next()
+            .unwrap()
+            .iter()
+            .zip(&cols)
+            .zip(&widths)
+            .map(|((cell, col), &width)| match col {
+                Some(_) => pad(cell, width, f),
+                None => format!("{:>width$}", cell, width = width),
+            })
+            .collect();
+        writeln!(f, "{}", line.join(" "))?;
+    }
+
+    Ok(())
+}
+
 pub fn print_matrix_display<const H: usize, const W: usize, Inner, T>(
     mt: &T,
     f: &mut Formatter<'_>,
@@ -24,21 +139,76 @@ where
     T: AsMatrix<H, W, Inner>,
     Inner: Display,
 {
-    if let Some(width) = f.width() {
-        for h in 0..H {
-            for w in 0..W {
-                write!(f, "{:width$}", mt.at(w, h), width = width)?;
-            }
-            writeln!(f)?;
-        }
-    } else {
-        for h in 0..H {
-            for w in 0..W {
-                write!(f, "{}", mt.at(w, h))?;
+    print_display_with(H, W, |row, col| mt.at(row, col), f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dynamic::DynMatrix;
+    use super::general::GeneralMatrix;
+    use super::tridiagonal::TridiagonalMatrix;
+
+    #[test]
+    fn row_major() {
+        let m = GeneralMatrix::new_row_major([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(format!("{}", m), "1 2 3\n4 5 6\n");
+
+        let t = TridiagonalMatrix::new([1, 2], [3, 4, 5], [6, 7]);
+        assert_eq!(format!("{}", t), "3 1 0\n6 4 2\n0 7 5\n");
+
+        let d = DynMatrix::new_row_major(3, 1, vec![1, 2, 3]).unwrap();
+        assert_eq!(format!("{}", d), "1\n2\n3\n");
+    }
+
+    #[test]
+    fn flags() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[1., -22.5], [100., 0.25]]);
+
+        // each column is as wide as its widest element
+        assert_eq!(format!("{}", m), "  1 -22.5\n100  0.25\n");
+        assert_eq!(format!("{:.2}", m), "  1.00 -22.50\n100.00   0.25\n");
+        assert_eq!(format!("{:+.1}", m), "  +1.0 -22.5\n+100.0  +0.2\n");
+        assert_eq!(format!("{:7.1}", m), "    1.0   -22.5\n  100.0     0.2\n");
+        assert_eq!(format!("{:<6}", m), "1      -22.5 \n100    0.25  \n");
+        assert_eq!(format!("{:06.1}", m), "0001.0 -022.5\n0100.0 0000.2\n");
+        assert_eq!(
+            format!("{:*^5}", GeneralMatrix::new_row_major([[1, 10]])),
+            "**1** *10**\n"
+        );
+
+        let h = GeneralMatrix::new_row_major([[Hex(255), Hex(1)]]);
+        assert_eq!(format!("{:#}", h), "0xff 0x1\n");
+    }
+
+    // shows the alternate flag reaching the elements
+    #[derive(Clone)]
+    struct Hex(u32);
+
+    impl core::fmt::Display for Hex {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                write!(f, "{:#x}", self.0)
+            } else {
+                write!(f, "{:x}", self.0)
             }
-            writeln!(f)?;
         }
     }
 
-    Ok(())
+    #[test]
+    fn elided() {
+        let m: GeneralMatrix<12, 20, usize> = GeneralMatrix::by_f(|col, row| row * 100 + col);
+        let s = format!("{}", m);
+        let lines: Vec<&str> = s.lines().collect();
+
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[0], "   0    1    2    3 ...   16   17   18   19");
+        assert_eq!(lines[4], "...");
+        assert_eq!(lines[8], "1100 1101 1102 1103 ... 1116 1117 1118 1119");
+
+        // columns stay aligned across the elision
+        assert_eq!(lines[5], " 800  801  802  803 ...  816  817  818  819");
+
+        let d = DynMatrix::by_f(100, 100, |col, row| (row + col) % 7);
+        assert_eq!(format!("{}", d).lines().count(), 9);
+    }
 }
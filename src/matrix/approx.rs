@@ -0,0 +1,351 @@
+use super::general::GeneralMatrix;
+use super::tridiagonal::TridiagonalMatrix;
+
+use float_cmp::{ApproxEq, F32Margin, F64Margin};
+use num_traits::{Float, Zero};
+
+use core::fmt::Debug;
+
+// number of differing elements printed by the assertion macros
+const MAX_REPORTED: usize = 16;
+
+// element-wise comparison of matrices of the same type.
+// only stored elements are compared, so the structural zeros of banded matrices are skipped.
+pub trait ApproxMatrix {
+    type Element: Copy;
+
+    // (row, col, value) of every stored element, in an order fixed by the type
+    fn stored_elements(&self) -> Vec<(usize, usize, Self::Element)>;
+
+    // (row, col, left, right) of the elements outside `margin`
+    fn approx_mismatches<M>(&self, other: &Self, margin: M) -> Vec<(usize, usize, Self::Element, Self::Element)>
+    where
+        Self::Element: ApproxEq,
+        M: Into<<Self::Element as ApproxEq>::Margin>,
+    {
+        let margin = margin.into();
+        self.stored_elements()
+            .into_iter()
+            .zip(other.stored_elements())
+            .filter(|((_, _, l), (_, _, r))| l.approx_ne(*r, margin))
+            .map(|((row, col, l), (_, _, r))| (row, col, l, r))
+            .collect()
+    }
+
+    #[allow(unused)]
+    fn frobenius_norm(&self) -> Self::Element
+    where
+        Self::Element: Float,
+    {
+        frobenius(self.stored_elements().into_iter().map(|(_, _, v)| v))
+    }
+
+    // ||self - other||_F / max(||self||_F, ||other||_F). zero if both are zero matrices.
+    fn relative_frobenius_error(&self, other: &Self) -> Self::Element
+    where
+        Self::Element: Float,
+    {
+        let (left, right) = (self.stored_elements(), other.stored_elements());
+
+        let diff = frobenius(left.iter().zip(&right).map(|((_, _, l), (_, _, r))| *l - *r));
+        let scale = frobenius(left.iter().map(|e| e.2)).max(frobenius(right.iter().map(|e| e.2)));
+
+        if diff.is_zero() {
+            Self::Element::zero()
+        } else {
+            diff / scale
+        }
+    }
+
+    // ||self - other||_F <= rel_tol * max(||self||_F, ||other||_F)
+    #[allow(unused)]
+    fn relative_eq(&self, other: &Self, rel_tol: Self::Element) -> bool
+    where
+        Self::Element: Float,
+    {
+        self.relative_frobenius_error(other) <= rel_tol
+    }
+}
+
+// sqrt(sum of v^2), scaled by the largest |v| so that the squares do not overflow
+fn frobenius<Inner: Float>(values: impl Iterator<Item = Inner> + Clone) -> Inner {
+    let scale = values.clone().fold(Inner::zero(), |max, v| max.max(v.abs()));
+    if scale.is_zero() || scale.is_infinite() {
+        return scale;
+    }
+
+    values.map(|v| (v / scale) * (v / scale)).fold(Inner::zero(), |sum, v| sum + v).sqrt() * scale
+}
+
+impl<const H: usize, const W: usize, Inner> ApproxMatrix for GeneralMatrix<H, W, Inner>
+where
+    Inner: Copy,
+{
+    type Element = Inner;
+
+    fn stored_elements(&self) -> Vec<(usize, usize, Inner)> {
+        // column-major, as stored
+        self.as_slice()
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i % H, i / H, *v))
+            .collect()
+    }
+}
+
+impl<const S: usize, Inner> ApproxMatrix for TridiagonalMatrix<S, Inner>
+where
+    Inner: Copy + Zero,
+    [(); S - 1]:,
+{
+    type Element = Inner;
+
+    fn stored_elements(&self) -> Vec<(usize, usize, Inner)> {
+        let (upper, diagonal, lower) = self.inner();
+
+        let upper = upper.iter().enumerate().map(|(i, v)| (i, i + 1, *v));
+        let diagonal = diagonal.iter().enumerate().map(|(i, v)| (i, i, *v));
+        let lower = lower.iter().enumerate().map(|(i, v)| (i + 1, i, *v));
+
+        upper.chain(diagonal).chain(lower).collect()
+    }
+}
+
+// the margin of float-cmp applies to every element: within `epsilon` or `ulps`
+impl<'a, const H: usize, const W: usize, Inner> ApproxEq for &'a GeneralMatrix<H, W, Inner>
+where
+    Inner: ApproxEq + Copy,
+{
+    type Margin = Inner::Margin;
+
+    fn approx_eq<M: Into<Self::Margin>>(self, other: Self, margin: M) -> bool {
+        self.as_slice().approx_eq(other.as_slice(), margin)
+    }
+}
+
+impl<'a, const S: usize, Inner> ApproxEq for &'a TridiagonalMatrix<S, Inner>
+where
+    Inner: ApproxEq + Copy + Zero,
+    [(); S - 1]:,
+{
+    type Margin = Inner::Margin;
+
+    fn approx_eq<M: Into<Self::Margin>>(self, other: Self, margin: M) -> bool {
+        let margin = margin.into();
+        let ((lu, ld, ll), (ru, rd, rl)) = (self.inner(), other.inner());
+
+        lu[..].approx_eq(&ru[..], margin) && ld[..].approx_eq(&rd[..], margin) && ll[..].approx_eq(&rl[..], margin)
+    }
+}
+
+// Margin::zero() of float-cmp is not part of any trait
+pub trait ZeroMargin {
+    fn zero_margin() -> Self;
+}
+
+impl ZeroMargin for F32Margin {
+    fn zero_margin() -> Self {
+        F32Margin::zero()
+    }
+}
+
+impl ZeroMargin for F64Margin {
+    fn zero_margin() -> Self {
+        F64Margin::zero()
+    }
+}
+
+// helpers of the assertion macros below. the matrix only fixes the type of the margin.
+#[doc(hidden)]
+pub fn default_margin<T>(_: &T) -> <T::Element as ApproxEq>::Margin
+where
+    T: ApproxMatrix,
+    T::Element: ApproxEq,
+{
+    Default::default()
+}
+
+#[doc(hidden)]
+pub fn zero_margin<T>(_: &T) -> <T::Element as ApproxEq>::Margin
+where
+    T: ApproxMatrix,
+    T::Element: ApproxEq,
+    <T::Element as ApproxEq>::Margin: ZeroMargin,
+{
+    ZeroMargin::zero_margin()
+}
+
+fn report<Inner: Debug>(elements: &[(usize, usize, Inner, Inner)]) -> String {
+    let mut lines: Vec<_> = elements
+        .iter()
+        .take(MAX_REPORTED)
+        .map(|(row, col, l, r)| format!("  ({}, {}): left = {:?}, right = {:?}", row, col, l, r))
+        .collect();
+
+    if elements.len() > MAX_REPORTED {
+        lines.push(format!("  ... and {} more", elements.len() - MAX_REPORTED));
+    }
+
+    lines.join("\n")
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_approx_eq_with<T>(left: &T, right: &T, margin: <T::Element as ApproxEq>::Margin)
+where
+    T: ApproxMatrix,
+    T::Element: ApproxEq + Debug,
+    <T::Element as ApproxEq>::Margin: Debug,
+{
+    let mismatches = left.approx_mismatches(right, margin);
+    if !mismatches.is_empty() {
+        panic!(
+            "assertion failed: `(left approx_eq right)` with {:?}\n{} element(s) differ (row, col are 0-based):\n{}",
+            margin,
+            mismatches.len(),
+            report(&mismatches)
+        );
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_relative_eq_with<T>(left: &T, right: &T, rel_tol: T::Element)
+where
+    T: ApproxMatrix,
+    T::Element: Float + Debug,
+{
+    let error = left.relative_frobenius_error(right);
+    if error > rel_tol || error.is_nan() {
+        // the largest differences first
+        let mut differing: Vec<_> = left
+            .stored_elements()
+            .into_iter()
+            .zip(right.stored_elements())
+            .filter(|((_, _, l), (_, _, r))| l != r)
+            .map(|((row, col, l), (_, _, r))| (row, col, l, r))
+            .collect();
+        differing.sort_by(|a, b| (b.2 - b.3).abs().partial_cmp(&(a.2 - a.3).abs()).unwrap_or(core::cmp::Ordering::Equal));
+
+        panic!(
+            "assertion failed: relative Frobenius error {:?} exceeds {:?}\n{} element(s) differ (row, col are 0-based):\n{}",
+            error,
+            rel_tol,
+            differing.len(),
+            report(&differing)
+        );
+    }
+}
+
+// assert_matrix_approx_eq!(left, right) compares every element with the default margin of float-cmp.
+// assert_matrix_approx_eq!(left, right, ulps = 2, epsilon = 1e-12) starts from a zero margin, as float-cmp's macros.
+#[macro_export]
+macro_rules! assert_matrix_approx_eq {
+    ($left: expr, $right: expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let margin = $crate::matrix::approx::default_margin(left);
+                $crate::matrix::approx::assert_approx_eq_with(left, right, margin);
+            }
+        }
+    };
+    ($left: expr, $right: expr $(, $set: ident = $val: expr)+ $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let margin = $crate::matrix::approx::zero_margin(left)$(.$set($val))+;
+                $crate::matrix::approx::assert_approx_eq_with(left, right, margin);
+            }
+        }
+    };
+}
+
+// assert_matrix_relative_eq!(left, right, rel_tol) checks ||left - right||_F <= rel_tol * max(||left||_F, ||right||_F).
+#[macro_export]
+macro_rules! assert_matrix_relative_eq {
+    ($left: expr, $right: expr, $rel_tol: expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::matrix::approx::assert_relative_eq_with(left, right, $rel_tol),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approx_eq_general() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[0.1 + 0.2, 1.], [2., 3.]]);
+        let n = GeneralMatrix::new_row_major([[0.3, 1.], [2., 3.]]);
+
+        assert_ne!(m, n);
+        assert!(m.approx_eq(&n, F64Margin::default()));
+        assert!(m.approx_eq(&n, (0., 1)));
+        assert!(!m.approx_eq(&n, (0., 0)));
+        assert!(m.approx_eq(&n, F64Margin::zero().epsilon(1e-15)));
+
+        assert_matrix_approx_eq!(m, n);
+        assert_matrix_approx_eq!(m, n, ulps = 1);
+        assert_matrix_approx_eq!(m, n, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn approx_eq_tridiagonal() {
+        let m: TridiagonalMatrix<3, f32> = TridiagonalMatrix::new([1., 2.], [0.1 + 0.2, 4., 5.], [6., 7.]);
+        let n = TridiagonalMatrix::new([1., 2.], [0.3, 4., 5.], [6., 7.]);
+
+        assert!(m.approx_eq(&n, (0., 1)));
+        assert!(!m.approx_eq(&TridiagonalMatrix::new([1., 2.], [0.3, 4., 5.], [6., 7.5]), F32Margin::default()));
+        assert_matrix_approx_eq!(m, n, ulps = 1);
+    }
+
+    #[test]
+    fn mismatches_are_positioned() {
+        let m: GeneralMatrix<2, 3, f64> = GeneralMatrix::new_row_major([[1., 2., 3.], [4., 5., 6.]]);
+        let n = GeneralMatrix::new_row_major([[1., 2., 3.5], [4., 5.5, 6.]]);
+
+        assert_eq!(
+            m.approx_mismatches(&n, F64Margin::default()),
+            vec![(1, 1, 5., 5.5), (0, 2, 3., 3.5)]
+        );
+
+        let t: TridiagonalMatrix<3, f64> = TridiagonalMatrix::new([1., 2.], [3., 4., 5.], [6., 7.]);
+        let u = TridiagonalMatrix::new([1., 0.], [3., 4., 5.], [6., 8.]);
+        assert_eq!(
+            t.approx_mismatches(&u, F64Margin::default()),
+            vec![(1, 2, 2., 0.), (2, 1, 7., 8.)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "(1, 0): left = 2.0, right = 2.5")]
+    fn assertion_reports_elements() {
+        let m: GeneralMatrix<2, 1, f64> = GeneralMatrix::new_row_major([[1.], [2.]]);
+        assert_matrix_approx_eq!(m, GeneralMatrix::new_row_major([[1.], [2.5]]));
+    }
+
+    #[test]
+    fn relative_frobenius() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[3., 0.], [0., 4.]]);
+        let n = GeneralMatrix::new_row_major([[3., 0.], [0., 4. + 5e-10]]);
+
+        assert_eq!(m.frobenius_norm(), 5.);
+        assert!((m.relative_frobenius_error(&n) - 1e-10).abs() < 1e-15);
+        assert!(m.relative_eq(&n, 1e-9));
+        assert!(!m.relative_eq(&n, 1e-11));
+        assert!(GeneralMatrix::<2, 2, f64>::zero().relative_eq(&GeneralMatrix::zero(), 0.));
+
+        // no overflow in the sum of squares
+        let large: GeneralMatrix<1, 2, f64> = GeneralMatrix::new_row_major([[3e300, 4e300]]);
+        assert!((large.frobenius_norm() / 5e300 - 1.).abs() < 1e-15);
+
+        assert_matrix_relative_eq!(m, n, 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "(1, 1): left = 4.0, right = 5.0")]
+    fn relative_assertion_reports_elements() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[3., 0.], [0., 4.]]);
+        assert_matrix_relative_eq!(m, GeneralMatrix::new_row_major([[3., 0.], [0., 5.]]), 1e-3);
+    }
+}
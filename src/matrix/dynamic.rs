@@ -0,0 +1,333 @@
+pub mod add;
+pub mod lu;
+pub mod mul;
+
+use super::general::GeneralMatrix;
+use super::print_display_with;
+
+use num_traits::{One, Zero};
+
+use core::convert::{From, TryFrom};
+use std::fmt::{Display, Formatter, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynMatrixError {
+    // the number of elements does not match height * width
+    LengthMismatch { expected: usize, found: usize },
+    // (height, width) of the operands are not compatible
+    DimensionMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+}
+
+impl Display for DynMatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::LengthMismatch { expected, found } => write!(
+                f,
+                "Expected {} elements, but {} elements were given.",
+                expected, found
+            ),
+            Self::DimensionMismatch { left, right } => write!(
+                f,
+                "Dimensions {}x{} and {}x{} are not compatible.",
+                left.0, left.1, right.0, right.1
+            ),
+        }
+    }
+}
+
+// column-major matrix whose size is decided at runtime
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynMatrix<Inner>
+where
+    Inner: Clone,
+{
+    height: usize,
+    width: usize,
+    inner: Vec<Inner>,
+}
+
+impl<Inner> DynMatrix<Inner>
+where
+    Inner: Clone,
+{
+    #[inline]
+    #[allow(unused)]
+    pub fn new_col_major(
+        height: usize,
+        width: usize,
+        inner: Vec<Inner>,
+    ) -> core::result::Result<Self, DynMatrixError> {
+        if inner.len() != height * width {
+            return Err(DynMatrixError::LengthMismatch {
+                expected: height * width,
+                found: inner.len(),
+            });
+        }
+
+        Ok(Self {
+            height,
+            width,
+            inner,
+        })
+    }
+
+    #[inline]
+    #[allow(unused)]
+    pub fn new_row_major(
+        height: usize,
+        width: usize,
+        v: Vec<Inner>,
+    ) -> core::result::Result<Self, DynMatrixError> {
+        if v.len() != height * width {
+            return Err(DynMatrixError::LengthMismatch {
+                expected: height * width,
+                found: v.len(),
+            });
+        }
+
+        Ok(Self::by_f(height, width, |col, row| v[row * width + col].clone()))
+    }
+
+    #[inline]
+    #[allow(unused)]
+    pub fn by(height: usize, width: usize, v: Inner) -> Self {
+        Self {
+            height,
+            width,
+            inner: vec![v; height * width],
+        }
+    }
+
+    #[inline]
+    #[allow(unused)]
+    pub fn by_f(height: usize, width: usize, f: impl Fn(usize, usize) -> Inner) -> Self {
+        let mut inner = Vec::with_capacity(height * width);
+        for w in 0..width {
+            for h in 0..height {
+                inner.push(f(w, h));
+            }
+        }
+
+        Self {
+            height,
+            width,
+            inner,
+        }
+    }
+
+    #[inline]
+    #[allow(unused)]
+    pub fn zero(height: usize, width: usize) -> Self
+    where
+        Inner: Zero,
+    {
+        Self::by(height, width, Inner::zero())
+    }
+
+    #[inline]
+    #[allow(unused)]
+    pub fn one(size: usize) -> Self
+    where
+        Inner: Zero + One,
+    {
+        Self::by_f(size, size, |col, row| {
+            if col == row {
+                Inner::one()
+            } else {
+                Inner::zero()
+            }
+        })
+    }
+
+    #[inline]
+    #[allow(unused)]
+    pub fn at(&self, row: usize, col: usize) -> &Inner {
+        assert!(row < self.height && col < self.width);
+        &self.inner[col * self.height + row]
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn at_mut(&mut self, row: usize, col: usize) -> &mut Inner {
+        assert!(row < self.height && col < self.width);
+        &mut self.inner[col * self.height + row]
+    }
+    #[inline]
+    #[allow(unused)]
+    pub const fn size(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn as_slice(&self) -> &[Inner] {
+        &self.inner
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn as_mut_slice(&mut self) -> &mut [Inner] {
+        &mut self.inner
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn into_vec(self) -> Vec<Inner> {
+        self.inner
+    }
+
+    // Err if the sizes of self and rhs are not the same
+    pub(crate) fn check_same_size(&self, rhs: &Self) -> core::result::Result<(), DynMatrixError> {
+        if self.size() == rhs.size() {
+            Ok(())
+        } else {
+            Err(DynMatrixError::DimensionMismatch {
+                left: self.size(),
+                right: rhs.size(),
+            })
+        }
+    }
+}
+
+impl<Inner> Display for DynMatrix<Inner>
+where
+    Inner: Clone + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        print_display_with(self.height, self.width, |row, col| self.at(row, col), f)
+    }
+}
+
+// both layouts are column-major, so the buffer is moved without copying on the heap
+#[cfg(feature = "on_heap")]
+impl<const H: usize, const W: usize, Inner> From<GeneralMatrix<H, W, Inner>> for DynMatrix<Inner>
+where
+    Inner: Clone,
+{
+    fn from(m: GeneralMatrix<H, W, Inner>) -> Self {
+        let ptr = Box::into_raw(m.into_inner_box()) as *mut Inner;
+
+        Self {
+            height: H,
+            width: W,
+            // [[Inner; H]; W] has the same layout as [Inner; H * W]
+            inner: unsafe { Vec::from_raw_parts(ptr, H * W, H * W) },
+        }
+    }
+}
+
+#[cfg(feature = "on_heap")]
+impl<const H: usize, const W: usize, Inner> TryFrom<DynMatrix<Inner>> for GeneralMatrix<H, W, Inner>
+where
+    Inner: Clone,
+{
+    type Error = DynMatrixError;
+
+    // reallocates only if the capacity of the Vec exceeds its length
+    fn try_from(m: DynMatrix<Inner>) -> core::result::Result<Self, Self::Error> {
+        if m.size() != (H, W) {
+            return Err(DynMatrixError::DimensionMismatch {
+                left: (H, W),
+                right: m.size(),
+            });
+        }
+
+        let ptr = Box::into_raw(m.inner.into_boxed_slice()) as *mut [[Inner; H]; W];
+
+        Ok(GeneralMatrix::new_col_major_box(unsafe { Box::from_raw(ptr) }))
+    }
+}
+
+#[cfg(not(feature = "on_heap"))]
+impl<const H: usize, const W: usize, Inner> From<GeneralMatrix<H, W, Inner>> for DynMatrix<Inner>
+where
+    Inner: Clone,
+{
+    fn from(m: GeneralMatrix<H, W, Inner>) -> Self {
+        Self {
+            height: H,
+            width: W,
+            inner: m.as_slice().to_vec(),
+        }
+    }
+}
+
+#[cfg(not(feature = "on_heap"))]
+impl<const H: usize, const W: usize, Inner> TryFrom<DynMatrix<Inner>> for GeneralMatrix<H, W, Inner>
+where
+    Inner: Clone,
+{
+    type Error = DynMatrixError;
+
+    fn try_from(m: DynMatrix<Inner>) -> core::result::Result<Self, Self::Error> {
+        if m.size() != (H, W) {
+            return Err(DynMatrixError::DimensionMismatch {
+                left: (H, W),
+                right: m.size(),
+            });
+        }
+
+        Ok(GeneralMatrix::by_f(|col, row| m.at(row, col).clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::AsMatrix;
+
+    #[test]
+    fn row_major() {
+        let m = DynMatrix::new_row_major(2, 3, vec![1, 2, 3, 4, 5, 6]).expect("length is 6");
+        let ans = DynMatrix::new_col_major(2, 3, vec![1, 4, 2, 5, 3, 6]).expect("length is 6");
+
+        assert_eq!(m, ans);
+        assert_eq!(m.size(), (2, 3));
+        assert_eq!(m.at(0, 2), &3);
+        assert_eq!(m.at(1, 0), &4);
+    }
+
+    #[test]
+    fn wrong_length() {
+        let error = DynMatrix::new_col_major(2, 2, vec![1, 2, 3]).expect_err("length is 3");
+        assert_eq!(
+            error,
+            DynMatrixError::LengthMismatch {
+                expected: 4,
+                found: 3
+            }
+        );
+    }
+
+    #[test]
+    fn display() {
+        let m = DynMatrix::new_row_major(2, 2, vec![1, 2, 3, 4]).expect("length is 4");
+
+        assert_eq!(format!("{}", m), "1 2\n3 4\n");
+        assert_eq!(format!("{:2}", m), " 1  2\n 3  4\n");
+    }
+
+    #[test]
+    fn convert_general() {
+        let g = GeneralMatrix::new_row_major([[1, 2, 3], [4, 5, 6]]);
+
+        let d = DynMatrix::from(g.clone());
+        assert_eq!(d.size(), (2, 3));
+        for row in 0..2 {
+            for col in 0..3 {
+                assert_eq!(d.at(row, col), g.at(row, col));
+            }
+        }
+
+        let back: GeneralMatrix<2, 3, i32> = GeneralMatrix::try_from(d.clone()).expect("same size");
+        assert_eq!(back, g);
+
+        let error = GeneralMatrix::<3, 2, i32>::try_from(d).expect_err("size differs");
+        assert_eq!(
+            error,
+            DynMatrixError::DimensionMismatch {
+                left: (3, 2),
+                right: (2, 3)
+            }
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use super::{DynMatrix, DynMatrixError};
+use crate::backend::{Backend, DefaultBackend};
+use crate::matrix::Complex;
+
+use num_traits::One;
+
+use std::ops::Add;
+
+impl<Inner> DynMatrix<Inner>
+where
+    Inner: Clone + Add<Output = Inner>,
+{
+    #[allow(unused)]
+    pub fn try_add(self, rhs: Self) -> Result<Self, DynMatrixError> {
+        self.check_same_size(&rhs)?;
+        Ok(self + rhs)
+    }
+}
+
+// panics if the sizes differ. use try_add to get the error instead.
+impl<Inner> Add<DynMatrix<Inner>> for DynMatrix<Inner>
+where
+    Inner: Clone + Add<Output = Inner>,
+{
+    type Output = DynMatrix<Inner>;
+
+    default fn add(self, rhs: DynMatrix<Inner>) -> Self::Output {
+        if let Err(error) = self.check_same_size(&rhs) {
+            panic!("{}", error);
+        }
+
+        let (height, width) = self.size();
+        let inner = self
+            .into_vec()
+            .into_iter()
+            .zip(rhs.into_vec())
+            .map(|(l, r)| l + r)
+            .collect();
+
+        DynMatrix {
+            height,
+            width,
+            inner,
+        }
+    }
+}
+
+macro_rules! impl_macro {
+    ($type: ty) => {
+        impl Add<DynMatrix<$type>> for DynMatrix<$type> {
+            #[inline(always)]
+            fn add(self, rhs: DynMatrix<$type>) -> Self::Output {
+                if let Err(error) = self.check_same_size(&rhs) {
+                    panic!("{}", error);
+                }
+
+                // dest += 1 * rhs
+                let mut dest = self;
+                let n = dest.inner.len();
+                <DefaultBackend as Backend<$type>>::axpy(n, <$type>::one(), rhs.as_slice(), 1, dest.as_mut_slice(), 1);
+                dest
+            }
+        }
+    };
+}
+
+impl_macro!(f32);
+impl_macro!(f64);
+impl_macro!(Complex<f32>);
+impl_macro!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn addition_primitive_rust() {
+        let m1 = DynMatrix::new_row_major(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let m2 = DynMatrix::new_row_major(2, 3, vec![6, 5, 4, 3, 2, 1]).unwrap();
+
+        assert_eq!(m1 + m2, DynMatrix::by(2, 3, 7));
+    }
+
+    #[test]
+    fn addition_backend_f64() {
+        let m1: DynMatrix<f64> = DynMatrix::new_row_major(2, 3, vec![1., 2., 3., 4., 5., 6.]).unwrap();
+        let m2 = DynMatrix::new_row_major(2, 3, vec![6., 5., 4., 3., 2., 1.]).unwrap();
+
+        assert_eq!(m1.try_add(m2), Ok(DynMatrix::by(2, 3, 7.)));
+    }
+
+    #[test]
+    fn addition_size_mismatch() {
+        let m1: DynMatrix<f32> = DynMatrix::by(2, 3, 1.);
+        let m2 = DynMatrix::by(3, 2, 1.);
+
+        assert_eq!(
+            m1.try_add(m2),
+            Err(DynMatrixError::DimensionMismatch {
+                left: (2, 3),
+                right: (3, 2)
+            })
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use super::DynMatrix;
+use crate::backend::{Backend, DefaultBackend};
+use crate::factorizations::lu::AsLuError;
+use crate::matrix::general::lu::GeneralLuError;
+use crate::matrix::Complex;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DynLuFormat<Inner>
+where
+    Inner: Clone,
+{
+    pub internal_matrix: DynMatrix<Inner>,
+    // length = min(height, width), 1-based as LAPACK reports it
+    pub pivot: Vec<i32>,
+}
+
+macro_rules! impl_macro {
+    ($type: ty) => {
+        impl DynMatrix<$type> {
+            // same contract as AsLu::lu: Err only for invalid arguments,
+            // a singular U is reported next to the factorization
+            #[allow(unused)]
+            pub fn lu(self) -> Result<(DynLuFormat<$type>, GeneralLuError), GeneralLuError> {
+                let (h, w) = self.size();
+                let mut mat = self;
+                let mut pivot = vec![0; h.min(w)];
+
+                let mut error = GeneralLuError::SUCCESS;
+                *error.as_lapack_into_mut() =
+                    <DefaultBackend as Backend<$type>>::getrf(h, w, mat.as_mut_slice(), h.max(1), &mut pivot);
+
+                if error.is_invalid() {
+                    Err(error)
+                } else {
+                    Ok((
+                        DynLuFormat {
+                            internal_matrix: mat,
+                            pivot,
+                        },
+                        error,
+                    ))
+                }
+            }
+        }
+    };
+}
+
+impl_macro!(f32);
+impl_macro!(f64);
+impl_macro!(Complex<f32>);
+impl_macro!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lu_square() {
+        let m: DynMatrix<f64> = DynMatrix::new_row_major(2, 2, vec![2., 9., 4., 4.]).unwrap();
+        let (lu, error) = m.lu().expect("arguments are valid");
+
+        assert!(!error.is_error());
+        assert_eq!(lu.pivot, vec![2, 2]);
+        assert_eq!(
+            lu.internal_matrix,
+            DynMatrix::new_row_major(2, 2, vec![4., 4., 0.5, 7.]).unwrap()
+        );
+    }
+
+    #[test]
+    fn lu_singular() {
+        let m: DynMatrix<f32> = DynMatrix::new_row_major(2, 2, vec![1., 2., 2., 4.]).unwrap();
+        let (_, error) = m.lu().expect("arguments are valid");
+
+        assert!(error.is_error());
+        assert!(!error.is_invalid());
+    }
+}
@@ -0,0 +1,133 @@
+use super::{DynMatrix, DynMatrixError};
+use crate::backend::{Backend, DefaultBackend, Transpose};
+use crate::matrix::Complex;
+
+use num_traits::{One, Zero};
+
+use std::ops::{AddAssign, Mul};
+
+impl<Inner> DynMatrix<Inner>
+where
+    Inner: Zero + Clone + AddAssign + Mul<Output = Inner>,
+{
+    #[allow(unused)]
+    pub fn try_mul(self, rhs: Self) -> Result<Self, DynMatrixError> {
+        self.check_mul_size(&rhs)?;
+        Ok(self * rhs)
+    }
+
+    // Err if self.width != rhs.height
+    fn check_mul_size(&self, rhs: &Self) -> Result<(), DynMatrixError> {
+        if self.width == rhs.height {
+            Ok(())
+        } else {
+            Err(DynMatrixError::DimensionMismatch {
+                left: self.size(),
+                right: rhs.size(),
+            })
+        }
+    }
+}
+
+// panics if self.width != rhs.height. use try_mul to get the error instead.
+impl<Inner> Mul<DynMatrix<Inner>> for DynMatrix<Inner>
+where
+    Inner: Zero + Clone + AddAssign + Mul<Output = Inner>,
+{
+    // Matrix(LH*LW) * Matrix(LW*RW)
+    type Output = DynMatrix<Inner>;
+
+    default fn mul(self, rhs: DynMatrix<Inner>) -> Self::Output {
+        if let Err(error) = self.check_mul_size(&rhs) {
+            panic!("{}", error);
+        }
+
+        let mut ret = DynMatrix::zero(self.height, rhs.width);
+
+        for h in 0..self.height {
+            for w in 0..rhs.width {
+                for index in 0..self.width {
+                    *ret.at_mut(h, w) += self.at(h, index).clone() * rhs.at(index, w).clone();
+                }
+            }
+        }
+
+        ret
+    }
+}
+
+macro_rules! impl_macro {
+    ($type: ty) => {
+        impl Mul<DynMatrix<$type>> for DynMatrix<$type> {
+            fn mul(self, rhs: DynMatrix<$type>) -> Self::Output {
+                if let Err(error) = self.check_mul_size(&rhs) {
+                    panic!("{}", error);
+                }
+
+                let (lh, lwrh, rw) = (self.height, self.width, rhs.width);
+                let mut ret = DynMatrix::zero(lh, rw);
+
+                // C = 1 * A * B + 0 * C
+                <DefaultBackend as Backend<$type>>::gemm(
+                    Transpose::No,
+                    Transpose::No,
+                    lh,
+                    rw,
+                    lwrh,
+                    <$type>::one(),
+                    self.as_slice(),
+                    lh.max(1),
+                    rhs.as_slice(),
+                    lwrh.max(1),
+                    <$type>::zero(),
+                    ret.as_mut_slice(),
+                    lh.max(1),
+                );
+
+                ret
+            }
+        }
+    };
+}
+
+impl_macro!(f32);
+impl_macro!(f64);
+impl_macro!(Complex<f32>);
+impl_macro!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multiplication_primitive_rust() {
+        let m1 = DynMatrix::new_col_major(2, 3, vec![1, 4, 2, 5, 3, 6]).unwrap();
+        let m2 = DynMatrix::new_col_major(3, 2, vec![1, 3, 5, 2, 4, 6]).unwrap();
+
+        let ans = DynMatrix::new_col_major(2, 2, vec![22, 49, 28, 64]).unwrap();
+        assert_eq!(m1 * m2, ans);
+    }
+
+    #[test]
+    fn multiplication_backend_f64() {
+        let m1: DynMatrix<f64> = DynMatrix::new_col_major(2, 3, vec![1., 4., 2., 5., 3., 6.]).unwrap();
+        let m2 = DynMatrix::new_col_major(3, 2, vec![1., 3., 5., 2., 4., 6.]).unwrap();
+
+        let ans = DynMatrix::new_col_major(2, 2, vec![22., 49., 28., 64.]).unwrap();
+        assert_eq!(m1.try_mul(m2), Ok(ans));
+    }
+
+    #[test]
+    fn multiplication_size_mismatch() {
+        let m1: DynMatrix<f32> = DynMatrix::by(2, 3, 1.);
+        let m2 = DynMatrix::by(2, 3, 1.);
+
+        assert_eq!(
+            m1.try_mul(m2),
+            Err(DynMatrixError::DimensionMismatch {
+                left: (2, 3),
+                right: (2, 3)
+            })
+        );
+    }
+}
@@ -1,6 +1,19 @@
 pub mod add;
+pub mod cholesky;
+pub mod eigen;
+pub mod gemm;
+pub mod hermitian;
+pub mod inverse;
 pub mod lu;
 pub mod mul;
+pub mod qr;
+pub mod scalar;
+pub mod solve;
+pub mod sub;
+pub mod svd;
+pub mod transpose;
+pub mod vector;
+pub mod view;
 
 use super::{print_matrix_display, AsMatrix};
 
@@ -105,6 +118,16 @@ where
     pub const fn as_ptr(&self) -> *const Inner {
         self.inner.as_ptr() as *const Inner
     }
+    #[inline]
+    #[allow(unused)]
+    pub fn as_slice(&self) -> &[Inner] {
+        unsafe { core::slice::from_raw_parts(self.as_ptr(), H * W) }
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn as_mut_slice(&mut self) -> &mut [Inner] {
+        unsafe { core::slice::from_raw_parts_mut(self.inner_mut() as *mut _ as *mut Inner, H * W) }
+    }
 
     #[inline]
     #[allow(unused)]
@@ -167,6 +190,12 @@ where
         Self { inner }
     }
 
+    #[inline]
+    #[allow(unused)]
+    pub fn into_inner_box(self) -> Box<[[Inner; H]; W]> {
+        self.inner
+    }
+
     #[inline]
     #[allow(unused)]
     pub fn new_row_major(v: [[Inner; W]; H]) -> Self {
@@ -1,8 +1,10 @@
-use num_traits::Zero;
+use num_traits::{One, Zero};
 
 use super::{AsMatrix, GeneralMatrix};
+use crate::backend::{Backend, DefaultBackend};
+use crate::matrix::Complex;
 
-use std::ops::Add;
+use std::ops::{Add, AddAssign};
 
 impl<const H: usize, const W: usize, InnerLeft, InnerRight, InnerOut>
     Add<GeneralMatrix<H, W, InnerRight>> for GeneralMatrix<H, W, InnerLeft>
@@ -22,6 +24,75 @@ where
     }
 }
 
+impl<const H: usize, const W: usize, Inner> AddAssign<GeneralMatrix<H, W, Inner>>
+    for GeneralMatrix<H, W, Inner>
+where
+    Inner: AddAssign + Clone,
+{
+    default fn add_assign(&mut self, rhs: GeneralMatrix<H, W, Inner>) {
+        for (dest, value) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+            *dest += value.clone();
+        }
+    }
+}
+
+impl<const H: usize, const W: usize, Inner> AddAssign<&GeneralMatrix<H, W, Inner>>
+    for GeneralMatrix<H, W, Inner>
+where
+    Inner: AddAssign + Clone,
+{
+    default fn add_assign(&mut self, rhs: &GeneralMatrix<H, W, Inner>) {
+        for (dest, value) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+            *dest += value.clone();
+        }
+    }
+}
+
+// borrowed operands. the owned side, if any, is reused as the destination.
+impl<'a, const H: usize, const W: usize, Inner> Add<&'a GeneralMatrix<H, W, Inner>>
+    for GeneralMatrix<H, W, Inner>
+where
+    Inner: AddAssign + Clone,
+{
+    type Output = GeneralMatrix<H, W, Inner>;
+
+    #[inline(always)]
+    fn add(self, rhs: &'a GeneralMatrix<H, W, Inner>) -> Self::Output {
+        let mut dest = self;
+        dest += rhs;
+        dest
+    }
+}
+
+impl<'a, const H: usize, const W: usize, Inner> Add<GeneralMatrix<H, W, Inner>>
+    for &'a GeneralMatrix<H, W, Inner>
+where
+    Inner: AddAssign + Clone,
+{
+    type Output = GeneralMatrix<H, W, Inner>;
+
+    default fn add(self, rhs: GeneralMatrix<H, W, Inner>) -> Self::Output {
+        GeneralMatrix::by_f(|col, row| {
+            let mut value = self.at(row, col).clone();
+            value += rhs.at(row, col).clone();
+            value
+        })
+    }
+}
+
+impl<'a, 'b, const H: usize, const W: usize, Inner> Add<&'b GeneralMatrix<H, W, Inner>>
+    for &'a GeneralMatrix<H, W, Inner>
+where
+    Inner: AddAssign + Clone,
+{
+    type Output = GeneralMatrix<H, W, Inner>;
+
+    #[inline(always)]
+    fn add(self, rhs: &'b GeneralMatrix<H, W, Inner>) -> Self::Output {
+        self.clone() + rhs
+    }
+}
+
 macro_rules! impl_macro {
     ($lapack: ident, $type: ty) => {
         paste::paste! {
@@ -33,28 +104,14 @@ macro_rules! impl_macro {
                 value: &GeneralMatrix<H, W, $type>,
             ) {
                 // dest += alpha * value
-
-                #[link(name = "lapack")]
-                extern "C" {
-                    fn [<$lapack _>](
-                        n: *const i32,
-                        alpha: *const $type,
-                        x: *const $type,
-                        incx: *const i32,
-                        y: *mut $type,
-                        incy: *const i32,
-                    );
-                }
-                let n = &((H * W) as i32);
-                let alpha = &alpha;
-                let x = value.inner() as *const _ as *const $type;
-                let incx = &1;
-                let y = dest.inner_mut() as *mut _ as *mut $type;
-                let incy = &1;
-
-                unsafe {
-                    [<$lapack _>](n, alpha, x, incx, y, incy);
-                }
+                <DefaultBackend as Backend<$type>>::axpy(
+                    H * W,
+                    alpha,
+                    value.as_slice(),
+                    1,
+                    dest.as_mut_slice(),
+                    1,
+                );
             }
         }
 
@@ -64,7 +121,37 @@ macro_rules! impl_macro {
             #[inline(always)]
             default fn add(self, rhs: GeneralMatrix<H, W, $type>) -> Self::Output {
                 let mut dest = self;
-                concat_idents!(general_matrix_add_, $lapack)(&mut dest, 1.0, &rhs);
+                concat_idents!(general_matrix_add_, $lapack)(&mut dest, <$type>::one(), &rhs);
+                dest
+            }
+        }
+
+        impl<const H: usize, const W: usize> AddAssign<GeneralMatrix<H, W, $type>>
+            for GeneralMatrix<H, W, $type>
+        {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: GeneralMatrix<H, W, $type>) {
+                concat_idents!(general_matrix_add_, $lapack)(self, <$type>::one(), &rhs);
+            }
+        }
+
+        impl<const H: usize, const W: usize> AddAssign<&GeneralMatrix<H, W, $type>>
+            for GeneralMatrix<H, W, $type>
+        {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: &GeneralMatrix<H, W, $type>) {
+                concat_idents!(general_matrix_add_, $lapack)(self, <$type>::one(), rhs);
+            }
+        }
+
+        // addition commutes, so the owned rhs takes the result
+        impl<'a, const H: usize, const W: usize> Add<GeneralMatrix<H, W, $type>>
+            for &'a GeneralMatrix<H, W, $type>
+        {
+            #[inline(always)]
+            fn add(self, rhs: GeneralMatrix<H, W, $type>) -> Self::Output {
+                let mut dest = rhs;
+                concat_idents!(general_matrix_add_, $lapack)(&mut dest, <$type>::one(), self);
                 dest
             }
         }
@@ -73,6 +160,8 @@ macro_rules! impl_macro {
 
 impl_macro!(saxpy, f32);
 impl_macro!(daxpy, f64);
+impl_macro!(caxpy, Complex<f32>);
+impl_macro!(zaxpy, Complex<f64>);
 
 #[cfg(test)]
 mod tests {
@@ -88,6 +177,47 @@ mod tests {
         assert_eq!(m1 + m2, ans);
     }
 
+    #[test]
+    fn add_assign() {
+        let mut m1 = GeneralMatrix::new_row_major([[1, 2], [3, 4]]);
+        m1 += GeneralMatrix::new_row_major([[4, 3], [2, 1]]);
+        assert_eq!(m1, GeneralMatrix::by(5));
+
+        let mut m2: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[1., 2.], [3., 4.]]);
+        m2 += GeneralMatrix::new_row_major([[4., 3.], [2., 1.]]);
+        assert_eq!(m2, GeneralMatrix::by(5.));
+    }
+
+    #[test]
+    fn addition_borrowed() {
+        let m1 = GeneralMatrix::new_row_major([[1, 2], [3, 4]]);
+        let m2 = GeneralMatrix::new_row_major([[4, 3], [2, 1]]);
+        let ans = GeneralMatrix::by(5);
+
+        assert_eq!(&m1 + &m2, ans);
+        assert_eq!(m1.clone() + &m2, ans);
+        assert_eq!(&m1 + m2.clone(), ans);
+
+        let mut m3 = m1;
+        m3 += &m2;
+        assert_eq!(m3, ans);
+    }
+
+    #[test]
+    fn addition_borrowed_lapack_f64() {
+        let m1: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[1., 2.], [3., 4.]]);
+        let m2 = GeneralMatrix::new_row_major([[4., 3.], [2., 1.]]);
+        let ans = GeneralMatrix::by(5.);
+
+        assert_eq!(&m1 + &m2, ans);
+        assert_eq!(m1.clone() + &m2, ans);
+        assert_eq!(&m1 + m2.clone(), ans);
+
+        let mut m3 = m1;
+        m3 += &m2;
+        assert_eq!(m3, ans);
+    }
+
     #[test]
     fn internal_impl_macro() {
         let mut m1 = GeneralMatrix::new_row_major([[1., 2., 3.], [4., 5., 6.]]);
@@ -125,4 +255,17 @@ mod tests {
 
         assert_eq!(m1 + m2, ans);
     }
+
+    #[test]
+    fn addition_lapack_complex() {
+        let m1: GeneralMatrix<1, 2, Complex<f32>> = GeneralMatrix::new_row_major([[Complex::new(1., 2.), Complex::new(3., 4.)]]);
+        let m2 = GeneralMatrix::new_row_major([[Complex::new(-1., 1.), Complex::new(0., -4.)]]);
+
+        let ans = GeneralMatrix::new_row_major([[Complex::new(0., 3.), Complex::new(3., 0.)]]);
+        assert_eq!(&m1 + &m2, ans);
+
+        let mut m3 = m1;
+        m3 -= m2;
+        assert_eq!(m3, GeneralMatrix::new_row_major([[Complex::new(2., 1.), Complex::new(3., 8.)]]));
+    }
 }
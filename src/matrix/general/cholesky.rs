@@ -0,0 +1,214 @@
+use super::solve::GeneralSolveError;
+use super::{AsMatrix, GeneralMatrix};
+use crate::backend::{DefaultBackend, RealBackend};
+use crate::factorizations::cholesky::{self, Uplo};
+use crate::solve;
+
+use num_traits::Zero;
+
+#[derive(Debug, PartialEq)]
+pub struct GeneralCholeskyFormat<const S: usize, Inner>
+where
+    Inner: Clone,
+{
+    pub internal_matrix: GeneralMatrix<S, S, Inner>,
+    pub uplo: Uplo,
+}
+
+impl<const S: usize, Inner> GeneralCholeskyFormat<S, Inner>
+where
+    Inner: Clone,
+{
+    pub fn new(matrix: GeneralMatrix<S, S, Inner>, uplo: Uplo) -> Self {
+        Self {
+            internal_matrix: matrix,
+            uplo,
+        }
+    }
+}
+
+impl<const S: usize, Inner> GeneralCholeskyFormat<S, Inner>
+where
+    Inner: Clone + Zero,
+{
+    // ?potrf leaves the other triangle untouched; this returns L (or U) with it cleared.
+    #[allow(unused)]
+    pub fn factor(&self) -> GeneralMatrix<S, S, Inner> {
+        GeneralMatrix::by_f(|col, row| {
+            let referenced = match self.uplo {
+                Uplo::Upper => row <= col,
+                Uplo::Lower => row >= col,
+            };
+            if referenced {
+                self.internal_matrix.at(row, col).clone()
+            } else {
+                Inner::zero()
+            }
+        })
+    }
+}
+
+#[derive(PartialEq, Eq)]
+pub struct GeneralCholeskyError(i32);
+
+impl GeneralCholeskyError {
+    pub const SUCCESS: Self = Self(0);
+    pub const INVALID_ARG_UPLO: Self = Self(-1);
+    pub const INVALID_ARG_SIZE: Self = Self(-2);
+    pub const INVALID_ARG_MATRIX: Self = Self(-3);
+    pub const INVALID_ARG_MATRIX_LD: Self = Self(-4);
+    pub const INVALID_ARG_INFO: Self = Self(-5);
+}
+
+impl core::fmt::Debug for GeneralCholeskyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::SUCCESS => write!(f, "GeneralCholeskyError::SUCCESS"),
+            Self::INVALID_ARG_UPLO => write!(f, "GeneralCholeskyError::INVALID_ARG_UPLO"),
+            Self::INVALID_ARG_SIZE => write!(f, "GeneralCholeskyError::INVALID_ARG_SIZE"),
+            Self::INVALID_ARG_MATRIX => write!(f, "GeneralCholeskyError::INVALID_ARG_MATRIX"),
+            Self::INVALID_ARG_MATRIX_LD => write!(f, "GeneralCholeskyError::INVALID_ARG_MATRIX_LD"),
+            Self::INVALID_ARG_INFO => write!(f, "GeneralCholeskyError::INVALID_ARG_INFO"),
+            _ => write!(f, "The leading minor of order {} is not positive definite, and the factorization could not be completed.", self.0),
+        }
+    }
+}
+
+impl core::fmt::Display for GeneralCholeskyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <Self as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+impl cholesky::AsCholeskyError for GeneralCholeskyError {
+    fn as_lapack_into_mut(&mut self) -> &mut i32 {
+        &mut self.0
+    }
+    fn as_lapack_into(&self) -> &i32 {
+        &self.0
+    }
+}
+
+macro_rules! impl_macro {
+    ($type: ty) => {
+        impl<const S: usize> cholesky::CholeskyFormat<GeneralMatrix<S, S, $type>>
+            for GeneralCholeskyFormat<S, $type>
+        {
+            fn new_with(internal_matrix: GeneralMatrix<S, S, $type>, uplo: Uplo) -> Self {
+                Self {
+                    internal_matrix,
+                    uplo,
+                }
+            }
+            fn data_ref(&self) -> (&GeneralMatrix<S, S, $type>, &Uplo) {
+                (&self.internal_matrix, &self.uplo)
+            }
+            fn data_mut(&mut self) -> (&mut GeneralMatrix<S, S, $type>, &mut Uplo) {
+                (&mut self.internal_matrix, &mut self.uplo)
+            }
+        }
+
+        impl<const S: usize> cholesky::AsCholesky<S, $type, GeneralCholeskyError>
+            for GeneralMatrix<S, S, $type>
+        {
+            type Cholesky = GeneralCholeskyFormat<S, $type>;
+
+            default fn fact_internal(dest: &mut Self::Cholesky) -> GeneralCholeskyError {
+                use cholesky::{AsCholeskyError, CholeskyFormat};
+
+                let (mat, uplo) = dest.data_mut();
+
+                let mut error = GeneralCholeskyError::SUCCESS;
+
+                *error.as_lapack_into_mut() =
+                    <DefaultBackend as RealBackend<$type>>::potrf(*uplo, S, mat.as_mut_slice(), S);
+
+                error
+            }
+        }
+
+        impl<const S: usize, const N: usize>
+            solve::CholeskySolve<GeneralMatrix<S, N, $type>, GeneralSolveError>
+            for GeneralCholeskyFormat<S, $type>
+        {
+            type Output = GeneralMatrix<S, N, $type>;
+
+            fn solve(
+                &self,
+                rhs: GeneralMatrix<S, N, $type>,
+            ) -> Result<Self::Output, GeneralSolveError> {
+                use solve::AsSolveError;
+
+                let mut dest = rhs;
+
+                let mut error = GeneralSolveError::SUCCESS;
+
+                *error.as_lapack_into_mut() = <DefaultBackend as RealBackend<$type>>::potrs(
+                    self.uplo,
+                    S,
+                    N,
+                    self.internal_matrix.as_slice(),
+                    S,
+                    dest.as_mut_slice(),
+                    S,
+                );
+
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(dest)
+                }
+            }
+        }
+    };
+}
+
+impl_macro!(f32);
+impl_macro!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factorizations::cholesky::{AsCholesky, AsCholeskyError};
+    use crate::solve::CholeskySolve;
+
+    #[test]
+    fn factorize_cholesky_lower() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[4., 2.], [2., 5.]]);
+        let ch = m.cholesky().expect("matrix is positive definite");
+
+        assert_eq!(ch.uplo, Uplo::Lower);
+        assert_eq!(ch.factor(), GeneralMatrix::new_row_major([[2., 0.], [1., 2.]]));
+    }
+
+    #[test]
+    fn factorize_cholesky_upper() {
+        let m: GeneralMatrix<2, 2, f32> = GeneralMatrix::new_row_major([[4., 2.], [2., 5.]]);
+        let ch = m.cholesky_with(Uplo::Upper).expect("matrix is positive definite");
+
+        assert_eq!(ch.uplo, Uplo::Upper);
+        assert_eq!(ch.factor(), GeneralMatrix::new_row_major([[2., 1.], [0., 2.]]));
+    }
+
+    #[test]
+    fn not_positive_definite() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[1., 2.], [2., 1.]]);
+        let error = m.cholesky().expect_err("matrix is not positive definite");
+
+        assert!(!error.is_invalid());
+        assert_eq!(error.leading_minor(), Some(2));
+    }
+
+    #[test]
+    fn solve_with_cholesky() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[4., 2.], [2., 5.]]);
+        let b = GeneralMatrix::new_col_major([[6., 7.], [12., 14.]]);
+
+        for uplo in [Uplo::Lower, Uplo::Upper] {
+            let ch = m.clone().cholesky_with(uplo).expect("matrix is positive definite");
+            let x = ch.solve(b.clone()).expect("solve failed");
+
+            assert_eq!(x, GeneralMatrix::new_col_major([[1., 1.], [2., 2.]]));
+        }
+    }
+}
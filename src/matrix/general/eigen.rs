@@ -0,0 +1,490 @@
+use super::{AsMatrix, GeneralMatrix};
+use crate::backend::{DefaultBackend, RealBackend, Uplo};
+use crate::factorizations::eigen::{self, EigenRange};
+use crate::matrix::Complex;
+
+use num_traits::{Float, Zero};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GeneralEigenFormat<Values, Vectors> {
+    pub values: Values,
+    // i-th eigenvector belongs to i-th eigenvalue
+    pub vectors: Vectors,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GeneralComplexEigenFormat<Values, Vectors> {
+    pub values: Values,
+    // i-th column belongs to i-th eigenvalue. None unless requested.
+    pub left: Option<Vectors>,
+    pub right: Option<Vectors>,
+}
+
+// real Schur form A = Z * T * Z^T.
+// T is quasi upper triangular; complex conjugate pairs appear as 2x2 diagonal blocks.
+#[derive(Debug, PartialEq)]
+pub struct GeneralSchurFormat<const S: usize, Inner>
+where
+    Inner: Clone,
+{
+    pub t: GeneralMatrix<S, S, Inner>,
+    pub z: GeneralMatrix<S, S, Inner>,
+    pub values: [Complex<Inner>; S],
+}
+
+#[derive(PartialEq, Eq)]
+pub struct GeneralEigenError(i32);
+
+impl GeneralEigenError {
+    pub const SUCCESS: Self = Self(0);
+}
+
+impl core::fmt::Debug for GeneralEigenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::SUCCESS => write!(f, "GeneralEigenError::SUCCESS"),
+            Self(info) if info < 0 => write!(f, "GeneralEigenError::INVALID_ARG({})", -info),
+            _ => write!(f, "The eigenvalue algorithm failed to converge (info = {}).", self.0),
+        }
+    }
+}
+
+impl core::fmt::Display for GeneralEigenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <Self as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+impl eigen::AsEigenError for GeneralEigenError {
+    fn as_lapack_into_mut(&mut self) -> &mut i32 {
+        &mut self.0
+    }
+    fn as_lapack_into(&self) -> &i32 {
+        &self.0
+    }
+}
+
+// LAPACK stores a complex conjugate pair of eigenvectors as two real columns (re, im).
+fn complex_vectors<const S: usize, T: Float>(v: &GeneralMatrix<S, S, T>, wi: &[T; S]) -> GeneralMatrix<S, S, Complex<T>> {
+    let mut ret = GeneralMatrix::zero();
+
+    let mut col = 0;
+    while col < S {
+        if wi[col].is_zero() {
+            for row in 0..S {
+                *ret.at_mut(row, col) = Complex::new(*v.at(row, col), T::zero());
+            }
+            col += 1;
+        } else {
+            for row in 0..S {
+                let (re, im) = (*v.at(row, col), *v.at(row, col + 1));
+                *ret.at_mut(row, col) = Complex::new(re, im);
+                *ret.at_mut(row, col + 1) = Complex::new(re, -im);
+            }
+            col += 2;
+        }
+    }
+
+    ret
+}
+
+fn complex_values<const S: usize, T: Float>(wr: &[T; S], wi: &[T; S]) -> [Complex<T>; S] {
+    let mut values = [Complex::new(T::zero(), T::zero()); S];
+    for (value, (re, im)) in values.iter_mut().zip(wr.iter().zip(wi.iter())) {
+        *value = Complex::new(*re, *im);
+    }
+    values
+}
+
+macro_rules! impl_macro {
+    ($type: ty) => {
+        impl<const S: usize> GeneralMatrix<S, S, $type> {
+            // all eigenvalues into `w`, and eigenvectors into `self` if job is 'V'.
+            fn symmetric_internal(&mut self, job: char, w: &mut [$type; S]) -> GeneralEigenError {
+                use eigen::AsEigenError;
+
+                let mut error = GeneralEigenError::SUCCESS;
+
+                *error.as_lapack_into_mut() =
+                    <DefaultBackend as RealBackend<$type>>::syevd(job, Uplo::Lower, S, self.as_mut_slice(), S, w);
+
+                error
+            }
+
+            // eigenvalues in `range`, and their eigenvectors if job is 'V'.
+            fn symmetric_range_internal(
+                &self,
+                job: char,
+                range: EigenRange<$type>,
+            ) -> Result<(Vec<$type>, Vec<[$type; S]>), GeneralEigenError> {
+                use eigen::AsEigenError;
+
+                let (range_char, vl, vu, il, iu) = match range {
+                    EigenRange::All => ('A', 0., 0., 0, 0),
+                    EigenRange::Index(start, end) => {
+                        if end <= start {
+                            return Ok((Vec::new(), Vec::new()));
+                        }
+                        ('I', 0., 0., start + 1, end)
+                    }
+                    EigenRange::Value(lower, upper) => ('V', lower, upper, 0, 0),
+                };
+                let ldz = if job == 'V' { S.max(1) } else { 1 };
+
+                // ?syevr destroys the lower triangle
+                let mut a = self.clone();
+
+                let mut found = 0;
+                let mut w = vec![0. as $type; S.max(1)];
+                let mut z = vec![0. as $type; ldz * S.max(1)];
+
+                let mut error = GeneralEigenError::SUCCESS;
+
+                *error.as_lapack_into_mut() = <DefaultBackend as RealBackend<$type>>::syevr(
+                    job,
+                    range_char,
+                    Uplo::Lower,
+                    S,
+                    a.as_mut_slice(),
+                    S,
+                    vl,
+                    vu,
+                    il,
+                    iu,
+                    &mut found,
+                    &mut w,
+                    &mut z,
+                    ldz,
+                );
+                if error.is_error() {
+                    return Err(error);
+                }
+
+                w.truncate(found);
+
+                let vectors = if job == 'V' {
+                    z.chunks(ldz)
+                        .take(found)
+                        .map(|col| {
+                            let mut v = [0. as $type; S];
+                            v.copy_from_slice(&col[..S]);
+                            v
+                        })
+                        .collect()
+                } else {
+                    Vec::new()
+                };
+
+                Ok((w, vectors))
+            }
+        }
+
+        impl<const S: usize> eigen::AsSymmetricEigen<S, $type, GeneralEigenError>
+            for GeneralMatrix<S, S, $type>
+        {
+            type Values = [$type; S];
+            type Eigen = GeneralEigenFormat<[$type; S], GeneralMatrix<S, S, $type>>;
+            type RangeEigen = GeneralEigenFormat<Vec<$type>, Vec<[$type; S]>>;
+
+            fn symmetric_eigenvalues(&self) -> Result<Self::Values, GeneralEigenError> {
+                use eigen::AsEigenError;
+
+                let mut mat = self.clone();
+                let mut w = [0. as $type; S];
+                let error = mat.symmetric_internal('N', &mut w);
+
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(w)
+                }
+            }
+
+            fn symmetric_eigen(&self) -> Result<Self::Eigen, GeneralEigenError> {
+                use eigen::AsEigenError;
+
+                let mut mat = self.clone();
+                let mut w = [0. as $type; S];
+                let error = mat.symmetric_internal('V', &mut w);
+
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(GeneralEigenFormat {
+                        values: w,
+                        vectors: mat,
+                    })
+                }
+            }
+
+            fn symmetric_eigenvalues_range(
+                &self,
+                range: EigenRange<$type>,
+            ) -> Result<Vec<$type>, GeneralEigenError> {
+                self.symmetric_range_internal('N', range).map(|(values, _)| values)
+            }
+
+            fn symmetric_eigen_range(
+                &self,
+                range: EigenRange<$type>,
+            ) -> Result<Self::RangeEigen, GeneralEigenError> {
+                self.symmetric_range_internal('V', range)
+                    .map(|(values, vectors)| GeneralEigenFormat { values, vectors })
+            }
+        }
+
+        impl<const S: usize> eigen::AsGeneralEigen<S, $type, GeneralEigenError>
+            for GeneralMatrix<S, S, $type>
+        {
+            type Values = [Complex<$type>; S];
+            type Eigen = GeneralComplexEigenFormat<
+                [Complex<$type>; S],
+                GeneralMatrix<S, S, Complex<$type>>,
+            >;
+            type Schur = GeneralSchurFormat<S, $type>;
+
+            fn eigenvalues(&self) -> Result<Self::Values, GeneralEigenError> {
+                self.eigen(false, false).map(|eigen| eigen.values)
+            }
+
+            fn eigen(&self, left: bool, right: bool) -> Result<Self::Eigen, GeneralEigenError> {
+                use eigen::AsEigenError;
+
+                let mut a = self.clone();
+                let mut wr = [0. as $type; S];
+                let mut wi = [0. as $type; S];
+                let mut vl = GeneralMatrix::<S, S, $type>::zero();
+                let mut vr = GeneralMatrix::<S, S, $type>::zero();
+
+                let mut error = GeneralEigenError::SUCCESS;
+
+                *error.as_lapack_into_mut() = <DefaultBackend as RealBackend<$type>>::geev(
+                    if left { 'V' } else { 'N' },
+                    if right { 'V' } else { 'N' },
+                    S,
+                    a.as_mut_slice(),
+                    S,
+                    &mut wr,
+                    &mut wi,
+                    vl.as_mut_slice(),
+                    S,
+                    vr.as_mut_slice(),
+                    S,
+                );
+                if error.is_error() {
+                    return Err(error);
+                }
+
+                Ok(GeneralComplexEigenFormat {
+                    values: complex_values(&wr, &wi),
+                    left: if left { Some(complex_vectors(&vl, &wi)) } else { None },
+                    right: if right { Some(complex_vectors(&vr, &wi)) } else { None },
+                })
+            }
+
+            fn schur(&self) -> Result<Self::Schur, GeneralEigenError> {
+                use eigen::AsEigenError;
+
+                let mut t = self.clone();
+                let mut z = GeneralMatrix::<S, S, $type>::zero();
+                let mut wr = [0. as $type; S];
+                let mut wi = [0. as $type; S];
+
+                let mut error = GeneralEigenError::SUCCESS;
+
+                *error.as_lapack_into_mut() = <DefaultBackend as RealBackend<$type>>::gees(
+                    S,
+                    t.as_mut_slice(),
+                    S,
+                    &mut wr,
+                    &mut wi,
+                    z.as_mut_slice(),
+                    S,
+                );
+                if error.is_error() {
+                    return Err(error);
+                }
+
+                Ok(GeneralSchurFormat {
+                    t,
+                    z,
+                    values: complex_values(&wr, &wi),
+                })
+            }
+        }
+
+        impl<const S: usize> GeneralSchurFormat<S, $type> {
+            // moves the eigenvalues for which `select` holds to the leading block of T
+            // (and updates Z accordingly). returns the dimension of that block.
+            // selecting either member of a complex conjugate pair selects both.
+            #[allow(unused)]
+            pub fn reorder(
+                &mut self,
+                select: impl Fn(Complex<$type>) -> bool,
+            ) -> Result<usize, GeneralEigenError> {
+                use eigen::AsEigenError;
+
+                let selected: Vec<bool> = self.values.iter().map(|v| select(*v)).collect();
+                let mut wr = [0. as $type; S];
+                let mut wi = [0. as $type; S];
+                let mut m = 0;
+
+                let mut error = GeneralEigenError::SUCCESS;
+
+                *error.as_lapack_into_mut() = <DefaultBackend as RealBackend<$type>>::trsen(
+                    &selected,
+                    S,
+                    self.t.as_mut_slice(),
+                    S,
+                    self.z.as_mut_slice(),
+                    S,
+                    &mut wr,
+                    &mut wi,
+                    &mut m,
+                );
+                if error.is_error() {
+                    return Err(error);
+                }
+
+                self.values = complex_values(&wr, &wi);
+
+                Ok(m)
+            }
+        }
+    };
+}
+
+impl_macro!(f32);
+impl_macro!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_matrix_approx_eq;
+    use crate::factorizations::eigen::{AsGeneralEigen, AsSymmetricEigen};
+
+    fn symmetric() -> GeneralMatrix<3, 3, f64> {
+        GeneralMatrix::new_row_major([[2., 1., 0.], [1., 2., 0.], [0., 0., 5.]])
+    }
+
+    #[test]
+    fn eigenvalues_sorted() {
+        let w = symmetric().symmetric_eigenvalues().expect("eigen solver failed");
+
+        assert_matrix_approx_eq!(
+            GeneralMatrix::new_col_major([w]),
+            GeneralMatrix::new_col_major([[1., 3., 5.]]),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn eigenvectors_orthonormal() {
+        use num_traits::One;
+
+        let m = symmetric();
+        let GeneralEigenFormat { values, vectors } = m.symmetric_eigen().expect("eigen solver failed");
+        let lambda = GeneralMatrix::by_f(|col, row| if col == row { values[col] } else { 0. });
+
+        // A V = V Lambda and V^T V = I
+        assert_matrix_approx_eq!(m * vectors.clone(), vectors.clone() * lambda, epsilon = 1e-12);
+        assert_matrix_approx_eq!(vectors.transposed() * vectors, GeneralMatrix::one(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn eigenvalues_index_range() {
+        let w = symmetric()
+            .symmetric_eigenvalues_range(EigenRange::Index(1, 3))
+            .expect("eigen solver failed");
+
+        assert_eq!(w.len(), 2);
+        assert_matrix_approx_eq!(
+            GeneralMatrix::new_col_major([[w[0], w[1]]]),
+            GeneralMatrix::new_col_major([[3., 5.]]),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn eigen_value_range() {
+        let eigen = symmetric()
+            .symmetric_eigen_range(EigenRange::Value(0., 2.))
+            .expect("eigen solver failed");
+
+        assert_eq!(eigen.values.len(), 1);
+        assert_eq!(eigen.vectors.len(), 1);
+
+        // the vector is (1, -1, 0) / sqrt(2) up to its sign
+        let v = eigen.vectors[0];
+        assert_matrix_approx_eq!(
+            GeneralMatrix::new_col_major([[eigen.values[0], v[0] + v[1], v[2]]]),
+            GeneralMatrix::new_col_major([[1., 0., 0.]]),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn general_eigenvalues_complex() {
+        let rotation: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[0., -1.], [1., 0.]]);
+        let values = rotation.eigenvalues().expect("eigen solver failed");
+
+        // (re, im) of each eigenvalue in a column
+        assert_matrix_approx_eq!(
+            GeneralMatrix::new_col_major(values.map(|v| [v.re, v.im])),
+            GeneralMatrix::new_col_major([[0., 1.], [0., -1.]]),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn general_eigenvectors() {
+        let m: GeneralMatrix<3, 3, f64> =
+            GeneralMatrix::new_row_major([[0., -2., 0.], [1., 0., 0.], [0., 0., 3.]]);
+        let eigen = m.eigen(true, true).expect("eigen solver failed");
+
+        let right = eigen.right.expect("right eigenvectors are requested");
+        let left = eigen.left.expect("left eigenvectors are requested");
+
+        // |A v - lambda v| and |u^H A - lambda u^H| of the k-th eigenvector in the k-th column
+        let right_residual = GeneralMatrix::<3, 3, f64>::by_f(|k, i| {
+            let av: Complex<f64> = (0..3).map(|j| right.at(j, k) * m.at(i, j)).sum();
+            (av - right.at(i, k) * eigen.values[k]).norm()
+        });
+        let left_residual = GeneralMatrix::<3, 3, f64>::by_f(|k, i| {
+            let ua: Complex<f64> = (0..3).map(|j| left.at(j, k).conj() * m.at(j, i)).sum();
+            (ua - left.at(i, k).conj() * eigen.values[k]).norm()
+        });
+
+        assert_matrix_approx_eq!(right_residual, GeneralMatrix::zero(), epsilon = 1e-12);
+        assert_matrix_approx_eq!(left_residual, GeneralMatrix::zero(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn schur_reorder() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[1., 2.], [0., 3.]]);
+        let mut schur = m.schur().expect("schur decomposition failed");
+
+        let selected = schur.reorder(|v| v.re > 2.).expect("reordering failed");
+
+        assert_eq!(selected, 1);
+        // the upper right element of T is not unique
+        let t = &schur.t;
+        assert_matrix_approx_eq!(
+            GeneralMatrix::new_row_major([[*t.at(0, 0), 0.], [*t.at(1, 0), *t.at(1, 1)]]),
+            GeneralMatrix::new_row_major([[3., 0.], [0., 1.]]),
+            epsilon = 1e-12
+        );
+        assert_matrix_approx_eq!(
+            GeneralMatrix::new_col_major([[schur.values[0].re, schur.values[0].im]]),
+            GeneralMatrix::new_col_major([[3., 0.]]),
+            epsilon = 1e-12
+        );
+
+        // A = Z T Z^T
+        assert_matrix_approx_eq!(
+            schur.z.clone() * schur.t.clone() * schur.z.transposed(),
+            m,
+            epsilon = 1e-12
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use super::hermitian::Conjugate;
+use super::transpose::AsGemmOperand;
+use super::GeneralMatrix;
+use crate::backend::{Backend, DefaultBackend, Transpose};
+use crate::matrix::Complex;
+
+use num_traits::{MulAdd, One, Zero};
+
+use std::ops::{AddAssign, Mul};
+
+// ?gemm on column-major slices: C = alpha * op(A) * op(B) + beta * C.
+// f32/f64 and their complex counterparts go to the backend, any other Inner to the loop below.
+pub trait GemmInner: Sized {
+    #[allow(clippy::too_many_arguments)]
+    fn gemm(
+        trans_a: Transpose,
+        trans_b: Transpose,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        b: &[Self],
+        ldb: usize,
+        beta: Self,
+        c: &mut [Self],
+        ldc: usize,
+    );
+}
+
+impl<Inner> GemmInner for Inner
+where
+    Inner: Zero + Conjugate + AddAssign + Mul<Output = Inner>,
+{
+    default fn gemm(
+        trans_a: Transpose,
+        trans_b: Transpose,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        b: &[Self],
+        ldb: usize,
+        beta: Self,
+        c: &mut [Self],
+        ldc: usize,
+    ) {
+        let op_a = |row: usize, col: usize| match trans_a {
+            Transpose::No => a[row + col * lda].clone(),
+            Transpose::Trans => a[col + row * lda].clone(),
+            Transpose::ConjTrans => a[col + row * lda].conjugate(),
+        };
+        let op_b = |row: usize, col: usize| match trans_b {
+            Transpose::No => b[row + col * ldb].clone(),
+            Transpose::Trans => b[col + row * ldb].clone(),
+            Transpose::ConjTrans => b[col + row * ldb].conjugate(),
+        };
+
+        for col in 0..n {
+            for row in 0..m {
+                let mut sum = Inner::zero();
+                for l in 0..k {
+                    sum += op_a(row, l) * op_b(l, col);
+                }
+
+                let dest = &mut c[row + col * ldc];
+                // as in BLAS, C is not read when beta is zero
+                let mut value = alpha.clone() * sum;
+                if !beta.is_zero() {
+                    value += beta.clone() * dest.clone();
+                }
+                *dest = value;
+            }
+        }
+    }
+}
+
+macro_rules! impl_macro {
+    ($type: ty) => {
+        impl GemmInner for $type {
+            fn gemm(
+                trans_a: Transpose,
+                trans_b: Transpose,
+                m: usize,
+                n: usize,
+                k: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                b: &[Self],
+                ldb: usize,
+                beta: Self,
+                c: &mut [Self],
+                ldc: usize,
+            ) {
+                <DefaultBackend as Backend<$type>>::gemm(
+                    trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+                );
+            }
+        }
+    };
+}
+
+impl_macro!(f32);
+impl_macro!(f64);
+impl_macro!(Complex<f32>);
+impl_macro!(Complex<f64>);
+
+impl<const LH: usize, const RW: usize, Inner> GeneralMatrix<LH, RW, Inner>
+where
+    Inner: GemmInner + Clone,
+{
+    // self = alpha * op(A) * op(B) + beta * self, in place.
+    // op is given by the operand: a matrix or view as is, or its transpose()/conj_transpose().
+    #[allow(unused)]
+    pub fn gemm<const K: usize, A, B>(&mut self, alpha: Inner, a: &A, b: &B, beta: Inner)
+    where
+        A: AsGemmOperand<LH, K, Inner>,
+        B: AsGemmOperand<K, RW, Inner>,
+    {
+        let (trans_a, a, lda) = a.gemm_operand();
+        let (trans_b, b, ldb) = b.gemm_operand();
+
+        Inner::gemm(
+            trans_a,
+            trans_b,
+            LH,
+            RW,
+            K,
+            alpha,
+            a,
+            lda,
+            b,
+            ldb,
+            beta,
+            self.as_mut_slice(),
+            LH.max(1),
+        );
+    }
+}
+
+// a.mul_add(&b, c) == a * b + c, accumulated into the buffer of c without temporaries
+impl<'a, 'b, const LH: usize, const K: usize, const RW: usize, Inner>
+    MulAdd<&'b GeneralMatrix<K, RW, Inner>, GeneralMatrix<LH, RW, Inner>>
+    for &'a GeneralMatrix<LH, K, Inner>
+where
+    Inner: GemmInner + Clone + One,
+{
+    type Output = GeneralMatrix<LH, RW, Inner>;
+
+    fn mul_add(
+        self,
+        b: &'b GeneralMatrix<K, RW, Inner>,
+        c: GeneralMatrix<LH, RW, Inner>,
+    ) -> Self::Output {
+        let mut dest = c;
+        dest.gemm(Inner::one(), self, b, Inner::one());
+        dest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn a() -> GeneralMatrix<2, 3, f64> {
+        GeneralMatrix::new_row_major([[1., 2., 3.], [4., 5., 6.]])
+    }
+
+    fn b() -> GeneralMatrix<3, 2, f64> {
+        GeneralMatrix::new_row_major([[1., 2.], [3., 4.], [5., 6.]])
+    }
+
+    #[test]
+    fn gemm_alpha_beta() {
+        let mut c: GeneralMatrix<2, 2, f64> = GeneralMatrix::by(1.);
+        c.gemm(2., &a(), &b(), 3.);
+
+        // A * B = [[22, 28], [49, 64]]
+        let ans = GeneralMatrix::new_row_major([[47., 59.], [101., 131.]]);
+        assert_eq!(c, ans);
+    }
+
+    #[test]
+    fn gemm_transposed_operands() {
+        // Aᵀ and Bᵀ of the matrices above
+        let at: GeneralMatrix<3, 2, f32> = GeneralMatrix::new_row_major([[1., 4.], [2., 5.], [3., 6.]]);
+        let bt: GeneralMatrix<2, 3, f32> = GeneralMatrix::new_row_major([[1., 3., 5.], [2., 4., 6.]]);
+
+        let mut c: GeneralMatrix<2, 2, f32> = GeneralMatrix::zero();
+        c.gemm(1., &at.transpose(), &bt.transpose(), 0.);
+
+        assert_eq!(c, GeneralMatrix::new_row_major([[22., 28.], [49., 64.]]));
+    }
+
+    #[test]
+    fn gemm_generic_inner() {
+        let a = GeneralMatrix::new_row_major([[1, 2], [3, 4]]);
+        let b = GeneralMatrix::new_row_major([[5, 6], [7, 8]]);
+        let mut c = GeneralMatrix::by(1);
+
+        c.gemm(1, &a.transpose(), &b, 2);
+        assert_eq!(c, GeneralMatrix::new_row_major([[28, 32], [40, 46]]));
+    }
+
+    #[test]
+    fn mul_add() {
+        let c: GeneralMatrix<2, 2, f64> = GeneralMatrix::by(1.);
+        let ans = GeneralMatrix::new_row_major([[23., 29.], [50., 65.]]);
+
+        assert_eq!((&a()).mul_add(&b(), c), ans);
+
+        let ints = GeneralMatrix::new_row_major([[1, 2], [3, 4]]);
+        assert_eq!(
+            (&ints).mul_add(&ints, GeneralMatrix::by(1)),
+            GeneralMatrix::new_row_major([[8, 11], [16, 23]])
+        );
+    }
+}
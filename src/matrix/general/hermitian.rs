@@ -0,0 +1,151 @@
+use super::gemm::GemmInner;
+use super::{AsMatrix, GeneralMatrix};
+use crate::matrix::Complex;
+
+use num_traits::{Num, One, Zero};
+
+use std::ops::Neg;
+
+// complex conjugate of an element. the identity for anything but Complex.
+pub trait Conjugate: Clone {
+    fn conjugate(&self) -> Self;
+}
+
+impl<Inner> Conjugate for Inner
+where
+    Inner: Clone,
+{
+    default fn conjugate(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<T> Conjugate for Complex<T>
+where
+    T: Clone + Num + Neg<Output = T>,
+{
+    fn conjugate(&self) -> Self {
+        self.conj()
+    }
+}
+
+impl<const H: usize, const W: usize, Inner> GeneralMatrix<H, W, Inner>
+where
+    Inner: Conjugate,
+{
+    // element-wise conjugate
+    #[allow(unused)]
+    pub fn conj(&self) -> Self {
+        GeneralMatrix::by_f(|col, row| self.at(row, col).conjugate())
+    }
+
+    // owned Aᴴ. use conj_transpose() for a zero-copy operand.
+    #[allow(unused)]
+    pub fn conj_transposed(&self) -> GeneralMatrix<W, H, Inner> {
+        GeneralMatrix::by_f(|col, row| self.at(col, row).conjugate())
+    }
+
+    // Aᴴ * A. Hermitian (symmetric for real Inner) and positive semidefinite.
+    #[allow(unused)]
+    pub fn gram(&self) -> GeneralMatrix<W, W, Inner>
+    where
+        Inner: GemmInner + Zero + One,
+    {
+        let mut ret = GeneralMatrix::zero();
+        ret.gemm(Inner::one(), &self.conj_transpose(), self, Inner::zero());
+        ret
+    }
+}
+
+impl<const S: usize, Inner> GeneralMatrix<S, S, Inner>
+where
+    Inner: Conjugate + PartialEq,
+{
+    // A == Aᴴ. for real Inner, whether A is symmetric.
+    #[allow(unused)]
+    pub fn is_hermitian(&self) -> bool {
+        (0..S).all(|col| (0..=col).all(|row| *self.at(row, col) == self.at(col, row).conjugate()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn conjugate() {
+        let m = GeneralMatrix::new_row_major([[c(1., 2.), c(3., -4.)]]);
+
+        assert_eq!(m.conj(), GeneralMatrix::new_row_major([[c(1., -2.), c(3., 4.)]]));
+        assert_eq!(
+            m.conj_transposed(),
+            GeneralMatrix::new_row_major([[c(1., -2.)], [c(3., 4.)]])
+        );
+        assert_eq!(m.conj_transpose().to_owned(), m.conj_transposed());
+        assert_eq!(m.transpose().to_owned(), m.transposed());
+
+        // the identity for real elements
+        let r = GeneralMatrix::new_row_major([[1, 2], [3, 4]]);
+        assert_eq!(r.conj_transposed(), r.transposed());
+    }
+
+    #[test]
+    fn hermitian() {
+        let h = GeneralMatrix::new_row_major([[c(2., 0.), c(1., -1.)], [c(1., 1.), c(3., 0.)]]);
+        assert!(h.is_hermitian());
+
+        // symmetric but not Hermitian
+        let s = GeneralMatrix::new_row_major([[c(2., 0.), c(1., 1.)], [c(1., 1.), c(3., 0.)]]);
+        assert!(!s.is_hermitian());
+
+        // a diagonal element must be real
+        let d = GeneralMatrix::new_row_major([[c(0., 1.)]]);
+        assert!(!d.is_hermitian());
+
+        assert!(GeneralMatrix::new_row_major([[1., 2.], [2., 1.]]).is_hermitian());
+    }
+
+    #[test]
+    fn gram() {
+        let a: GeneralMatrix<3, 2, Complex<f64>> =
+            GeneralMatrix::new_row_major([[c(1., 1.), c(0., 0.)], [c(0., 0.), c(0., 2.)], [c(1., 0.), c(1., -1.)]]);
+        let g = a.gram();
+
+        assert!(g.is_hermitian());
+        assert_eq!(g, &a.conj_transposed() * &a);
+        assert_eq!(
+            g,
+            GeneralMatrix::new_row_major([[c(3., 0.), c(1., -1.)], [c(1., 1.), c(6., 0.)]])
+        );
+
+        let r: GeneralMatrix<2, 2, i32> = GeneralMatrix::new_row_major([[1, 2], [3, 4]]);
+        assert_eq!(r.gram(), GeneralMatrix::new_row_major([[10, 14], [14, 20]]));
+    }
+
+    #[test]
+    fn conj_transpose_product() {
+        let a = GeneralMatrix::new_row_major([[c(1., 1.), c(2., 0.)], [c(0., -1.), c(1., 0.)]]);
+        let b = GeneralMatrix::new_row_major([[c(0., 1.), c(1., 0.)], [c(1., 0.), c(0., 0.)]]);
+        let ans = &a.conj_transposed() * &b;
+
+        // ?gemm with 'C' through the view product and gemm()
+        assert_eq!(a.conj_transpose() * b.as_view(), ans);
+        let mut out: GeneralMatrix<2, 2, Complex<f64>> = GeneralMatrix::zero();
+        out.gemm(c(1., 0.), &a.conj_transpose(), &b, c(0., 0.));
+        assert_eq!(out, ans);
+
+        // the loop of the generic view product
+        let ints = GeneralMatrix::new_row_major([[Complex::new(1, 1), Complex::new(0, 2)]]);
+        assert_eq!(
+            ints.conj_transpose() * ints.as_view(),
+            GeneralMatrix::new_row_major([
+                [Complex::new(2, 0), Complex::new(2, 2)],
+                [Complex::new(2, -2), Complex::new(4, 0)]
+            ])
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use super::lu::{min, GeneralLuError, GeneralLuFormat, GeneralLuPivot};
+use super::{AsMatrix, GeneralMatrix};
+use crate::backend::{Backend, DefaultBackend};
+use crate::factorizations::lu::{AsLu, AsLuError};
+
+use num_traits::Float;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GeneralInverseError {
+    // element (1-based, as LAPACK reports it) of the diagonal of U is exactly zero
+    Singular(usize),
+    // LAPACK rejected the argument at this (1-based) position.
+    // not returned in practice: both backends pass valid arguments for every S, including 0.
+    // it reports a backend failure instead of a panic.
+    InvalidArgument(usize),
+}
+
+impl core::fmt::Display for GeneralInverseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Singular(i) => write!(f, "The matrix is singular: element {} of the diagonal of U is exactly zero.", i),
+            Self::InvalidArgument(i) => write!(f, "Argument {} had an illegal value.", i),
+        }
+    }
+}
+
+impl GeneralInverseError {
+    fn from_lapack_info(info: i32) -> Option<Self> {
+        match info {
+            0 => None,
+            i if i < 0 => Some(Self::InvalidArgument((-i) as usize)),
+            i => Some(Self::Singular(i as usize)),
+        }
+    }
+}
+
+impl From<GeneralLuError> for GeneralInverseError {
+    fn from(error: GeneralLuError) -> Self {
+        Self::InvalidArgument(error.as_lapack_into().unsigned_abs() as usize)
+    }
+}
+
+impl<const S: usize, Inner> GeneralLuFormat<S, S, Inner, GeneralLuPivot<{ min(S, S) }>>
+where
+    Inner: Float,
+{
+    // first zero element of the diagonal of U (1-based)
+    fn singular_element(&self) -> Option<usize> {
+        (0..S)
+            .find(|&i| self.internal_matrix.at(i, i).is_zero())
+            .map(|i| i + 1)
+    }
+
+    // (-1)^(number of row interchanges)
+    fn pivot_sign(&self) -> Inner {
+        let swaps = self
+            .pivot
+            .pivot
+            .iter()
+            .enumerate()
+            .filter(|(i, p)| **p as usize != i + 1)
+            .count();
+
+        if swaps % 2 == 0 {
+            Inner::one()
+        } else {
+            -Inner::one()
+        }
+    }
+
+    #[allow(unused)]
+    pub fn det(&self) -> Inner {
+        (0..S).fold(self.pivot_sign(), |acc, i| {
+            acc * *self.internal_matrix.at(i, i)
+        })
+    }
+
+    // returns (sign, log|det|). never overflows even if det itself would.
+    #[allow(unused)]
+    pub fn log_abs_det(&self) -> Result<(Inner, Inner), GeneralInverseError> {
+        if let Some(i) = self.singular_element() {
+            return Err(GeneralInverseError::Singular(i));
+        }
+
+        Ok((0..S).fold((self.pivot_sign(), Inner::zero()), |(sign, log), i| {
+            let d = *self.internal_matrix.at(i, i);
+            (sign * d.signum(), log + d.abs().ln())
+        }))
+    }
+}
+
+macro_rules! impl_macro {
+    ($type: ty) => {
+        impl<const S: usize> GeneralLuFormat<S, S, $type, GeneralLuPivot<{ min(S, S) }>> {
+            #[allow(unused)]
+            pub fn inverse(self) -> Result<GeneralMatrix<S, S, $type>, GeneralInverseError> {
+                if let Some(i) = self.singular_element() {
+                    return Err(GeneralInverseError::Singular(i));
+                }
+
+                let Self {
+                    internal_matrix: mut mat,
+                    pivot,
+                } = self;
+
+                let info = <DefaultBackend as Backend<$type>>::getri(
+                    S,
+                    mat.as_mut_slice(),
+                    S,
+                    &pivot.pivot,
+                );
+
+                match GeneralInverseError::from_lapack_info(info) {
+                    Some(error) => Err(error),
+                    None => Ok(mat),
+                }
+            }
+        }
+
+        impl<const S: usize> GeneralMatrix<S, S, $type>
+        where
+            [(); min(S, S)]:,
+        {
+            // NaN if the backend fails, which does not happen for valid arguments (see InvalidArgument)
+            #[allow(unused)]
+            pub fn det(&self) -> $type {
+                self.clone().lu().map_or(<$type>::NAN, |(lu, _)| lu.det())
+            }
+
+            #[allow(unused)]
+            pub fn log_abs_det(&self) -> Result<($type, $type), GeneralInverseError> {
+                let (lu, _) = self.clone().lu()?;
+                lu.log_abs_det()
+            }
+
+            #[allow(unused)]
+            pub fn inverse(&self) -> Result<Self, GeneralInverseError> {
+                let (lu, _) = self.clone().lu()?;
+                lu.inverse()
+            }
+        }
+    };
+}
+
+impl_macro!(f32);
+impl_macro!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_matrix_approx_eq;
+    use num_traits::One;
+
+    #[test]
+    fn determinant() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[2., 9.], [4., 4.]]);
+        assert_eq!(m.det(), -28.);
+
+        let (sign, log) = m.log_abs_det().expect("matrix is regular");
+        assert_matrix_approx_eq!(
+            GeneralMatrix::new_col_major([[sign, log]]),
+            GeneralMatrix::new_col_major([[-1., 28f64.ln()]]),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn log_abs_det_does_not_overflow() {
+        let m: GeneralMatrix<64, 64, f32> = GeneralMatrix::by_f(|col, row| {
+            if col == row {
+                1e3
+            } else {
+                0.
+            }
+        });
+
+        assert!(m.det().is_infinite());
+
+        let (sign, log) = m.log_abs_det().expect("matrix is regular");
+        assert_matrix_approx_eq!(
+            GeneralMatrix::new_col_major([[sign, log]]),
+            GeneralMatrix::new_col_major([[1., 64. * 1e3f32.ln()]]),
+            epsilon = 1e-3
+        );
+    }
+
+    #[test]
+    fn inverse_f64() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[2., 1.], [2., 3.]]);
+        let inv = m.inverse().expect("matrix is regular");
+
+        assert_matrix_approx_eq!(
+            inv,
+            GeneralMatrix::new_row_major([[0.75, -0.25], [-0.5, 0.5]]),
+            epsilon = 1e-12
+        );
+        assert_matrix_approx_eq!(m * inv, GeneralMatrix::one(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn singular_matrix() {
+        let m: GeneralMatrix<2, 2, f32> = GeneralMatrix::new_row_major([[1., 2.], [2., 4.]]);
+
+        assert_eq!(m.det(), 0.);
+        assert_eq!(m.log_abs_det(), Err(GeneralInverseError::Singular(2)));
+        assert_eq!(m.inverse(), Err(GeneralInverseError::Singular(2)));
+    }
+
+    #[test]
+    fn empty_matrix() {
+        // the leading dimension passed to ?getrf and ?getri is at least 1, so 0x0 is valid
+        let m: GeneralMatrix<0, 0, f64> = GeneralMatrix::new_col_major([]);
+
+        assert_eq!(m.det(), 1.);
+        assert_eq!(m.log_abs_det(), Ok((1., 0.)));
+        assert_eq!(m.inverse(), Ok(m));
+    }
+}
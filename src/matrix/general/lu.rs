@@ -1,5 +1,11 @@
-use super::GeneralMatrix;
+use super::{AsMatrix, GeneralMatrix};
+use crate::backend::{Backend, DefaultBackend};
 use crate::factorizations::lu;
+use crate::matrix::Complex;
+
+use num_traits::{One, Zero};
+
+use core::cmp::Ordering;
 
 pub const fn min(a: usize, b: usize) -> usize {
     if a < b {
@@ -58,17 +64,12 @@ where
             + Clone
             + num_traits::Zero
             + num_traits::One
-            + std::ops::SubAssign
             + std::ops::AddAssign
-            + std::ops::Add,
+            + std::ops::Add<Output = Inner>
+            + std::ops::Sub<Output = Inner>,
     {
-        use num_traits::One;
-        println!("Reimplement with sub!");
-        let mut internal = l + u; // - GeneralMatrix::one();
-
-        for i in 0..S {
-            *internal.at_mut(i, i) -= Inner::one();
-        }
+        // the unit diagonal of L is implicit in the packed format
+        let internal = l + u - GeneralMatrix::one();
 
         Self {
             internal_matrix: internal,
@@ -77,6 +78,68 @@ where
     }
 }
 
+impl<const H: usize, const W: usize, Inner> GeneralLuFormat<H, W, Inner, GeneralLuPivot<{ min(H, W) }>>
+where
+    Inner: Clone + Zero + One,
+{
+    // unit lower triangular factor L (H x min(H, W))
+    #[allow(unused)]
+    pub fn l(&self) -> GeneralMatrix<H, { min(H, W) }, Inner> {
+        GeneralMatrix::by_f(|col, row| match row.cmp(&col) {
+            Ordering::Greater => self.internal_matrix.at(row, col).clone(),
+            Ordering::Equal => Inner::one(),
+            Ordering::Less => Inner::zero(),
+        })
+    }
+
+    // upper triangular (trapezoidal) factor U (min(H, W) x W)
+    #[allow(unused)]
+    pub fn u(&self) -> GeneralMatrix<{ min(H, W) }, W, Inner> {
+        GeneralMatrix::by_f(|col, row| {
+            if row <= col {
+                self.internal_matrix.at(row, col).clone()
+            } else {
+                Inner::zero()
+            }
+        })
+    }
+
+    // row i of P * A is row perm[i] of A.
+    // panics unless each pivot[i] is in i+1..=H as after a factorization, e.g. for GeneralLuPivot::default().
+    #[allow(unused)]
+    pub fn permutation_vector(&self) -> [usize; H] {
+        let mut perm = [0; H];
+        for (i, p) in perm.iter_mut().enumerate() {
+            *p = i;
+        }
+        for (i, &swap) in self.pivot.pivot.iter().enumerate() {
+            assert!(
+                swap as usize > i && swap as usize <= H,
+                "pivot[{}] = {} is out of range {}..={}",
+                i,
+                swap,
+                i + 1,
+                H
+            );
+            perm.swap(i, swap as usize - 1);
+        }
+        perm
+    }
+
+    // P such that P * A = L * U
+    #[allow(unused)]
+    pub fn permutation_matrix(&self) -> GeneralMatrix<H, H, Inner> {
+        let perm = self.permutation_vector();
+        GeneralMatrix::by_f(|col, row| {
+            if perm[row] == col {
+                Inner::one()
+            } else {
+                Inner::zero()
+            }
+        })
+    }
+}
+
 #[derive(PartialEq, Eq)]
 pub struct GeneralLuError(i32);
 
@@ -169,30 +232,18 @@ macro_rules! impl_macro {
                 type Lu = GeneralLuFormat<H, W, $type, GeneralLuPivot<{ min(H, W) }>>;
 
                 default fn fact_internal(dest: &mut Self::Lu) -> GeneralLuError {
-                    #[link(name = "lapack")]
-                    extern "C" {
-                        fn [<$lapack _>] (
-                            m: *const i32,   // integer
-                            n: *const i32,   // integer
-                            a: *mut $type,     // array of $type. length = S * S
-                            lda: *const i32, // integer
-                            ipiv: *mut i32,  // array of integer. length = S
-                            info: *mut i32,  // integer);
-                        );
-                    }
-
-                    let m: *const i32 = &(H as i32);
-                    let n: *const i32 = &(W as i32);
-                    let lda: *const i32 = &(H as i32);
-
                     use lu::{AsLuError, LuFormat};
                     let (mat, piv) = dest.data_mut();
-                    let mat = mat.inner_mut() as *mut _ as *mut $type;
-                    let piv = &mut piv.pivot as *mut _ as *mut i32;
 
                     let mut error = GeneralLuError::SUCCESS;
 
-                    unsafe { concat_idents!($lapack, _)(m, n, mat, lda, piv, error.as_lapack_into_mut()) };
+                    *error.as_lapack_into_mut() = <DefaultBackend as Backend<$type>>::getrf(
+                        H,
+                        W,
+                        mat.as_mut_slice(),
+                        H,
+                        &mut piv.pivot,
+                    );
 
                     error
                 }
@@ -203,10 +254,13 @@ macro_rules! impl_macro {
 
 impl_macro!(sgetrf, f32);
 impl_macro!(dgetrf, f64);
+impl_macro!(cgetrf, Complex<f32>);
+impl_macro!(zgetrf, Complex<f64>);
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::assert_matrix_approx_eq;
     use crate::factorizations::lu::{AsLu, AsLuError};
 
     #[test]
@@ -229,7 +283,8 @@ mod test {
 
         assert!(!error.is_error());
 
-        assert_eq!(matrix, ans);
+        assert_matrix_approx_eq!(matrix.internal_matrix, ans.internal_matrix);
+        assert_eq!(matrix.pivot, ans.pivot);
     }
 
     #[test]
@@ -252,6 +307,64 @@ mod test {
 
         assert!(!error.is_error());
 
-        assert_eq!(matrix, ans);
+        assert_matrix_approx_eq!(matrix.internal_matrix, ans.internal_matrix);
+        assert_eq!(matrix.pivot, ans.pivot);
+    }
+
+    #[test]
+    fn extract_l_u_p_square() {
+        let m = GeneralMatrix::new_col_major([[2_f64, 4.], [9., 4.]]);
+        let (lu, _) = m.clone().lu().expect("factorization failed");
+
+        assert_eq!(lu.l(), GeneralMatrix::new_row_major([[1., 0.], [0.5, 1.]]));
+        assert_eq!(lu.u(), GeneralMatrix::new_row_major([[4., 4.], [0., 7.]]));
+        assert_eq!(lu.permutation_vector(), [1, 0]);
+        assert_eq!(
+            lu.permutation_matrix(),
+            GeneralMatrix::new_row_major([[0., 1.], [1., 0.]])
+        );
+
+        assert_eq!(lu.permutation_matrix() * m, lu.l() * lu.u());
+    }
+
+    #[test]
+    fn extract_l_u_p_not_regular() {
+        let m: GeneralMatrix<3, 2, f64> =
+            GeneralMatrix::new_row_major([[1., 2.5], [4., 4.], [2., 6.]]);
+        let (lu, _) = m.clone().lu().expect("factorization failed");
+
+        assert_eq!(lu.pivot, GeneralLuPivot { pivot: [2, 3] });
+        assert_eq!(
+            lu.l(),
+            GeneralMatrix::new_row_major([[1., 0.], [0.5, 1.], [0.25, 0.375]])
+        );
+        assert_eq!(lu.u(), GeneralMatrix::new_row_major([[4., 4.], [0., 4.]]));
+        assert_eq!(lu.permutation_vector(), [1, 2, 0]);
+
+        assert_eq!(lu.permutation_matrix() * m, lu.l() * lu.u());
+    }
+
+    #[test]
+    #[should_panic(expected = "pivot[0] = 0 is out of range 1..=2")]
+    fn permutation_vector_default_pivot() {
+        let lu: GeneralLuFormat<2, 2, f64, GeneralLuPivot<2>> =
+            GeneralLuFormat::new(GeneralMatrix::zero(), GeneralLuPivot::default());
+        lu.permutation_vector();
+    }
+
+    #[test]
+    fn factorize_lu_complex() {
+        let i = Complex::new(0., 1.);
+        let one = Complex::new(1., 0.);
+        // |2i| > |1| selects the second row as the pivot
+        let m: GeneralMatrix<2, 2, Complex<f64>> = GeneralMatrix::new_row_major([[one, one], [2. * i, one]]);
+
+        let (lu, error) = m.clone().lu().expect("factorization failed");
+        assert!(!error.is_error());
+
+        assert_eq!(lu.permutation_vector(), [1, 0]);
+        assert_eq!(lu.l(), GeneralMatrix::new_row_major([[one, Complex::zero()], [-0.5 * i, one]]));
+        assert_eq!(lu.u(), GeneralMatrix::new_row_major([[2. * i, one], [Complex::zero(), one + 0.5 * i]]));
+        assert_eq!(lu.permutation_matrix() * m, lu.l() * lu.u());
     }
 }
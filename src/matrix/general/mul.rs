@@ -1,6 +1,8 @@
 use super::{AsMatrix, GeneralMatrix};
+use crate::backend::{Backend, DefaultBackend, Transpose};
+use crate::matrix::Complex;
 
-use num_traits::Zero;
+use num_traits::{One, Zero};
 
 use std::ops::{AddAssign, Mul};
 
@@ -28,6 +30,55 @@ where
     }
 }
 
+// borrowed operands. owned and mixed forms go through &A * &B.
+impl<'a, 'b, const LH: usize, const LWRH: usize, const RW: usize, Inner>
+    Mul<&'b GeneralMatrix<LWRH, RW, Inner>> for &'a GeneralMatrix<LH, LWRH, Inner>
+where
+    Inner: Zero + Clone + AddAssign + Mul<Output = Inner>,
+{
+    type Output = GeneralMatrix<LH, RW, Inner>;
+
+    default fn mul(self, rhs: &'b GeneralMatrix<LWRH, RW, Inner>) -> Self::Output {
+        let mut ret = GeneralMatrix::zero();
+
+        for h in 0..LH {
+            for w in 0..RW {
+                for index in 0..LWRH {
+                    *ret.at_mut(h, w) += self.at(h, index).clone() * rhs.at(index, w).clone();
+                }
+            }
+        }
+
+        ret
+    }
+}
+
+impl<'b, const LH: usize, const LWRH: usize, const RW: usize, Inner>
+    Mul<&'b GeneralMatrix<LWRH, RW, Inner>> for GeneralMatrix<LH, LWRH, Inner>
+where
+    Inner: Zero + Clone + AddAssign + Mul<Output = Inner>,
+{
+    type Output = GeneralMatrix<LH, RW, Inner>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &'b GeneralMatrix<LWRH, RW, Inner>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<'a, const LH: usize, const LWRH: usize, const RW: usize, Inner>
+    Mul<GeneralMatrix<LWRH, RW, Inner>> for &'a GeneralMatrix<LH, LWRH, Inner>
+where
+    Inner: Zero + Clone + AddAssign + Mul<Output = Inner>,
+{
+    type Output = GeneralMatrix<LH, RW, Inner>;
+
+    #[inline(always)]
+    fn mul(self, rhs: GeneralMatrix<LWRH, RW, Inner>) -> Self::Output {
+        self * &rhs
+    }
+}
+
 macro_rules! impl_macro {
     ($lapack: ident, $type: ty) => {
         paste::paste! {
@@ -38,66 +89,80 @@ macro_rules! impl_macro {
                 alpha: $type,
                 beta: $type,
             ) {
-                extern "C" { fn [<$lapack _>](
-                        // C = alpha * A * B + beta * C
-                        trans_mode_a: *const i8,
-                        trans_mode_b: *const i8,
-                        height_of_a: *const i32,
-                        width_of_b: *const i32,
-                        widht_of_a_height_of_b: *const i32,
-                        alpha: *const $type,
-                        a: *const $type,
-                        leading_a: *const i32,
-                        b: *const $type,
-                        leading_b: *const i32,
-                        beta: *const $type,
-                        c: *mut $type,
-                        leading_c: *const i32,
+                // C = alpha * A * B + beta * C
+                <DefaultBackend as Backend<$type>>::gemm(
+                    Transpose::No,
+                    Transpose::No,
+                    LH,
+                    RW,
+                    LWRH,
+                    alpha,
+                    a.as_slice(),
+                    LH,
+                    b.as_slice(),
+                    LWRH,
+                    beta,
+                    c.as_mut_slice(),
+                    LH,
+                );
+            }
+
+            // A * B. products with a vector go through ?gemv instead of ?gemm.
+            fn [<general_matrix_product_ $lapack>]<const LH: usize, const LWRH: usize, const RW: usize>(
+                a: &GeneralMatrix<LH, LWRH, $type>,
+                b: &GeneralMatrix<LWRH, RW, $type>,
+            ) -> GeneralMatrix<LH, RW, $type> {
+                let mut ret = GeneralMatrix::zero();
+
+                if RW == 1 {
+                    // y = A * x
+                    <DefaultBackend as Backend<$type>>::gemv(
+                        Transpose::No,
+                        LH,
+                        LWRH,
+                        <$type>::one(),
+                        a.as_slice(),
+                        LH.max(1),
+                        b.as_slice(),
+                        1,
+                        <$type>::zero(),
+                        ret.as_mut_slice(),
+                        1,
                     );
-                }
-                let (ah, aw) = (LH, LWRH);
-                let (bh, bw) = (LWRH, RW);
-                let (ch, _cw) = (LH, RW);
-
-                let trans_mode_a: *const i8 = &('N' as i8);
-                let trans_mode_b: *const i8 = &('N' as i8);
-                let height_of_a: *const i32 = &(ah as i32);
-                let width_of_b: *const i32 = &(bw as i32);
-                let widht_of_a_height_of_b: *const i32 = &(aw as i32); // aw == bh
-                let alpha: *const $type = &alpha;
-                let a: *const $type = a.inner() as *const _ as *const $type;
-                let leading_a: *const i32 = &(ah as i32);
-                let b: *const $type = b.as_ptr() as *const _ as *const $type;
-                let leading_b: *const i32 = &(bh as i32);
-                let beta: *const $type = &beta;
-                let c: *mut $type = c.inner_mut() as *mut _ as *mut $type;
-                let leading_c: *const i32 = &(ch as i32);
-
-                unsafe {
-                    [<$lapack _>](
-                        trans_mode_a,
-                        trans_mode_b,
-                        height_of_a,
-                        width_of_b,
-                        widht_of_a_height_of_b,
-                        alpha,
-                        a,
-                        leading_a,
-                        b,
-                        leading_b,
-                        beta,
-                        c,
-                        leading_c,
+                } else if LH == 1 {
+                    // yᵀ = xᵀ * B, i.e. y = Bᵀ * x
+                    <DefaultBackend as Backend<$type>>::gemv(
+                        Transpose::Trans,
+                        LWRH,
+                        RW,
+                        <$type>::one(),
+                        b.as_slice(),
+                        LWRH.max(1),
+                        a.as_slice(),
+                        1,
+                        <$type>::zero(),
+                        ret.as_mut_slice(),
+                        1,
                     );
+                } else {
+                    [<general_matrix_mul_ $lapack>](a, b, &mut ret, <$type>::one(), <$type>::zero());
                 }
+
+                ret
             }
 impl<const LH: usize, const LWRH: usize, const RW: usize> Mul<GeneralMatrix<LWRH, RW, $type>>
     for GeneralMatrix<LH, LWRH, $type>
 {
     fn mul(self, lhs: GeneralMatrix<LWRH, RW, $type>) -> Self::Output {
-        let mut ret = GeneralMatrix::zero();
-        concat_idents!(general_matrix_mul_, $lapack)(&self, &lhs, &mut ret, 1.0, 0.0);
-        ret
+        [<general_matrix_product_ $lapack>](&self, &lhs)
+    }
+}
+
+impl<'a, 'b, const LH: usize, const LWRH: usize, const RW: usize>
+    Mul<&'b GeneralMatrix<LWRH, RW, $type>> for &'a GeneralMatrix<LH, LWRH, $type>
+{
+    fn mul(self, rhs: &'b GeneralMatrix<LWRH, RW, $type>) -> Self::Output {
+        [<general_matrix_product_ $lapack>](self, rhs)
     }
 }
 
@@ -107,6 +172,8 @@ impl<const LH: usize, const LWRH: usize, const RW: usize> Mul<GeneralMatrix<LWRH
 
 impl_macro!(sgemm, f32);
 impl_macro!(dgemm, f64);
+impl_macro!(cgemm, Complex<f32>);
+impl_macro!(zgemm, Complex<f64>);
 
 #[cfg(test)]
 mod tests {
@@ -122,6 +189,31 @@ mod tests {
         assert_eq!(m1 * m2, expect);
     }
 
+    #[test]
+    fn multiplication_borrowed() {
+        let m1 = GeneralMatrix::new_col_major([[1, 4], [2, 5], [3, 6]]);
+        let m2 = GeneralMatrix::new_col_major([[1, 3, 5], [2, 4, 6]]);
+
+        let expect = GeneralMatrix::new_col_major([[22, 49], [28, 64]]);
+
+        assert_eq!(&m1 * &m2, expect);
+        assert_eq!(m1.clone() * &m2, expect);
+        assert_eq!(&m1 * m2, expect);
+    }
+
+    #[test]
+    fn multiplication_borrowed_lapack_f64() {
+        let m1: GeneralMatrix<2, 3, f64> =
+            GeneralMatrix::new_col_major([[1., 4.], [2., 5.], [3., 6.]]);
+        let m2 = GeneralMatrix::new_col_major([[1., 3., 5.], [2., 4., 6.]]);
+
+        let ans = GeneralMatrix::new_col_major([[22., 49.], [28., 64.]]);
+
+        assert_eq!(&m1 * &m2, ans);
+        assert_eq!(m1.clone() * &m2, ans);
+        assert_eq!(&m1 * m2, ans);
+    }
+
     #[test]
     fn internal_impl_macro() {
         let m1 = GeneralMatrix::new_col_major([[1., 4.], [2., 5.], [3., 6.]]);
@@ -155,4 +247,18 @@ mod tests {
 
         assert_eq!(m1 * m2, ans);
     }
+
+    #[test]
+    fn multiplication_lapack_complex() {
+        let i = Complex::new(0., 1.);
+        let m1: GeneralMatrix<2, 2, Complex<f64>> = GeneralMatrix::new_row_major([[i, Complex::one()], [Complex::one(), -i]]);
+        let m2 = GeneralMatrix::new_row_major([[i, Complex::zero()], [Complex::zero(), i]]);
+
+        let ans = GeneralMatrix::new_row_major([[-Complex::one(), i], [i, Complex::one()]]);
+        assert_eq!(&m1 * &m2, ans);
+
+        // matrix * vector through ?gemv
+        let x = GeneralMatrix::new_col_major([[Complex::one(), i]]);
+        assert_eq!(m1 * x, GeneralMatrix::new_col_major([[2. * i, Complex::new(2., 0.)]]));
+    }
 }
@@ -0,0 +1,376 @@
+use super::lu::min;
+use super::solve::GeneralSolveError;
+use super::{AsMatrix, GeneralMatrix};
+use crate::backend::{DefaultBackend, RealBackend, Transpose};
+use crate::factorizations::qr;
+use crate::solve;
+
+use num_traits::Zero;
+
+pub const fn max(a: usize, b: usize) -> usize {
+    if a < b {
+        b
+    } else {
+        a
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GeneralQrTau<const S: usize, Inner> {
+    pub tau: [Inner; S],
+}
+
+impl<const S: usize, Inner> Default for GeneralQrTau<S, Inner>
+where
+    Inner: Zero + Copy,
+{
+    fn default() -> Self {
+        Self {
+            tau: [Inner::zero(); S],
+        }
+    }
+}
+
+// compact Householder form as returned by ?geqrf:
+// R is stored on and above the diagonal, the reflectors below it.
+#[derive(Debug, PartialEq)]
+pub struct GeneralQrFormat<const H: usize, const W: usize, Inner, Tau>
+where
+    Inner: Clone,
+{
+    pub internal_matrix: GeneralMatrix<H, W, Inner>,
+    pub tau: Tau,
+}
+
+impl<const H: usize, const W: usize, Inner, Tau> GeneralQrFormat<H, W, Inner, Tau>
+where
+    Inner: Clone,
+{
+    pub fn new(matrix: GeneralMatrix<H, W, Inner>, tau: Tau) -> Self {
+        Self {
+            internal_matrix: matrix,
+            tau,
+        }
+    }
+}
+
+impl<const H: usize, const W: usize, Inner, Tau> GeneralQrFormat<H, W, Inner, Tau>
+where
+    Inner: Clone + Zero,
+    [(); min(H, W)]:,
+{
+    // upper triangular (trapezoidal) factor R (min(H, W) x W).
+    #[allow(unused)]
+    pub fn r(&self) -> GeneralMatrix<{ min(H, W) }, W, Inner> {
+        GeneralMatrix::by_f(|col, row| {
+            if row <= col {
+                self.internal_matrix.at(row, col).clone()
+            } else {
+                Inner::zero()
+            }
+        })
+    }
+}
+
+#[derive(PartialEq, Eq)]
+pub struct GeneralQrError(i32);
+
+impl GeneralQrError {
+    pub const SUCCESS: Self = Self(0);
+}
+
+impl core::fmt::Debug for GeneralQrError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::SUCCESS => write!(f, "GeneralQrError::SUCCESS"),
+            _ => write!(f, "GeneralQrError::INVALID_ARG({})", -self.0),
+        }
+    }
+}
+
+impl core::fmt::Display for GeneralQrError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <Self as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+impl qr::AsQrError for GeneralQrError {
+    fn as_lapack_into_mut(&mut self) -> &mut i32 {
+        &mut self.0
+    }
+    fn as_lapack_into(&self) -> &i32 {
+        &self.0
+    }
+}
+
+macro_rules! impl_macro {
+    ($type: ty) => {
+        impl<const H: usize, const W: usize>
+            qr::QrFormat<GeneralMatrix<H, W, $type>, GeneralQrTau<{ min(H, W) }, $type>>
+            for GeneralQrFormat<H, W, $type, GeneralQrTau<{ min(H, W) }, $type>>
+        where
+            Self: Sized,
+        {
+            fn new_with(
+                internal_matrix: GeneralMatrix<H, W, $type>,
+                tau: GeneralQrTau<{ min(H, W) }, $type>,
+            ) -> Self {
+                Self {
+                    internal_matrix,
+                    tau,
+                }
+            }
+            fn data_ref(&self) -> (&GeneralMatrix<H, W, $type>, &GeneralQrTau<{ min(H, W) }, $type>) {
+                (&self.internal_matrix, &self.tau)
+            }
+            fn data_mut(
+                &mut self,
+            ) -> (
+                &mut GeneralMatrix<H, W, $type>,
+                &mut GeneralQrTau<{ min(H, W) }, $type>,
+            ) {
+                (&mut self.internal_matrix, &mut self.tau)
+            }
+        }
+
+        impl<const H: usize, const W: usize>
+            qr::AsQr<H, W, $type, GeneralQrTau<{ min(H, W) }, $type>, GeneralQrError>
+            for GeneralMatrix<H, W, $type>
+        {
+            type Qr = GeneralQrFormat<H, W, $type, GeneralQrTau<{ min(H, W) }, $type>>;
+
+            default fn fact_internal(dest: &mut Self::Qr) -> GeneralQrError {
+                use qr::{AsQrError, QrFormat};
+
+                let (mat, tau) = dest.data_mut();
+
+                let mut error = GeneralQrError::SUCCESS;
+
+                *error.as_lapack_into_mut() =
+                    <DefaultBackend as RealBackend<$type>>::geqrf(H, W, mat.as_mut_slice(), H, &mut tau.tau);
+
+                error
+            }
+        }
+
+        impl<const H: usize, const W: usize>
+            GeneralQrFormat<H, W, $type, GeneralQrTau<{ min(H, W) }, $type>>
+        {
+            // generates Q (H x N) from the first min(H, W) reflectors into `q`.
+            fn generate_q<const N: usize>(
+                &self,
+                q: &mut GeneralMatrix<H, N, $type>,
+            ) -> GeneralQrError {
+                use qr::AsQrError;
+
+                let mut error = GeneralQrError::SUCCESS;
+
+                *error.as_lapack_into_mut() = <DefaultBackend as RealBackend<$type>>::orgqr(
+                    H,
+                    N,
+                    min(H, W),
+                    q.as_mut_slice(),
+                    H,
+                    &self.tau.tau,
+                );
+
+                error
+            }
+
+            // orthonormal columns Q1 such that A = Q1 * R (H x min(H, W)).
+            #[allow(unused)]
+            pub fn thin_q(&self) -> Result<GeneralMatrix<H, { min(H, W) }, $type>, GeneralQrError> {
+                use qr::AsQrError;
+
+                let mut q = GeneralMatrix::by_f(|col, row| *self.internal_matrix.at(row, col));
+                let error = self.generate_q(&mut q);
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(q)
+                }
+            }
+
+            // square orthogonal Q (H x H).
+            #[allow(unused)]
+            pub fn full_q(&self) -> Result<GeneralMatrix<H, H, $type>, GeneralQrError> {
+                use qr::AsQrError;
+
+                let mut q = GeneralMatrix::by_f(|col, row| {
+                    if col < W {
+                        *self.internal_matrix.at(row, col)
+                    } else {
+                        0.
+                    }
+                });
+                let error = self.generate_q(&mut q);
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(q)
+                }
+            }
+
+            // computes Q * C, or Q^T * C when `transpose` is set, without forming Q.
+            #[allow(unused)]
+            pub fn apply_q<const N: usize>(
+                &self,
+                c: GeneralMatrix<H, N, $type>,
+                transpose: bool,
+            ) -> Result<GeneralMatrix<H, N, $type>, GeneralQrError> {
+                use qr::AsQrError;
+
+                let trans = if transpose { Transpose::Trans } else { Transpose::No };
+                let mut dest = c;
+
+                let mut error = GeneralQrError::SUCCESS;
+
+                *error.as_lapack_into_mut() = <DefaultBackend as RealBackend<$type>>::ormqr(
+                    trans,
+                    H,
+                    N,
+                    min(H, W),
+                    self.internal_matrix.as_slice(),
+                    H,
+                    &self.tau.tau,
+                    dest.as_mut_slice(),
+                    H,
+                );
+
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(dest)
+                }
+            }
+        }
+
+        impl<const H: usize, const W: usize, const N: usize>
+            solve::AsLeastSquares<GeneralMatrix<H, N, $type>, GeneralSolveError>
+            for GeneralMatrix<H, W, $type>
+        {
+            // minimum norm solution (W x N)
+            type Output = GeneralMatrix<W, N, $type>;
+            // 2-norm of the residual of each column. zero unless H > W.
+            type Residual = [$type; N];
+
+            fn least_squares(
+                self,
+                rhs: GeneralMatrix<H, N, $type>,
+            ) -> Result<(Self::Output, Self::Residual), GeneralSolveError> {
+                use solve::AsSolveError;
+
+                let ld = max(H, W).max(1);
+                let mut mat = self;
+
+                // B has to hold both the H x N right-hand side and the W x N solution.
+                let mut buffer = vec![0. as $type; ld * N];
+                for col in 0..N {
+                    for row in 0..H {
+                        buffer[col * ld + row] = *rhs.at(row, col);
+                    }
+                }
+
+                let mut error = GeneralSolveError::SUCCESS;
+
+                *error.as_lapack_into_mut() =
+                    <DefaultBackend as RealBackend<$type>>::gels(H, W, N, mat.as_mut_slice(), H, &mut buffer, ld);
+                if error.is_error() {
+                    return Err(error);
+                }
+
+                let solution = GeneralMatrix::by_f(|col, row| buffer[col * ld + row]);
+
+                let mut residual = [0. as $type; N];
+                for (col, r) in residual.iter_mut().enumerate() {
+                    *r = (W..H)
+                        .map(|row| buffer[col * ld + row].powi(2))
+                        .sum::<$type>()
+                        .sqrt();
+                }
+
+                Ok((solution, residual))
+            }
+        }
+    };
+}
+
+impl_macro!(f32);
+impl_macro!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_matrix_approx_eq;
+    use crate::factorizations::qr::AsQr;
+    use crate::solve::AsLeastSquares;
+
+    #[test]
+    fn factorize_qr_thin() {
+        let m: GeneralMatrix<3, 2, f64> =
+            GeneralMatrix::new_row_major([[1., 2.], [3., 4.], [5., 6.]]);
+        let qr = m.clone().qr().expect("factorization failed");
+
+        let q = qr.thin_q().expect("generating Q failed");
+        let r = qr.r();
+
+        assert_eq!(*r.at(1, 0), 0.);
+        assert_matrix_approx_eq!(q * r, m, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn factorize_qr_full() {
+        use num_traits::One;
+
+        let m: GeneralMatrix<3, 2, f64> =
+            GeneralMatrix::new_row_major([[1., 2.], [3., 4.], [5., 6.]]);
+        let qr = m.clone().qr().expect("factorization failed");
+
+        let q = qr.full_q().expect("generating Q failed");
+        let qt = GeneralMatrix::<3, 3, f64>::by_f(|col, row| *q.at(col, row));
+
+        assert_matrix_approx_eq!(qt * q, GeneralMatrix::one(), epsilon = 1e-10);
+
+        let r = qr.r();
+        assert_matrix_approx_eq!(
+            qr.apply_q(m, true).expect("applying Q failed"),
+            GeneralMatrix::by_f(|col, row| if row < 2 { *r.at(row, col) } else { 0. }),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn least_squares_exact_fit() {
+        let a: GeneralMatrix<3, 2, f64> =
+            GeneralMatrix::new_row_major([[1., 0.], [1., 1.], [1., 2.]]);
+        let b = GeneralMatrix::new_col_major([[1., 3., 5.]]);
+
+        let (x, residual) = a.least_squares(b).expect("solve failed");
+
+        assert_matrix_approx_eq!(x, GeneralMatrix::new_col_major([[1., 2.]]), epsilon = 1e-10);
+        assert!(residual[0].abs() < 1e-10);
+    }
+
+    #[test]
+    fn least_squares_overdetermined() {
+        let a: GeneralMatrix<3, 2, f64> =
+            GeneralMatrix::new_row_major([[1., 0.], [1., 1.], [1., 2.]]);
+        let b = GeneralMatrix::new_col_major([[1., 2., 1.]]);
+
+        let (x, residual) = a.least_squares(b).expect("solve failed");
+
+        assert_matrix_approx_eq!(x, GeneralMatrix::new_col_major([[4. / 3., 0.]]), epsilon = 1e-10);
+        assert!((residual[0] - (2f64 / 3.).sqrt()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn least_squares_underdetermined() {
+        let a: GeneralMatrix<1, 2, f64> = GeneralMatrix::new_row_major([[1., 1.]]);
+        let b = GeneralMatrix::new_col_major([[2.]]);
+
+        let (x, residual) = a.least_squares(b).expect("solve failed");
+
+        assert_matrix_approx_eq!(x, GeneralMatrix::new_col_major([[1., 1.]]), epsilon = 1e-10);
+        assert_eq!(residual, [0.]);
+    }
+}
@@ -0,0 +1,166 @@
+use super::GeneralMatrix;
+use crate::backend::{Backend, DefaultBackend};
+use crate::matrix::Complex;
+
+use std::ops::{Div, DivAssign, Mul, MulAssign};
+
+// scalar operands are limited to primitives: a generic `Mul<Inner>` would overlap with
+// the matrix product when Inner is itself a GeneralMatrix.
+macro_rules! impl_primitive {
+    (@by_assign $type: ty) => {
+        impl<const H: usize, const W: usize> Mul<$type> for GeneralMatrix<H, W, $type> {
+            type Output = GeneralMatrix<H, W, $type>;
+
+            fn mul(self, rhs: $type) -> Self::Output {
+                let mut dest = self;
+                dest *= rhs;
+                dest
+            }
+        }
+
+        impl<const H: usize, const W: usize> Mul<GeneralMatrix<H, W, $type>> for $type {
+            type Output = GeneralMatrix<H, W, $type>;
+
+            fn mul(self, rhs: GeneralMatrix<H, W, $type>) -> Self::Output {
+                let mut dest = rhs;
+                dest *= self;
+                dest
+            }
+        }
+
+        impl<const H: usize, const W: usize> Div<$type> for GeneralMatrix<H, W, $type> {
+            type Output = GeneralMatrix<H, W, $type>;
+
+            fn div(self, rhs: $type) -> Self::Output {
+                let mut dest = self;
+                dest /= rhs;
+                dest
+            }
+        }
+    };
+    ($($type: ty),*) => {
+        $(
+            impl<const H: usize, const W: usize> MulAssign<$type> for GeneralMatrix<H, W, $type> {
+                fn mul_assign(&mut self, rhs: $type) {
+                    self.as_mut_slice().iter_mut().for_each(|x| *x *= rhs);
+                }
+            }
+
+            impl<const H: usize, const W: usize> DivAssign<$type> for GeneralMatrix<H, W, $type> {
+                fn div_assign(&mut self, rhs: $type) {
+                    self.as_mut_slice().iter_mut().for_each(|x| *x /= rhs);
+                }
+            }
+
+            impl_primitive!(@by_assign $type);
+        )*
+    };
+}
+
+impl_primitive!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_macro {
+    ($lapack: ident, $type: ty) => {
+        paste::paste! {
+            // dest = alpha * dest
+            #[inline(always)]
+            #[allow(unused)]
+            pub fn [<general_matrix_scal_ $lapack>]<const H: usize, const W: usize>(
+                dest: &mut GeneralMatrix<H, W, $type>,
+                alpha: $type,
+            ) {
+                <DefaultBackend as Backend<$type>>::scal(H * W, alpha, dest.as_mut_slice(), 1);
+            }
+
+            impl<const H: usize, const W: usize> MulAssign<$type> for GeneralMatrix<H, W, $type> {
+                #[inline(always)]
+                fn mul_assign(&mut self, rhs: $type) {
+                    [<general_matrix_scal_ $lapack>](self, rhs);
+                }
+            }
+
+            // BLAS has no division, and scaling by the reciprocal overflows when rhs is subnormal.
+            impl<const H: usize, const W: usize> DivAssign<$type> for GeneralMatrix<H, W, $type> {
+                #[inline(always)]
+                fn div_assign(&mut self, rhs: $type) {
+                    self.as_mut_slice().iter_mut().for_each(|x| *x /= rhs);
+                }
+            }
+        }
+
+        impl_primitive!(@by_assign $type);
+    };
+}
+
+impl_macro!(sscal, f32);
+impl_macro!(dscal, f64);
+impl_macro!(cscal, Complex<f32>);
+impl_macro!(zscal, Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::AsMatrix;
+
+    #[test]
+    fn scalar_primitive_rust() {
+        let m: GeneralMatrix<2, 3, i32> = GeneralMatrix::new_row_major([[1, 2, 3], [4, 5, 6]]);
+        let ans = GeneralMatrix::new_row_major([[2, 4, 6], [8, 10, 12]]);
+
+        assert_eq!(m.clone() * 2, ans);
+        assert_eq!(2 * m.clone(), ans);
+        assert_eq!(ans.clone() / 2, m);
+
+        let mut n = m;
+        n *= 2;
+        assert_eq!(n, ans);
+        n /= 2;
+        assert_eq!(n * 1, GeneralMatrix::new_row_major([[1, 2, 3], [4, 5, 6]]));
+    }
+
+    #[test]
+    fn scalar_lapack_f64() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[1., 2.], [3., 4.]]);
+        let ans = GeneralMatrix::new_row_major([[0.5, 1.], [1.5, 2.]]);
+
+        assert_eq!(m.clone() * 0.5, ans);
+        assert_eq!(0.5 * m.clone(), ans);
+        assert_eq!(m.clone() / 2., ans);
+
+        let mut n = m;
+        n *= 0.5;
+        assert_eq!(n, ans);
+        n /= 0.5;
+        assert_eq!(n, GeneralMatrix::new_row_major([[1., 2.], [3., 4.]]));
+    }
+
+    #[test]
+    fn scalar_lapack_f32() {
+        let m: GeneralMatrix<1, 3, f32> = GeneralMatrix::new_row_major([[1., -2., 4.]]);
+
+        assert_eq!(-2. * m, GeneralMatrix::new_row_major([[-2., 4., -8.]]));
+    }
+
+    #[test]
+    fn scalar_lapack_complex() {
+        let i = Complex::new(0., 1.);
+        let m: GeneralMatrix<1, 2, Complex<f64>> = GeneralMatrix::new_row_major([[Complex::new(1., 0.), i]]);
+
+        assert_eq!(i * m.clone(), GeneralMatrix::new_row_major([[i, Complex::new(-1., 0.)]]));
+        assert_eq!(m / i, GeneralMatrix::new_row_major([[-i, Complex::new(1., 0.)]]));
+    }
+
+    #[test]
+    fn scalar_divide_subnormal() {
+        // 1 / 1e-310 is inf, the quotient itself is not
+        let m: GeneralMatrix<1, 2, f64> = GeneralMatrix::new_row_major([[1e-300, -1e-300]]);
+        let n = m / 1e-310;
+
+        assert!((n.at(0, 0) / 1e10 - 1.).abs() < 1e-12);
+        assert!((n.at(0, 1) / -1e10 - 1.).abs() < 1e-12);
+
+        let mut m: GeneralMatrix<1, 1, f32> = GeneralMatrix::new_row_major([[1e-30]]);
+        m /= 1e-40;
+        assert!((m.at(0, 0) / 1e10 - 1.).abs() < 1e-3);
+    }
+}
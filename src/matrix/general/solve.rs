@@ -0,0 +1,174 @@
+use super::lu::{min, GeneralLuFormat, GeneralLuPivot};
+use super::GeneralMatrix;
+use crate::backend::{Backend, DefaultBackend, Transpose};
+use crate::matrix::Complex;
+use crate::solve;
+
+#[derive(PartialEq, Eq)]
+pub struct GeneralSolveError(i32);
+
+impl GeneralSolveError {
+    pub const SUCCESS: Self = Self(0);
+}
+
+impl core::fmt::Debug for GeneralSolveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::SUCCESS => write!(f, "GeneralSolveError::SUCCESS"),
+            Self(info) if info < 0 => write!(f, "GeneralSolveError::INVALID_ARG({})", -info),
+            _ => write!(f, "Element {} of the diagonal of the triangular factor is exactly zero, so the matrix does not have full rank and the solution could not be computed.", self.0),
+        }
+    }
+}
+
+impl core::fmt::Display for GeneralSolveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <Self as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+impl solve::AsSolveError for GeneralSolveError {
+    fn as_lapack_into_mut(&mut self) -> &mut i32 {
+        &mut self.0
+    }
+    fn as_lapack_into(&self) -> &i32 {
+        &self.0
+    }
+}
+
+macro_rules! impl_macro {
+    ($type: ty) => {
+        impl<const S: usize, const N: usize>
+            solve::LuSolve<GeneralMatrix<S, N, $type>, GeneralSolveError>
+            for GeneralLuFormat<S, S, $type, GeneralLuPivot<{ min(S, S) }>>
+        {
+            type Output = GeneralMatrix<S, N, $type>;
+
+            fn solve(
+                &self,
+                rhs: GeneralMatrix<S, N, $type>,
+            ) -> Result<Self::Output, GeneralSolveError> {
+                use solve::AsSolveError;
+
+                let mut dest = rhs;
+                let mut error = GeneralSolveError::SUCCESS;
+
+                *error.as_lapack_into_mut() = <DefaultBackend as Backend<$type>>::getrs(
+                    Transpose::No,
+                    S,
+                    N,
+                    self.internal_matrix.as_slice(),
+                    S,
+                    &self.pivot.pivot,
+                    dest.as_mut_slice(),
+                    S,
+                );
+
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(dest)
+                }
+            }
+        }
+
+        // ?gesv is ?getrf followed by ?getrs
+        impl<const S: usize, const N: usize>
+            solve::AsSolve<GeneralMatrix<S, N, $type>, GeneralSolveError>
+            for GeneralMatrix<S, S, $type>
+        {
+            type Output = GeneralMatrix<S, N, $type>;
+
+            fn solve(
+                self,
+                rhs: GeneralMatrix<S, N, $type>,
+            ) -> Result<Self::Output, GeneralSolveError> {
+                use solve::AsSolveError;
+
+                let mut mat = self;
+                let mut pivot = GeneralLuPivot::<S>::default();
+                let mut error = GeneralSolveError::SUCCESS;
+
+                *error.as_lapack_into_mut() =
+                    <DefaultBackend as Backend<$type>>::getrf(S, S, mat.as_mut_slice(), S, &mut pivot.pivot);
+                if error.is_error() {
+                    return Err(error);
+                }
+
+                let mut dest = rhs;
+                *error.as_lapack_into_mut() = <DefaultBackend as Backend<$type>>::getrs(
+                    Transpose::No,
+                    S,
+                    N,
+                    mat.as_slice(),
+                    S,
+                    &pivot.pivot,
+                    dest.as_mut_slice(),
+                    S,
+                );
+
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(dest)
+                }
+            }
+        }
+    };
+}
+
+impl_macro!(f32);
+impl_macro!(f64);
+impl_macro!(Complex<f32>);
+impl_macro!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factorizations::lu::AsLu;
+    use crate::solve::{AsSolve, AsSolveError, LuSolve};
+
+    #[test]
+    fn solve_with_lu_f32() {
+        let m: GeneralMatrix<2, 2, f32> = GeneralMatrix::new_row_major([[2., 9.], [4., 4.]]);
+        let b = GeneralMatrix::new_col_major([[11., 8.], [22., 16.]]);
+
+        let (lu, _) = m.lu().expect("factorization failed");
+        let x = lu.solve(b).expect("solve failed");
+
+        let ans = GeneralMatrix::new_col_major([[1., 1.], [2., 2.]]);
+        assert_eq!(x, ans);
+    }
+
+    #[test]
+    fn solve_with_lu_f64() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[2., 9.], [4., 4.]]);
+        let b = GeneralMatrix::new_col_major([[11., 8.]]);
+
+        let (lu, _) = m.lu().expect("factorization failed");
+        let x = lu.solve(b).expect("solve failed");
+
+        let ans = GeneralMatrix::new_col_major([[1., 1.]]);
+        assert_eq!(x, ans);
+    }
+
+    #[test]
+    fn solve_one_shot_f64() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[2., 9.], [4., 4.]]);
+        let b = GeneralMatrix::new_col_major([[11., 8.]]);
+
+        let x = m.solve(b).expect("solve failed");
+
+        let ans = GeneralMatrix::new_col_major([[1., 1.]]);
+        assert_eq!(x, ans);
+    }
+
+    #[test]
+    fn solve_one_shot_singular() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[1., 2.], [2., 4.]]);
+        let b = GeneralMatrix::new_col_major([[1., 1.]]);
+
+        let error = m.solve(b).expect_err("singular matrix must not be solved");
+        assert!(error.is_singular());
+    }
+}
@@ -0,0 +1,236 @@
+use num_traits::{One, Zero};
+
+use super::{add, AsMatrix, GeneralMatrix};
+use crate::backend::{Backend, DefaultBackend};
+use crate::matrix::Complex;
+
+use std::ops::{Neg, Sub, SubAssign};
+
+impl<const H: usize, const W: usize, InnerLeft, InnerRight, InnerOut>
+    Sub<GeneralMatrix<H, W, InnerRight>> for GeneralMatrix<H, W, InnerLeft>
+where
+    InnerOut: Clone,
+    InnerRight: Zero + Clone,
+    InnerLeft: Zero + Sub<InnerRight, Output = InnerOut> + Clone,
+{
+    type Output = GeneralMatrix<H, W, InnerOut>;
+
+    default fn sub(self, rhs: GeneralMatrix<H, W, InnerRight>) -> Self::Output {
+        use array_macro::array;
+
+        Self::Output::new_col_major(
+            array![col => array![row => self.at(row, col).clone() - rhs.at(row, col).clone(); H]; W],
+        )
+    }
+}
+
+impl<const H: usize, const W: usize, Inner> SubAssign<GeneralMatrix<H, W, Inner>>
+    for GeneralMatrix<H, W, Inner>
+where
+    Inner: SubAssign + Clone,
+{
+    default fn sub_assign(&mut self, rhs: GeneralMatrix<H, W, Inner>) {
+        for (dest, value) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+            *dest -= value.clone();
+        }
+    }
+}
+
+impl<const H: usize, const W: usize, Inner> SubAssign<&GeneralMatrix<H, W, Inner>>
+    for GeneralMatrix<H, W, Inner>
+where
+    Inner: SubAssign + Clone,
+{
+    default fn sub_assign(&mut self, rhs: &GeneralMatrix<H, W, Inner>) {
+        for (dest, value) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+            *dest -= value.clone();
+        }
+    }
+}
+
+// borrowed operands. the owned side, if any, is reused as the destination.
+impl<'a, const H: usize, const W: usize, Inner> Sub<&'a GeneralMatrix<H, W, Inner>>
+    for GeneralMatrix<H, W, Inner>
+where
+    Inner: SubAssign + Clone,
+{
+    type Output = GeneralMatrix<H, W, Inner>;
+
+    #[inline(always)]
+    fn sub(self, rhs: &'a GeneralMatrix<H, W, Inner>) -> Self::Output {
+        let mut dest = self;
+        dest -= rhs;
+        dest
+    }
+}
+
+impl<'a, const H: usize, const W: usize, Inner> Sub<GeneralMatrix<H, W, Inner>>
+    for &'a GeneralMatrix<H, W, Inner>
+where
+    Inner: SubAssign + Clone,
+{
+    type Output = GeneralMatrix<H, W, Inner>;
+
+    default fn sub(self, rhs: GeneralMatrix<H, W, Inner>) -> Self::Output {
+        GeneralMatrix::by_f(|col, row| {
+            let mut value = self.at(row, col).clone();
+            value -= rhs.at(row, col).clone();
+            value
+        })
+    }
+}
+
+impl<'a, 'b, const H: usize, const W: usize, Inner> Sub<&'b GeneralMatrix<H, W, Inner>>
+    for &'a GeneralMatrix<H, W, Inner>
+where
+    Inner: SubAssign + Clone,
+{
+    type Output = GeneralMatrix<H, W, Inner>;
+
+    #[inline(always)]
+    fn sub(self, rhs: &'b GeneralMatrix<H, W, Inner>) -> Self::Output {
+        self.clone() - rhs
+    }
+}
+
+impl<const H: usize, const W: usize, Inner> Neg for GeneralMatrix<H, W, Inner>
+where
+    Inner: Neg<Output = Inner> + Clone,
+{
+    type Output = GeneralMatrix<H, W, Inner>;
+
+    default fn neg(self) -> Self::Output {
+        GeneralMatrix::by_f(|col, row| -self.at(row, col).clone())
+    }
+}
+
+macro_rules! impl_macro {
+    ($axpy: ident, $type: ty) => {
+        paste::paste! {
+            impl<const H: usize, const W: usize> Sub<GeneralMatrix<H, W, $type>>
+                for GeneralMatrix<H, W, $type>
+            {
+                #[inline(always)]
+                fn sub(self, rhs: GeneralMatrix<H, W, $type>) -> Self::Output {
+                    // dest += -1 * rhs
+                    let mut dest = self;
+                    add::[<general_matrix_add_ $axpy>](&mut dest, -<$type>::one(), &rhs);
+                    dest
+                }
+            }
+
+            impl<const H: usize, const W: usize> SubAssign<GeneralMatrix<H, W, $type>>
+                for GeneralMatrix<H, W, $type>
+            {
+                #[inline(always)]
+                fn sub_assign(&mut self, rhs: GeneralMatrix<H, W, $type>) {
+                    add::[<general_matrix_add_ $axpy>](self, -<$type>::one(), &rhs);
+                }
+            }
+
+            impl<const H: usize, const W: usize> SubAssign<&GeneralMatrix<H, W, $type>>
+                for GeneralMatrix<H, W, $type>
+            {
+                #[inline(always)]
+                fn sub_assign(&mut self, rhs: &GeneralMatrix<H, W, $type>) {
+                    add::[<general_matrix_add_ $axpy>](self, -<$type>::one(), rhs);
+                }
+            }
+
+            // the owned rhs takes the result: dest = -rhs + lhs
+            impl<'a, const H: usize, const W: usize> Sub<GeneralMatrix<H, W, $type>>
+                for &'a GeneralMatrix<H, W, $type>
+            {
+                #[inline(always)]
+                fn sub(self, rhs: GeneralMatrix<H, W, $type>) -> Self::Output {
+                    let mut dest = -rhs;
+                    add::[<general_matrix_add_ $axpy>](&mut dest, <$type>::one(), self);
+                    dest
+                }
+            }
+
+            impl<const H: usize, const W: usize> Neg for GeneralMatrix<H, W, $type> {
+                #[inline(always)]
+                fn neg(self) -> Self::Output {
+                    let mut dest = self;
+                    <DefaultBackend as Backend<$type>>::scal(H * W, -<$type>::one(), dest.as_mut_slice(), 1);
+                    dest
+                }
+            }
+        }
+    };
+}
+
+impl_macro!(saxpy, f32);
+impl_macro!(daxpy, f64);
+impl_macro!(caxpy, Complex<f32>);
+impl_macro!(zaxpy, Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subtraction_primitive_rust() {
+        let m1 = GeneralMatrix::new_row_major([[7, 7, 7], [7, 7, 7]]);
+        let m2 = GeneralMatrix::new_row_major([[6, 5, 4], [3, 2, 1]]);
+
+        let ans = GeneralMatrix::new_row_major([[1, 2, 3], [4, 5, 6]]);
+
+        assert_eq!(m1 - m2, ans);
+    }
+
+    #[test]
+    fn subtraction_lapack_f64() {
+        let m1: GeneralMatrix<2, 3, f64> =
+            GeneralMatrix::new_row_major([[7., 7., 7.], [7., 7., 7.]]);
+        let m2 = GeneralMatrix::new_row_major([[6., 5., 4.], [3., 2., 1.]]);
+
+        let ans = GeneralMatrix::new_row_major([[1., 2., 3.], [4., 5., 6.]]);
+
+        assert_eq!(m1.clone() - m2.clone(), ans);
+
+        let mut m3 = m1;
+        m3 -= m2;
+        assert_eq!(m3, ans);
+    }
+
+    #[test]
+    fn subtraction_borrowed() {
+        let m1 = GeneralMatrix::new_row_major([[5, 5], [5, 5]]);
+        let m2 = GeneralMatrix::new_row_major([[4, 3], [2, 1]]);
+        let ans = GeneralMatrix::new_row_major([[1, 2], [3, 4]]);
+
+        assert_eq!(&m1 - &m2, ans);
+        assert_eq!(m1.clone() - &m2, ans);
+        assert_eq!(&m1 - m2.clone(), ans);
+
+        let mut m3 = m1;
+        m3 -= &m2;
+        assert_eq!(m3, ans);
+    }
+
+    #[test]
+    fn subtraction_borrowed_lapack_f32() {
+        let m1: GeneralMatrix<2, 2, f32> = GeneralMatrix::by(5.);
+        let m2 = GeneralMatrix::new_row_major([[4., 3.], [2., 1.]]);
+        let ans = GeneralMatrix::new_row_major([[1., 2.], [3., 4.]]);
+
+        assert_eq!(&m1 - &m2, ans);
+        assert_eq!(m1.clone() - &m2, ans);
+        assert_eq!(&m1 - m2.clone(), ans);
+
+        let mut m3 = m1;
+        m3 -= &m2;
+        assert_eq!(m3, ans);
+    }
+
+    #[test]
+    fn negation() {
+        let m = GeneralMatrix::new_row_major([[1, -2], [3, 0]]);
+        assert_eq!(-m, GeneralMatrix::new_row_major([[-1, 2], [-3, 0]]));
+
+        let m: GeneralMatrix<2, 2, f32> = GeneralMatrix::new_row_major([[1., -2.], [3., 0.]]);
+        assert_eq!(-m, GeneralMatrix::new_row_major([[-1., 2.], [-3., -0.]]));
+    }
+}
@@ -0,0 +1,305 @@
+use super::lu::min;
+use super::qr::max;
+use super::{AsMatrix, GeneralMatrix};
+use crate::backend::{DefaultBackend, RealBackend};
+use crate::factorizations::svd;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GeneralSvdFormat<const K: usize, Inner, U, Vt> {
+    pub u: U,
+    pub singular_values: [Inner; K],
+    pub vt: Vt,
+}
+
+#[derive(PartialEq, Eq)]
+pub struct GeneralSvdError(i32);
+
+impl GeneralSvdError {
+    pub const SUCCESS: Self = Self(0);
+}
+
+impl core::fmt::Debug for GeneralSvdError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::SUCCESS => write!(f, "GeneralSvdError::SUCCESS"),
+            Self(info) if info < 0 => write!(f, "GeneralSvdError::INVALID_ARG({})", -info),
+            _ => write!(f, "The singular value decomposition did not converge ({} superdiagonals did not converge to zero).", self.0),
+        }
+    }
+}
+
+impl core::fmt::Display for GeneralSvdError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <Self as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+impl svd::AsSvdError for GeneralSvdError {
+    fn as_lapack_into_mut(&mut self) -> &mut i32 {
+        &mut self.0
+    }
+    fn as_lapack_into(&self) -> &i32 {
+        &self.0
+    }
+}
+
+macro_rules! impl_macro {
+    ($type: ty) => {
+        impl<const H: usize, const W: usize> GeneralMatrix<H, W, $type> {
+            // job is 'N' (values only), 'S' (thin) or 'A' (full), shared by ?gesdd and ?gesvd.
+            fn svd_internal(
+                &self,
+                job: char,
+                s: &mut [$type],
+                u: &mut [$type],
+                ldu: usize,
+                vt: &mut [$type],
+                ldvt: usize,
+            ) -> GeneralSvdError {
+                use svd::AsSvdError;
+
+                let mut a = self.clone();
+                let mut error = GeneralSvdError::SUCCESS;
+
+                *error.as_lapack_into_mut() =
+                    <DefaultBackend as RealBackend<$type>>::gesdd(job, H, W, a.as_mut_slice(), H, s, u, ldu, vt, ldvt);
+                if !error.is_error() || error.is_invalid() {
+                    return error;
+                }
+
+                // ?gesdd did not converge. fall back to the slower but more robust ?gesvd.
+                let mut a = self.clone();
+                let mut error = GeneralSvdError::SUCCESS;
+
+                *error.as_lapack_into_mut() =
+                    <DefaultBackend as RealBackend<$type>>::gesvd(job, H, W, a.as_mut_slice(), H, s, u, ldu, vt, ldvt);
+
+                error
+            }
+        }
+
+        impl<const H: usize, const W: usize> svd::AsSvd<H, W, $type, GeneralSvdError>
+            for GeneralMatrix<H, W, $type>
+        where
+            [(); min(H, W)]:,
+        {
+            type Values = [$type; min(H, W)];
+            type Thin = GeneralSvdFormat<
+                { min(H, W) },
+                $type,
+                GeneralMatrix<H, { min(H, W) }, $type>,
+                GeneralMatrix<{ min(H, W) }, W, $type>,
+            >;
+            type Full = GeneralSvdFormat<
+                { min(H, W) },
+                $type,
+                GeneralMatrix<H, H, $type>,
+                GeneralMatrix<W, W, $type>,
+            >;
+
+            fn singular_values(&self) -> Result<Self::Values, GeneralSvdError> {
+                use svd::AsSvdError;
+
+                let mut s = [0. as $type; min(H, W)];
+
+                let error = self.svd_internal('N', &mut s, &mut [], 1, &mut [], 1);
+
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(s)
+                }
+            }
+
+            fn svd_thin(&self) -> Result<Self::Thin, GeneralSvdError> {
+                use num_traits::Zero;
+                use svd::AsSvdError;
+
+                let mut ret = GeneralSvdFormat {
+                    u: GeneralMatrix::zero(),
+                    singular_values: [0. as $type; min(H, W)],
+                    vt: GeneralMatrix::zero(),
+                };
+
+                let error = self.svd_internal(
+                    'S',
+                    &mut ret.singular_values,
+                    ret.u.as_mut_slice(),
+                    H,
+                    ret.vt.as_mut_slice(),
+                    min(H, W),
+                );
+
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(ret)
+                }
+            }
+
+            fn svd_full(&self) -> Result<Self::Full, GeneralSvdError> {
+                use num_traits::Zero;
+                use svd::AsSvdError;
+
+                let mut ret = GeneralSvdFormat {
+                    u: GeneralMatrix::zero(),
+                    singular_values: [0. as $type; min(H, W)],
+                    vt: GeneralMatrix::zero(),
+                };
+
+                let error = self.svd_internal(
+                    'A',
+                    &mut ret.singular_values,
+                    ret.u.as_mut_slice(),
+                    H,
+                    ret.vt.as_mut_slice(),
+                    W,
+                );
+
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(ret)
+                }
+            }
+        }
+
+        impl<const H: usize, const W: usize> GeneralMatrix<H, W, $type>
+        where
+            [(); min(H, W)]:,
+        {
+            // singular values below this are treated as zero unless a tolerance is given.
+            fn default_tolerance(singular_values: &[$type]) -> $type {
+                singular_values.first().copied().unwrap_or(0.)
+                    * max(H, W) as $type
+                    * <$type>::EPSILON
+            }
+
+            // numerical rank: the number of singular values greater than the tolerance.
+            #[allow(unused)]
+            pub fn rank(&self, tolerance: Option<$type>) -> Result<usize, GeneralSvdError> {
+                use svd::AsSvd;
+
+                let s = self.singular_values()?;
+                let tolerance = tolerance.unwrap_or_else(|| Self::default_tolerance(&s));
+
+                Ok(s.iter().filter(|&&v| v > tolerance).count())
+            }
+
+            // spectral norm: the largest singular value.
+            #[allow(unused)]
+            pub fn norm2(&self) -> Result<$type, GeneralSvdError> {
+                use svd::AsSvd;
+
+                Ok(self.singular_values()?.first().copied().unwrap_or(0.))
+            }
+
+            // 2-norm condition number. infinite for rank deficient matrices.
+            #[allow(unused)]
+            pub fn cond(&self) -> Result<$type, GeneralSvdError> {
+                use svd::AsSvd;
+
+                let s = self.singular_values()?;
+                match (s.first(), s.last()) {
+                    (Some(&largest), Some(&smallest)) => Ok(largest / smallest),
+                    _ => Ok(0.),
+                }
+            }
+
+            // Moore-Penrose pseudo-inverse (W x H) via V * S^+ * U^T.
+            #[allow(unused)]
+            pub fn pinv(
+                &self,
+                tolerance: Option<$type>,
+            ) -> Result<GeneralMatrix<W, H, $type>, GeneralSvdError> {
+                use svd::AsSvd;
+
+                let GeneralSvdFormat {
+                    u,
+                    singular_values: s,
+                    vt,
+                } = self.svd_thin()?;
+                let tolerance = tolerance.unwrap_or_else(|| Self::default_tolerance(&s));
+
+                let inv: Vec<$type> = s
+                    .iter()
+                    .map(|&v| if v > tolerance { 1. / v } else { 0. })
+                    .collect();
+
+                Ok(GeneralMatrix::by_f(|col, row| {
+                    (0..min(H, W))
+                        .map(|k| vt.at(k, row) * inv[k] * u.at(col, k))
+                        .sum()
+                }))
+            }
+        }
+    };
+}
+
+impl_macro!(f32);
+impl_macro!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_matrix_approx_eq;
+    use crate::factorizations::svd::AsSvd;
+
+    #[test]
+    fn singular_values_sorted() {
+        let m: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[3., 0.], [0., -4.]]);
+        let s = m.singular_values().expect("svd failed");
+
+        assert_matrix_approx_eq!(
+            GeneralMatrix::new_col_major([s]),
+            GeneralMatrix::new_col_major([[4., 3.]]),
+            epsilon = 1e-12
+        );
+        assert!((m.norm2().unwrap() - 4.).abs() < 1e-12);
+        assert!((m.cond().unwrap() - 4. / 3.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn reconstruct_thin() {
+        let m: GeneralMatrix<3, 2, f64> =
+            GeneralMatrix::new_row_major([[1., 2.], [3., 4.], [5., 6.]]);
+        let GeneralSvdFormat {
+            u,
+            singular_values: s,
+            vt,
+        } = m.svd_thin().expect("svd failed");
+
+        let us = GeneralMatrix::<3, 2, f64>::by_f(|col, row| u.at(row, col) * s[col]);
+        assert_matrix_approx_eq!(us * vt, m, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn full_u_is_orthogonal() {
+        use num_traits::One;
+
+        let m: GeneralMatrix<3, 2, f64> =
+            GeneralMatrix::new_row_major([[1., 2.], [3., 4.], [5., 6.]]);
+        let full = m.svd_full().expect("svd failed");
+
+        let ut = GeneralMatrix::<3, 3, f64>::by_f(|col, row| *full.u.at(col, row));
+        assert_matrix_approx_eq!(ut * full.u, GeneralMatrix::one(), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let m: GeneralMatrix<2, 3, f64> =
+            GeneralMatrix::new_row_major([[1., 2., 3.], [2., 4., 6.]]);
+
+        assert_eq!(m.rank(None), Ok(1));
+        assert!(m.cond().unwrap() > 1e12);
+    }
+
+    #[test]
+    fn pseudo_inverse() {
+        let m: GeneralMatrix<3, 2, f64> =
+            GeneralMatrix::new_row_major([[1., 0.], [0., 2.], [0., 0.]]);
+        let ans = GeneralMatrix::new_row_major([[1., 0., 0.], [0., 0.5, 0.]]);
+
+        assert_matrix_approx_eq!(m.pinv(None).expect("svd failed"), ans, epsilon = 1e-10);
+    }
+}
@@ -0,0 +1,405 @@
+use super::hermitian::Conjugate;
+use super::view::{GeneralMatrixView, GeneralMatrixViewMut};
+use super::{AsMatrix, GeneralMatrix};
+use crate::backend::{Backend, DefaultBackend, Transpose};
+use crate::matrix::Complex;
+
+use num_traits::{One, Zero};
+
+use std::ops::{AddAssign, Mul};
+
+// op(A) of a H x W operand as ?gemm takes it: (trans, a, lda).
+// when trans is not Transpose::No, `a` holds the W x H matrix A itself.
+pub trait AsGemmOperand<const H: usize, const W: usize, Inner> {
+    fn gemm_operand(&self) -> (Transpose, &[Inner], usize);
+}
+
+// H x W transpose of a borrowed W x H block. nothing is copied.
+#[derive(Debug)]
+pub struct GeneralMatrixTransposeView<'a, const H: usize, const W: usize, Inner> {
+    base: GeneralMatrixView<'a, W, H, Inner>,
+    trans: Transpose,
+}
+
+impl<'a, const H: usize, const W: usize, Inner> Clone for GeneralMatrixTransposeView<'a, H, W, Inner> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, const H: usize, const W: usize, Inner> Copy for GeneralMatrixTransposeView<'a, H, W, Inner> {}
+
+impl<'a, const H: usize, const W: usize, Inner> GeneralMatrixTransposeView<'a, H, W, Inner> {
+    #[inline]
+    #[allow(unused)]
+    pub const fn trans(&self) -> Transpose {
+        self.trans
+    }
+
+    #[inline]
+    #[allow(unused)]
+    pub const fn size(&self) -> (usize, usize) {
+        (H, W)
+    }
+
+    // the original W x H block. None for conj_transpose(), whose transpose is conjugated.
+    #[inline]
+    #[allow(unused)]
+    pub const fn transpose(&self) -> Option<GeneralMatrixView<'a, W, H, Inner>> {
+        match self.trans {
+            Transpose::ConjTrans => None,
+            _ => Some(self.base),
+        }
+    }
+
+    // element (row, col) of op(A), conjugated for conj_transpose().
+    // there is no `at` returning a reference, as the conjugate is not stored.
+    #[allow(unused)]
+    pub fn op_at(&self, row: usize, col: usize) -> Inner
+    where
+        Inner: Conjugate,
+    {
+        match self.trans {
+            Transpose::ConjTrans => self.base.at(col, row).conjugate(),
+            _ => self.base.at(col, row).clone(),
+        }
+    }
+
+    #[allow(unused)]
+    pub fn to_owned(&self) -> GeneralMatrix<H, W, Inner>
+    where
+        Inner: Conjugate,
+    {
+        GeneralMatrix::by_f(|col, row| self.op_at(row, col))
+    }
+}
+
+impl<'a, const H: usize, const W: usize, Inner> GeneralMatrixView<'a, H, W, Inner> {
+    #[inline]
+    #[allow(unused)]
+    pub const fn transpose(&self) -> GeneralMatrixTransposeView<'a, W, H, Inner> {
+        GeneralMatrixTransposeView {
+            base: *self,
+            trans: Transpose::Trans,
+        }
+    }
+    #[inline]
+    #[allow(unused)]
+    pub const fn conj_transpose(&self) -> GeneralMatrixTransposeView<'a, W, H, Inner> {
+        GeneralMatrixTransposeView {
+            base: *self,
+            trans: Transpose::ConjTrans,
+        }
+    }
+}
+
+impl<const H: usize, const W: usize, Inner> GeneralMatrix<H, W, Inner>
+where
+    Inner: Clone,
+{
+    // zero-copy transpose. use transposed() for an owned copy.
+    #[inline]
+    #[allow(unused)]
+    pub fn transpose(&self) -> GeneralMatrixTransposeView<'_, W, H, Inner> {
+        self.as_view().transpose()
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn conj_transpose(&self) -> GeneralMatrixTransposeView<'_, W, H, Inner> {
+        self.as_view().conj_transpose()
+    }
+
+    #[allow(unused)]
+    pub fn transposed(&self) -> GeneralMatrix<W, H, Inner> {
+        GeneralMatrix::by_f(|col, row| self.at(col, row).clone())
+    }
+}
+
+impl<const H: usize, const W: usize, Inner> AsGemmOperand<H, W, Inner> for GeneralMatrix<H, W, Inner>
+where
+    Inner: Clone,
+{
+    fn gemm_operand(&self) -> (Transpose, &[Inner], usize) {
+        (Transpose::No, self.as_slice(), H.max(1))
+    }
+}
+
+impl<'a, const H: usize, const W: usize, Inner> AsGemmOperand<H, W, Inner>
+    for GeneralMatrixView<'a, H, W, Inner>
+{
+    fn gemm_operand(&self) -> (Transpose, &[Inner], usize) {
+        (Transpose::No, self.as_slice(), self.leading_dimension())
+    }
+}
+
+impl<'a, const H: usize, const W: usize, Inner> AsGemmOperand<H, W, Inner>
+    for GeneralMatrixViewMut<'a, H, W, Inner>
+{
+    fn gemm_operand(&self) -> (Transpose, &[Inner], usize) {
+        (Transpose::No, self.as_slice(), self.leading_dimension())
+    }
+}
+
+impl<'a, const H: usize, const W: usize, Inner> AsGemmOperand<H, W, Inner>
+    for GeneralMatrixTransposeView<'a, H, W, Inner>
+{
+    fn gemm_operand(&self) -> (Transpose, &[Inner], usize) {
+        (self.trans, self.base.as_slice(), self.base.leading_dimension())
+    }
+}
+
+// element (row, col) of op(A), with the conjugation applied
+trait OpAt<Inner> {
+    fn op_at(&self, row: usize, col: usize) -> Inner;
+}
+
+impl<'a, const H: usize, const W: usize, Inner> OpAt<Inner> for GeneralMatrixView<'a, H, W, Inner>
+where
+    Inner: Clone,
+{
+    fn op_at(&self, row: usize, col: usize) -> Inner {
+        self.at(row, col).clone()
+    }
+}
+
+impl<'a, const H: usize, const W: usize, Inner> OpAt<Inner> for GeneralMatrixTransposeView<'a, H, W, Inner>
+where
+    Inner: Conjugate,
+{
+    fn op_at(&self, row: usize, col: usize) -> Inner {
+        GeneralMatrixTransposeView::op_at(self, row, col)
+    }
+}
+
+// Matrix(LH*LWRH) * Matrix(LWRH*RW) for every pair of views
+macro_rules! impl_mul {
+    ($lhs: ident, $rhs: ident) => {
+        impl<'a, 'b, const LH: usize, const LWRH: usize, const RW: usize, Inner>
+            Mul<$rhs<'b, LWRH, RW, Inner>> for $lhs<'a, LH, LWRH, Inner>
+        where
+            Inner: Zero + Conjugate + AddAssign + Mul<Output = Inner>,
+        {
+            type Output = GeneralMatrix<LH, RW, Inner>;
+
+            default fn mul(self, rhs: $rhs<'b, LWRH, RW, Inner>) -> Self::Output {
+                let mut ret = GeneralMatrix::zero();
+
+                for h in 0..LH {
+                    for w in 0..RW {
+                        for index in 0..LWRH {
+                            *ret.at_mut(h, w) += self.op_at(h, index) * rhs.op_at(index, w);
+                        }
+                    }
+                }
+
+                ret
+            }
+        }
+    };
+}
+
+impl_mul!(GeneralMatrixView, GeneralMatrixView);
+impl_mul!(GeneralMatrixView, GeneralMatrixTransposeView);
+impl_mul!(GeneralMatrixTransposeView, GeneralMatrixView);
+impl_mul!(GeneralMatrixTransposeView, GeneralMatrixTransposeView);
+
+// products of a transpose view and a GeneralMatrix go through its view, so ?gemm is used where it applies
+impl<'a, 'b, const LH: usize, const LWRH: usize, const RW: usize, Inner>
+    Mul<&'b GeneralMatrix<LWRH, RW, Inner>> for GeneralMatrixTransposeView<'a, LH, LWRH, Inner>
+where
+    Inner: Zero + Conjugate + AddAssign + Mul<Output = Inner>,
+{
+    type Output = GeneralMatrix<LH, RW, Inner>;
+
+    #[inline(always)]
+    fn mul(self, rhs: &'b GeneralMatrix<LWRH, RW, Inner>) -> Self::Output {
+        self * rhs.as_view()
+    }
+}
+
+impl<'a, const LH: usize, const LWRH: usize, const RW: usize, Inner> Mul<GeneralMatrix<LWRH, RW, Inner>>
+    for GeneralMatrixTransposeView<'a, LH, LWRH, Inner>
+where
+    Inner: Zero + Conjugate + AddAssign + Mul<Output = Inner>,
+{
+    type Output = GeneralMatrix<LH, RW, Inner>;
+
+    #[inline(always)]
+    fn mul(self, rhs: GeneralMatrix<LWRH, RW, Inner>) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl<'a, 'b, const LH: usize, const LWRH: usize, const RW: usize, Inner>
+    Mul<GeneralMatrixTransposeView<'b, LWRH, RW, Inner>> for &'a GeneralMatrix<LH, LWRH, Inner>
+where
+    Inner: Zero + Conjugate + AddAssign + Mul<Output = Inner>,
+{
+    type Output = GeneralMatrix<LH, RW, Inner>;
+
+    #[inline(always)]
+    fn mul(self, rhs: GeneralMatrixTransposeView<'b, LWRH, RW, Inner>) -> Self::Output {
+        self.as_view() * rhs
+    }
+}
+
+impl<'b, const LH: usize, const LWRH: usize, const RW: usize, Inner>
+    Mul<GeneralMatrixTransposeView<'b, LWRH, RW, Inner>> for GeneralMatrix<LH, LWRH, Inner>
+where
+    Inner: Zero + Conjugate + AddAssign + Mul<Output = Inner>,
+{
+    type Output = GeneralMatrix<LH, RW, Inner>;
+
+    #[inline(always)]
+    fn mul(self, rhs: GeneralMatrixTransposeView<'b, LWRH, RW, Inner>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+macro_rules! impl_macro {
+    ($lapack: ident, $type: ty) => {
+        paste::paste! {
+            // C = alpha * op(A) * op(B) + beta * C
+            #[allow(unused)]
+            pub fn [<general_matrix_op_mul_ $lapack>]<const LH: usize, const LWRH: usize, const RW: usize, A, B>(
+                a: &A,
+                b: &B,
+                c: &mut GeneralMatrix<LH, RW, $type>,
+                alpha: $type,
+                beta: $type,
+            ) where
+                A: AsGemmOperand<LH, LWRH, $type>,
+                B: AsGemmOperand<LWRH, RW, $type>,
+            {
+                let (trans_a, a, lda) = a.gemm_operand();
+                let (trans_b, b, ldb) = b.gemm_operand();
+
+                <DefaultBackend as Backend<$type>>::gemm(
+                    trans_a,
+                    trans_b,
+                    LH,
+                    RW,
+                    LWRH,
+                    alpha,
+                    a,
+                    lda,
+                    b,
+                    ldb,
+                    beta,
+                    c.as_mut_slice(),
+                    LH.max(1),
+                );
+            }
+
+            impl_macro!(@mul $lapack, $type, GeneralMatrixView, GeneralMatrixView);
+            impl_macro!(@mul $lapack, $type, GeneralMatrixView, GeneralMatrixTransposeView);
+            impl_macro!(@mul $lapack, $type, GeneralMatrixTransposeView, GeneralMatrixView);
+            impl_macro!(@mul $lapack, $type, GeneralMatrixTransposeView, GeneralMatrixTransposeView);
+        }
+    };
+    (@mul $lapack: ident, $type: ty, $lhs: ident, $rhs: ident) => {
+        paste::paste! {
+            impl<'a, 'b, const LH: usize, const LWRH: usize, const RW: usize>
+                Mul<$rhs<'b, LWRH, RW, $type>> for $lhs<'a, LH, LWRH, $type>
+            {
+                fn mul(self, rhs: $rhs<'b, LWRH, RW, $type>) -> Self::Output {
+                    let mut ret = GeneralMatrix::zero();
+                    [<general_matrix_op_mul_ $lapack>](&self, &rhs, &mut ret, <$type>::one(), <$type>::zero());
+                    ret
+                }
+            }
+        }
+    };
+}
+
+impl_macro!(sgemm, f32);
+impl_macro!(dgemm, f64);
+impl_macro!(cgemm, Complex<f32>);
+impl_macro!(zgemm, Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transpose_view() {
+        let m = GeneralMatrix::new_row_major([[1, 2, 3], [4, 5, 6]]);
+        let t = m.transpose();
+
+        assert_eq!(t.size(), (3, 2));
+        assert_eq!(t.op_at(0, 1), 4);
+        assert_eq!(t.op_at(2, 0), 3);
+        assert_eq!(t.to_owned(), m.transposed());
+        assert_eq!(
+            m.transposed(),
+            GeneralMatrix::new_row_major([[1, 4], [2, 5], [3, 6]])
+        );
+        assert_eq!(t.transpose().expect("not conjugated").to_owned(), m);
+    }
+
+    #[test]
+    fn conj_transpose_view() {
+        let m: GeneralMatrix<1, 2, Complex<f64>> =
+            GeneralMatrix::new_row_major([[Complex::new(1., 2.), Complex::new(3., -4.)]]);
+        let t = m.conj_transpose();
+
+        assert_eq!(t.size(), (2, 1));
+        assert_eq!(t.op_at(0, 0), Complex::new(1., -2.));
+        assert_eq!(t.op_at(1, 0), Complex::new(3., 4.));
+        // the transpose of Aᴴ is conj(A), which is not a view of A
+        assert!(t.transpose().is_none());
+        assert_eq!(m.transpose().op_at(1, 0), Complex::new(3., -4.));
+    }
+
+    #[test]
+    fn transpose_mul_primitive_rust() {
+        let a = GeneralMatrix::new_row_major([[1, 2], [3, 4]]);
+        let b = GeneralMatrix::new_row_major([[5, 6], [7, 8]]);
+
+        let ans = GeneralMatrix::new_row_major([[26, 30], [38, 44]]);
+        assert_eq!(a.transpose() * b.as_view(), ans);
+        assert_eq!(a.transpose() * &b, ans);
+        assert_eq!(a.transpose() * b.clone(), ans);
+
+        // A * Bᵀ with matrix operands
+        let abt = GeneralMatrix::new_row_major([[17, 23], [39, 53]]);
+        assert_eq!(&a * b.transpose(), abt);
+        assert_eq!(a * b.transpose(), abt);
+    }
+
+    #[test]
+    fn transpose_mul_gemm() {
+        let a: GeneralMatrix<3, 2, f64> =
+            GeneralMatrix::new_row_major([[1., 2.], [3., 4.], [5., 6.]]);
+        let b: GeneralMatrix<3, 2, f64> =
+            GeneralMatrix::new_row_major([[1., 0.], [0., 1.], [1., 1.]]);
+
+        // Aᵀ * B
+        let atb = a.transpose() * b.as_view();
+        assert_eq!(atb, a.transposed() * b.clone());
+
+        // A * Bᵀ
+        let abt = a.as_view() * b.transpose();
+        assert_eq!(abt, a.clone() * b.transposed());
+
+        // without as_view()
+        assert_eq!(a.transpose() * &b, atb);
+        assert_eq!(a.transpose() * b.clone(), atb);
+        assert_eq!(&a * b.transpose(), abt);
+        assert_eq!(a.clone() * b.transpose(), abt);
+
+        // Aᵀ * Bᵀᵀ
+        let atbtt = a.conj_transpose() * b.transpose().transpose().expect("not conjugated");
+        assert_eq!(atbtt, atb);
+    }
+
+    #[test]
+    fn transpose_of_block() {
+        let m: GeneralMatrix<3, 3, f32> =
+            GeneralMatrix::new_row_major([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+        let block = m.view::<2, 2>(1, 1);
+
+        let product = block.transpose() * block;
+        let owned = block.to_owned();
+        assert_eq!(product, owned.transposed() * owned);
+    }
+}
@@ -0,0 +1,193 @@
+use super::transpose::AsGemmOperand;
+use super::GeneralMatrix;
+use crate::backend::{Backend, DefaultBackend};
+use crate::matrix::Complex;
+
+use num_traits::{One, Zero};
+
+// vectors are thin matrices, so every matrix operation applies to them as well.
+// products with a vector on either side use ?gemv (see mul.rs).
+pub type ColVector<const N: usize, Inner> = GeneralMatrix<N, 1, Inner>;
+pub type RowVector<const N: usize, Inner> = GeneralMatrix<1, N, Inner>;
+
+impl<const N: usize, Inner> GeneralMatrix<N, 1, Inner>
+where
+    Inner: Clone,
+{
+    #[inline]
+    #[allow(unused)]
+    pub fn new_col_vector(v: [Inner; N]) -> Self {
+        Self::new_col_major([v])
+    }
+}
+
+impl<const N: usize, Inner> GeneralMatrix<1, N, Inner>
+where
+    Inner: Clone,
+{
+    #[inline]
+    #[allow(unused)]
+    pub fn new_row_vector(v: [Inner; N]) -> Self {
+        Self::new_row_major([v])
+    }
+}
+
+macro_rules! impl_macro {
+    ($type: ty, $real: ty) => {
+        impl<const N: usize> GeneralMatrix<N, 1, $type> {
+            // selfᵀ * other, without conjugation
+            #[allow(unused)]
+            pub fn dot(&self, other: &ColVector<N, $type>) -> $type {
+                <DefaultBackend as Backend<$type>>::dot(N, self.as_slice(), 1, other.as_slice(), 1)
+            }
+
+            // selfᴴ * other, the inner product of complex vectors
+            #[allow(unused)]
+            pub fn dotc(&self, other: &ColVector<N, $type>) -> $type {
+                <DefaultBackend as Backend<$type>>::dotc(N, self.as_slice(), 1, other.as_slice(), 1)
+            }
+
+            // Euclidean norm
+            #[allow(unused)]
+            pub fn nrm2(&self) -> $real {
+                <DefaultBackend as Backend<$type>>::nrm2(N, self.as_slice(), 1)
+            }
+
+            // sum of |re| + |im|, the absolute values for real vectors
+            #[allow(unused)]
+            pub fn asum(&self) -> $real {
+                <DefaultBackend as Backend<$type>>::asum(N, self.as_slice(), 1)
+            }
+
+            // index of the first element of the largest |re| + |im|. None if N is 0.
+            #[allow(unused)]
+            pub fn iamax(&self) -> Option<usize> {
+                <DefaultBackend as Backend<$type>>::iamax(N, self.as_slice(), 1).checked_sub(1)
+            }
+
+            // self * otherᵀ
+            #[allow(unused)]
+            pub fn outer<const M: usize>(&self, other: &ColVector<M, $type>) -> GeneralMatrix<N, M, $type> {
+                let mut ret: GeneralMatrix<N, M, $type> = GeneralMatrix::zero();
+                ret.ger(<$type>::one(), self, other);
+                ret
+            }
+
+            // self = alpha * op(A) * x + beta * self
+            #[allow(unused)]
+            pub fn gemv<const K: usize, A>(&mut self, alpha: $type, a: &A, x: &ColVector<K, $type>, beta: $type)
+            where
+                A: AsGemmOperand<N, K, $type>,
+            {
+                let (trans, a, lda) = a.gemm_operand();
+                // ?gemv takes the size of A, not of op(A)
+                let (m, n) = match trans {
+                    crate::backend::Transpose::No => (N, K),
+                    _ => (K, N),
+                };
+
+                <DefaultBackend as Backend<$type>>::gemv(
+                    trans,
+                    m,
+                    n,
+                    alpha,
+                    a,
+                    lda,
+                    x.as_slice(),
+                    1,
+                    beta,
+                    self.as_mut_slice(),
+                    1,
+                );
+            }
+        }
+
+        impl<const H: usize, const W: usize> GeneralMatrix<H, W, $type> {
+            // self = alpha * x * yᵀ + self
+            #[allow(unused)]
+            pub fn ger(&mut self, alpha: $type, x: &ColVector<H, $type>, y: &ColVector<W, $type>) {
+                <DefaultBackend as Backend<$type>>::ger(
+                    H,
+                    W,
+                    alpha,
+                    x.as_slice(),
+                    1,
+                    y.as_slice(),
+                    1,
+                    self.as_mut_slice(),
+                    H.max(1),
+                );
+            }
+        }
+    };
+}
+
+impl_macro!(f32, f32);
+impl_macro!(f64, f64);
+impl_macro!(Complex<f32>, f32);
+impl_macro!(Complex<f64>, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn construct() {
+        let x = ColVector::new_col_vector([1, 2, 3]);
+        let y = RowVector::new_row_vector([1, 2, 3]);
+
+        assert_eq!(x, GeneralMatrix::new_row_major([[1], [2], [3]]));
+        assert_eq!(y, GeneralMatrix::new_row_major([[1, 2, 3]]));
+        assert_eq!(x.transposed(), y);
+    }
+
+    #[test]
+    fn level1() {
+        let x: ColVector<3, f64> = ColVector::new_col_vector([3., -4., 0.]);
+        let y = ColVector::new_col_vector([1., 2., 3.]);
+
+        assert_eq!(x.dot(&y), -5.);
+        assert_eq!(x.nrm2(), 5.);
+        assert_eq!(x.asum(), 7.);
+        assert_eq!(x.iamax(), Some(1));
+        assert_eq!(ColVector::<0, f32>::zero().iamax(), None);
+    }
+
+    #[test]
+    fn matrix_vector_product() {
+        let a: GeneralMatrix<3, 2, f64> = GeneralMatrix::new_row_major([[1., 2.], [3., 4.], [5., 6.]]);
+        let x = ColVector::new_col_vector([1., 1.]);
+        let y = RowVector::new_row_vector([1., 0., 1.]);
+
+        assert_eq!(&a * &x, ColVector::new_col_vector([3., 7., 11.]));
+        assert_eq!(y * a.clone(), RowVector::new_row_vector([6., 8.]));
+
+        // z = 2 * Aᵀ * [1, 0, 1] + z
+        let mut z: ColVector<2, f64> = ColVector::new_col_vector([1., 1.]);
+        z.gemv(2., &a.transpose(), &ColVector::new_col_vector([1., 0., 1.]), 1.);
+        assert_eq!(z, ColVector::new_col_vector([13., 17.]));
+    }
+
+    #[test]
+    fn outer_product() {
+        let x: ColVector<2, f32> = ColVector::new_col_vector([1., 2.]);
+        let y = ColVector::new_col_vector([3., 4., 5.]);
+
+        assert_eq!(
+            x.outer(&y),
+            GeneralMatrix::new_row_major([[3., 4., 5.], [6., 8., 10.]])
+        );
+    }
+
+    #[test]
+    fn level1_complex() {
+        let x: ColVector<2, Complex<f64>> = ColVector::new_col_vector([Complex::new(3., 4.), Complex::new(0., -1.)]);
+        let y = ColVector::new_col_vector([Complex::new(0., 1.), Complex::new(1., 0.)]);
+
+        assert_eq!(x.dot(&y), Complex::new(-4., 2.));
+        assert_eq!(x.dotc(&y), Complex::new(4., 4.));
+        assert_eq!(x.dotc(&x).re, 26.);
+        assert_eq!(x.asum(), 8.);
+        assert_eq!(x.iamax(), Some(0));
+    }
+}
@@ -0,0 +1,442 @@
+use super::lu::{min, GeneralLuError, GeneralLuPivot};
+use super::{AsMatrix, GeneralMatrix};
+use crate::backend::{Backend, DefaultBackend, Transpose};
+use crate::factorizations::lu::AsLuError;
+use crate::matrix::Complex;
+
+// number of elements spanned by a column-major H x W block with leading dimension ld
+const fn span(h: usize, w: usize, ld: usize) -> usize {
+    if h == 0 || w == 0 {
+        0
+    } else {
+        (w - 1) * ld + h
+    }
+}
+
+// range of the buffer holding the h x w block that starts at index `start`.
+// an empty block is at 0, since `start` may be past the end, e.g. for 0 columns right of the last one.
+fn block(start: usize, h: usize, w: usize, ld: usize) -> core::ops::Range<usize> {
+    match span(h, w, ld) {
+        0 => 0..0,
+        len => start..start + len,
+    }
+}
+
+// borrowed H x W block of a column-major buffer. element (row, col) is data[row + col * ld].
+#[derive(Debug)]
+pub struct GeneralMatrixView<'a, const H: usize, const W: usize, Inner> {
+    data: &'a [Inner],
+    ld: usize,
+}
+
+// a shared borrow is copyable whatever Inner is
+impl<'a, const H: usize, const W: usize, Inner> Clone for GeneralMatrixView<'a, H, W, Inner> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, const H: usize, const W: usize, Inner> Copy for GeneralMatrixView<'a, H, W, Inner> {}
+
+#[derive(Debug)]
+pub struct GeneralMatrixViewMut<'a, const H: usize, const W: usize, Inner> {
+    data: &'a mut [Inner],
+    ld: usize,
+}
+
+impl<'a, const H: usize, const W: usize, Inner> GeneralMatrixView<'a, H, W, Inner> {
+    // panics if ld < H or data is too short
+    #[inline]
+    #[allow(unused)]
+    pub fn from_slice(data: &'a [Inner], ld: usize) -> Self {
+        assert!(ld >= H.max(1), "leading dimension {} is smaller than {}", ld, H);
+        assert!(data.len() >= span(H, W, ld), "slice is too short for the view");
+
+        Self {
+            data: &data[..span(H, W, ld)],
+            ld,
+        }
+    }
+
+    #[inline]
+    #[allow(unused)]
+    pub const fn leading_dimension(&self) -> usize {
+        self.ld
+    }
+    // starts at element (0, 0). pass it with leading_dimension() as (a, lda) to BLAS/LAPACK.
+    #[inline]
+    #[allow(unused)]
+    pub const fn as_slice(&self) -> &'a [Inner] {
+        self.data
+    }
+
+    // VH x VW block starting at (row, col)
+    #[inline]
+    #[allow(unused)]
+    pub fn view<const VH: usize, const VW: usize>(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> GeneralMatrixView<'a, VH, VW, Inner> {
+        assert!(row + VH <= H && col + VW <= W, "view is out of range");
+
+        let start = row + col * self.ld;
+        GeneralMatrixView {
+            data: &self.data[block(start, VH, VW, self.ld)],
+            ld: self.ld,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn to_owned(&self) -> GeneralMatrix<H, W, Inner>
+    where
+        Inner: Clone,
+    {
+        GeneralMatrix::by_f(|col, row| self.at(row, col).clone())
+    }
+}
+
+impl<'a, const H: usize, const W: usize, Inner> GeneralMatrixViewMut<'a, H, W, Inner> {
+    // panics if ld < H or data is too short
+    #[inline]
+    #[allow(unused)]
+    pub fn from_slice(data: &'a mut [Inner], ld: usize) -> Self {
+        assert!(ld >= H.max(1), "leading dimension {} is smaller than {}", ld, H);
+        assert!(data.len() >= span(H, W, ld), "slice is too short for the view");
+
+        Self {
+            data: &mut data[..span(H, W, ld)],
+            ld,
+        }
+    }
+
+    #[inline]
+    #[allow(unused)]
+    pub const fn leading_dimension(&self) -> usize {
+        self.ld
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn as_slice(&self) -> &[Inner] {
+        self.data
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn as_mut_slice(&mut self) -> &mut [Inner] {
+        self.data
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn at_mut(&mut self, row: usize, col: usize) -> &mut Inner {
+        assert!(row < H && col < W);
+        &mut self.data[row + col * self.ld]
+    }
+
+    #[inline]
+    #[allow(unused)]
+    pub fn as_view(&self) -> GeneralMatrixView<'_, H, W, Inner> {
+        GeneralMatrixView {
+            data: self.data,
+            ld: self.ld,
+        }
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn view<const VH: usize, const VW: usize>(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> GeneralMatrixView<'_, VH, VW, Inner> {
+        self.as_view().view(row, col)
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn view_mut<const VH: usize, const VW: usize>(
+        &mut self,
+        row: usize,
+        col: usize,
+    ) -> GeneralMatrixViewMut<'_, VH, VW, Inner> {
+        assert!(row + VH <= H && col + VW <= W, "view is out of range");
+
+        let start = row + col * self.ld;
+        GeneralMatrixViewMut {
+            data: &mut self.data[block(start, VH, VW, self.ld)],
+            ld: self.ld,
+        }
+    }
+
+    // splits into the first K columns and the remaining W - K columns, e.g. a panel and its trailing matrix
+    #[allow(unused)]
+    pub fn split_cols_mut<const K: usize>(
+        self,
+    ) -> (
+        GeneralMatrixViewMut<'a, H, K, Inner>,
+        GeneralMatrixViewMut<'a, H, { W - K }, Inner>,
+    )
+    where
+        [(); W - K]:,
+    {
+        assert!(K <= W, "split point is out of range");
+
+        let ld = self.ld;
+        let (left, right) = self.data.split_at_mut((K * ld).min(self.data.len()));
+        (
+            GeneralMatrixViewMut {
+                data: &mut left[..span(H, K, ld)],
+                ld,
+            },
+            GeneralMatrixViewMut { data: right, ld },
+        )
+    }
+
+    #[allow(unused)]
+    pub fn copy_from<T>(&mut self, source: &T)
+    where
+        T: AsMatrix<H, W, Inner>,
+        Inner: Clone,
+    {
+        for col in 0..W {
+            for row in 0..H {
+                *self.at_mut(row, col) = source.at(row, col).clone();
+            }
+        }
+    }
+}
+
+impl<'a, const H: usize, const W: usize, Inner> AsMatrix<H, W, Inner>
+    for GeneralMatrixView<'a, H, W, Inner>
+{
+    fn at(&self, row: usize, col: usize) -> &Inner {
+        assert!(row < H && col < W);
+        &self.data[row + col * self.ld]
+    }
+}
+
+impl<'a, const H: usize, const W: usize, Inner> AsMatrix<H, W, Inner>
+    for GeneralMatrixViewMut<'a, H, W, Inner>
+{
+    fn at(&self, row: usize, col: usize) -> &Inner {
+        assert!(row < H && col < W);
+        &self.data[row + col * self.ld]
+    }
+}
+
+impl<const H: usize, const W: usize, Inner> GeneralMatrix<H, W, Inner>
+where
+    Inner: Clone,
+{
+    #[inline]
+    #[allow(unused)]
+    pub fn as_view(&self) -> GeneralMatrixView<'_, H, W, Inner> {
+        GeneralMatrixView::from_slice(self.as_slice(), H.max(1))
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn as_view_mut(&mut self) -> GeneralMatrixViewMut<'_, H, W, Inner> {
+        GeneralMatrixViewMut::from_slice(self.as_mut_slice(), H.max(1))
+    }
+
+    // VH x VW block starting at (row, col), without copying
+    #[inline]
+    #[allow(unused)]
+    pub fn view<const VH: usize, const VW: usize>(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> GeneralMatrixView<'_, VH, VW, Inner> {
+        self.as_view().view(row, col)
+    }
+    #[inline]
+    #[allow(unused)]
+    pub fn view_mut<const VH: usize, const VW: usize>(
+        &mut self,
+        row: usize,
+        col: usize,
+    ) -> GeneralMatrixViewMut<'_, VH, VW, Inner> {
+        let ld = H.max(1);
+        let start = row + col * ld;
+        assert!(row + VH <= H && col + VW <= W, "view is out of range");
+
+        GeneralMatrixViewMut {
+            data: &mut self.as_mut_slice()[block(start, VH, VW, ld)],
+            ld,
+        }
+    }
+}
+
+macro_rules! impl_macro {
+    ($axpy: ident, $gemm: ident, $getrf: ident, $type: ty) => {
+        paste::paste! {
+            // dest += alpha * value, column by column
+            #[allow(unused)]
+            pub fn [<general_matrix_view_add_ $axpy>]<const H: usize, const W: usize>(
+                dest: &mut GeneralMatrixViewMut<H, W, $type>,
+                alpha: $type,
+                value: &GeneralMatrixView<H, W, $type>,
+            ) {
+                if H == 0 {
+                    return;
+                }
+
+                let (ldd, ldv) = (dest.leading_dimension(), value.leading_dimension());
+                for col in 0..W {
+                    <DefaultBackend as Backend<$type>>::axpy(
+                        H,
+                        alpha,
+                        &value.as_slice()[col * ldv..],
+                        1,
+                        &mut dest.as_mut_slice()[col * ldd..],
+                        1,
+                    );
+                }
+            }
+
+            // C = alpha * A * B + beta * C
+            #[allow(unused)]
+            pub fn [<general_matrix_view_mul_ $gemm>]<const LH: usize, const LWRH: usize, const RW: usize>(
+                a: &GeneralMatrixView<LH, LWRH, $type>,
+                b: &GeneralMatrixView<LWRH, RW, $type>,
+                c: &mut GeneralMatrixViewMut<LH, RW, $type>,
+                alpha: $type,
+                beta: $type,
+            ) {
+                let ldc = c.leading_dimension();
+                <DefaultBackend as Backend<$type>>::gemm(
+                    Transpose::No,
+                    Transpose::No,
+                    LH,
+                    RW,
+                    LWRH,
+                    alpha,
+                    a.as_slice(),
+                    a.leading_dimension(),
+                    b.as_slice(),
+                    b.leading_dimension(),
+                    beta,
+                    c.as_mut_slice(),
+                    ldc,
+                );
+            }
+
+            // LU factorization of the block in place
+            #[allow(unused)]
+            pub fn [<general_matrix_view_lu_ $getrf>]<const H: usize, const W: usize>(
+                a: &mut GeneralMatrixViewMut<H, W, $type>,
+                pivot: &mut GeneralLuPivot<{ min(H, W) }>,
+            ) -> GeneralLuError
+            where
+                [(); min(H, W)]:,
+            {
+                let lda = a.leading_dimension();
+                let mut error = GeneralLuError::SUCCESS;
+                *error.as_lapack_into_mut() =
+                    <DefaultBackend as Backend<$type>>::getrf(H, W, a.as_mut_slice(), lda, &mut pivot.pivot);
+                error
+            }
+        }
+    };
+}
+
+impl_macro!(saxpy, sgemm, sgetrf, f32);
+impl_macro!(daxpy, dgemm, dgetrf, f64);
+impl_macro!(caxpy, cgemm, cgetrf, Complex<f32>);
+impl_macro!(zaxpy, zgemm, zgetrf, Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factorizations::lu::AsLu;
+
+    #[test]
+    fn view_block() {
+        let m = GeneralMatrix::new_row_major([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let v = m.view::<2, 2>(1, 1);
+
+        assert_eq!(v.leading_dimension(), 3);
+        assert_eq!(v.at(0, 0), &5);
+        assert_eq!(v.at(0, 1), &6);
+        assert_eq!(v.at(1, 0), &8);
+        assert_eq!(v.at(1, 1), &9);
+        assert_eq!(v.to_owned(), GeneralMatrix::new_row_major([[5, 6], [8, 9]]));
+
+        let w = v.view::<1, 2>(1, 0);
+        assert_eq!(w.to_owned(), GeneralMatrix::new_row_major([[8, 9]]));
+    }
+
+    #[test]
+    fn view_zero_sized() {
+        let mut m = GeneralMatrix::<2, 2, f64>::new_row_major([[1., 2.], [3., 4.]]);
+
+        // the blocks start past the end of the buffer
+        assert!(m.view::<1, 0>(1, 2).as_slice().is_empty());
+        assert!(m.view::<0, 1>(2, 1).as_slice().is_empty());
+        assert!(m.view_mut::<1, 0>(1, 2).as_slice().is_empty());
+
+        let mut v = m.view_mut::<2, 2>(0, 0);
+        assert!(v.view::<0, 0>(2, 2).as_slice().is_empty());
+        assert!(v.view_mut::<2, 0>(0, 2).as_slice().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn view_out_of_range() {
+        let m = GeneralMatrix::new_row_major([[1, 2], [3, 4]]);
+        m.view::<2, 2>(1, 0);
+    }
+
+    #[test]
+    fn explicit_leading_dimension() {
+        // 2 x 2 matrix stored in a buffer with ld = 3
+        let data = [1., 2., -1., 3., 4., -1.];
+        let v = GeneralMatrixView::<2, 2, f64>::from_slice(&data, 3);
+
+        assert_eq!(v.to_owned(), GeneralMatrix::new_col_major([[1., 2.], [3., 4.]]));
+    }
+
+    #[test]
+    fn update_block_in_place() {
+        let mut m: GeneralMatrix<3, 3, f64> = GeneralMatrix::by(1.);
+        let a: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[1., 2.], [3., 4.]]);
+        let id: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[1., 0.], [0., 1.]]);
+
+        // lower right block = 2 * a * id + block
+        general_matrix_view_mul_dgemm(&a.as_view(), &id.as_view(), &mut m.view_mut::<2, 2>(1, 1), 2., 1.);
+
+        let ans = GeneralMatrix::new_row_major([[1., 1., 1.], [1., 3., 5.], [1., 7., 9.]]);
+        assert_eq!(m, ans);
+
+        // first column -= second column
+        let second = m.clone();
+        general_matrix_view_add_daxpy(&mut m.view_mut::<3, 1>(0, 0), -1., &second.view::<3, 1>(0, 1));
+
+        let ans = GeneralMatrix::new_row_major([[0., 1., 1.], [-2., 3., 5.], [-6., 7., 9.]]);
+        assert_eq!(m, ans);
+    }
+
+    #[test]
+    fn split_panels() {
+        let mut m = GeneralMatrix::new_row_major([[1, 2, 3], [4, 5, 6]]);
+        let (mut panel, mut trailing) = m.as_view_mut().split_cols_mut::<1>();
+
+        *panel.at_mut(1, 0) = 0;
+        *trailing.at_mut(0, 1) = 0;
+
+        assert_eq!(m, GeneralMatrix::new_row_major([[1, 2, 0], [0, 5, 6]]));
+    }
+
+    #[test]
+    fn lu_of_block() {
+        let mut m: GeneralMatrix<3, 3, f64> =
+            GeneralMatrix::new_row_major([[9., 9., 9.], [9., 2., 9.], [9., 4., 4.]]);
+        let mut pivot = GeneralLuPivot::<2>::default();
+
+        let error = general_matrix_view_lu_dgetrf(&mut m.view_mut::<2, 2>(1, 1), &mut pivot);
+        assert!(!error.is_error());
+
+        let block: GeneralMatrix<2, 2, f64> = GeneralMatrix::new_row_major([[2., 9.], [4., 4.]]);
+        let (lu, _) = block.lu().expect("factorization failed");
+
+        assert_eq!(m.view::<2, 2>(1, 1).to_owned(), lu.internal_matrix);
+        assert_eq!(pivot, lu.pivot);
+        assert_eq!(m.at(0, 0), &9.);
+    }
+}
@@ -1,9 +1,12 @@
-use num_traits::Zero;
+pub mod lu;
+pub mod solve;
+
+use num_traits::{Signed, Zero};
 
 use super::general::GeneralMatrix;
 use super::{print_matrix_display, AsMatrix};
 
-use core::convert::From;
+use core::convert::{From, TryFrom};
 use core::fmt::{Display, Formatter, Result};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -244,23 +247,100 @@ where
     fn at(&self, row: usize, col: usize) -> &Inner {
         if row == col {
             &self.diagonal[row]
-        } else if row == col + 1 {
-            &self.upper[col]
         } else if row + 1 == col {
-            &self.lower[row]
+            &self.upper[row]
+        } else if row == col + 1 {
+            &self.lower[col]
         } else {
             &self.zero
         }
     }
 }
 
-impl<const S: usize, Inner> From<GeneralMatrix<S, S, Inner>> for TridiagonalMatrix<S, Inner>
+// the first element (in row-major order) outside the three bands that is not negligible
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutOfBandError<Inner> {
+    pub row: usize,
+    pub col: usize,
+    pub value: Inner,
+}
+
+impl<Inner> Display for OutOfBandError<Inner>
+where
+    Inner: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "element ({}, {}) = {} is outside the tridiagonal band",
+            self.row, self.col, self.value
+        )
+    }
+}
+
+impl<const S: usize, Inner> TridiagonalMatrix<S, Inner>
+where
+    Inner: Clone + Zero,
+    [(); S - 1]:,
+{
+    // extracts the three bands if every element outside them satisfies `is_negligible`.
+    #[allow(unused)]
+    pub fn try_from_general_with(
+        matrix: &GeneralMatrix<S, S, Inner>,
+        is_negligible: impl Fn(&Inner) -> bool,
+    ) -> core::result::Result<Self, OutOfBandError<Inner>> {
+        for row in 0..S {
+            for col in 0..S {
+                let in_band = row <= col + 1 && col <= row + 1;
+                if !in_band && !is_negligible(matrix.at(row, col)) {
+                    return Err(OutOfBandError {
+                        row,
+                        col,
+                        value: matrix.at(row, col).clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(Self::by_f(
+            |i| matrix.at(i, i + 1).clone(),
+            |i| matrix.at(i, i).clone(),
+            |i| matrix.at(i + 1, i).clone(),
+        ))
+    }
+
+    // treats elements outside the bands whose absolute value is at most `tolerance` as zero.
+    #[allow(unused)]
+    pub fn try_from_general_with_tolerance(
+        matrix: &GeneralMatrix<S, S, Inner>,
+        tolerance: Inner,
+    ) -> core::result::Result<Self, OutOfBandError<Inner>>
+    where
+        Inner: Signed + PartialOrd,
+    {
+        Self::try_from_general_with(matrix, |v| v.abs() <= tolerance)
+    }
+}
+
+impl<const S: usize, Inner> TryFrom<GeneralMatrix<S, S, Inner>> for TridiagonalMatrix<S, Inner>
 where
     Inner: Clone + Zero,
     [(); S - 1]:,
 {
-    fn from(matrix: GeneralMatrix<S, S, Inner>) -> Self {
-        todo!();
+    type Error = OutOfBandError<Inner>;
+
+    fn try_from(matrix: GeneralMatrix<S, S, Inner>) -> core::result::Result<Self, Self::Error> {
+        Self::try_from_general_with(&matrix, |v| v.is_zero())
+    }
+}
+
+impl<const S: usize, Inner> From<TridiagonalMatrix<S, Inner>> for GeneralMatrix<S, S, Inner>
+where
+    Inner: Clone + Zero,
+    [(); S - 1]:,
+{
+    fn from(matrix: TridiagonalMatrix<S, Inner>) -> Self {
+        GeneralMatrix::by_f(|col, row| matrix.at(row, col).clone())
     }
 }
 
@@ -302,4 +382,51 @@ mod tests {
 
         assert_eq!(ans, m);
     }
+
+    #[test]
+    fn test_at_band_orientation() {
+        let m = TridiagonalMatrix::new([1, 2], [3, 4, 5], [6, 7]);
+
+        // upper is the super diagonal, lower is the sub diagonal
+        assert_eq!(m.at(0, 1), &1);
+        assert_eq!(m.at(1, 2), &2);
+        assert_eq!(m.at(1, 0), &6);
+        assert_eq!(m.at(2, 1), &7);
+        assert_eq!(m.at(2, 0), &0);
+    }
+
+    #[test]
+    fn test_try_from_general() {
+        let general = GeneralMatrix::new_row_major([[1, 2, 0], [3, 4, 5], [0, 6, 7]]);
+        let m = TridiagonalMatrix::try_from(general.clone()).expect("matrix is tridiagonal");
+
+        assert_eq!(m, TridiagonalMatrix::new([2, 5], [1, 4, 7], [3, 6]));
+        assert_eq!(GeneralMatrix::from(m), general);
+    }
+
+    #[test]
+    fn test_try_from_general_out_of_band() {
+        let general = GeneralMatrix::new_row_major([[1, 2, 0], [3, 4, 5], [8, 6, 7]]);
+        let error = TridiagonalMatrix::try_from(general).expect_err("matrix is not tridiagonal");
+
+        assert_eq!(
+            error,
+            OutOfBandError {
+                row: 2,
+                col: 0,
+                value: 8
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_from_general_with_tolerance() {
+        let general = GeneralMatrix::new_row_major([[1., 2., 1e-14], [3., 4., 5.], [0., 6., 7.]]);
+
+        assert!(TridiagonalMatrix::try_from(general.clone()).is_err());
+
+        let m = TridiagonalMatrix::try_from_general_with_tolerance(&general, 1e-12)
+            .expect("matrix is tridiagonal within the tolerance");
+        assert_eq!(m, TridiagonalMatrix::new([2., 5.], [1., 4., 7.], [3., 6.]));
+    }
 }
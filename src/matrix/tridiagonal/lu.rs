@@ -0,0 +1,189 @@
+use super::TridiagonalMatrix;
+use crate::backend::{DefaultBackend, RealBackend};
+use crate::factorizations::lu;
+use crate::matrix::general::lu::GeneralLuError;
+use crate::matrix::general::solve::GeneralSolveError;
+use crate::matrix::general::GeneralMatrix;
+use crate::solve;
+
+use num_traits::Zero;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TridiagonalLuPivot<const S: usize, Inner> {
+    // second super diagonal of U, filled in by row interchanges. length = S - 2
+    pub second_upper: Vec<Inner>,
+    pub pivot: [i32; S],
+}
+
+impl<const S: usize, Inner> Default for TridiagonalLuPivot<S, Inner>
+where
+    Inner: Zero + Clone,
+{
+    fn default() -> Self {
+        Self {
+            second_upper: vec![Inner::zero(); S.saturating_sub(2)],
+            pivot: [0; S],
+        }
+    }
+}
+
+// result of ?gttrf: L's multipliers are stored in `lower`, U's diagonal and first super diagonal
+// in `diagonal` and `upper`, and U's second super diagonal in the pivot.
+#[derive(Debug, PartialEq)]
+pub struct TridiagonalLuFormat<const S: usize, Inner, Pivot>
+where
+    Inner: Clone + Zero,
+    [(); S - 1]:,
+{
+    pub internal_matrix: TridiagonalMatrix<S, Inner>,
+    pub pivot: Pivot,
+}
+
+impl<const S: usize, Inner, Pivot> TridiagonalLuFormat<S, Inner, Pivot>
+where
+    Inner: Clone + Zero,
+    [(); S - 1]:,
+{
+    pub fn new(matrix: TridiagonalMatrix<S, Inner>, pivot: Pivot) -> Self {
+        Self {
+            internal_matrix: matrix,
+            pivot,
+        }
+    }
+}
+
+macro_rules! impl_macro {
+    ($type: ty) => {
+        impl<const S: usize>
+            lu::LuFormat<TridiagonalMatrix<S, $type>, TridiagonalLuPivot<S, $type>>
+            for TridiagonalLuFormat<S, $type, TridiagonalLuPivot<S, $type>>
+        where
+            [(); S - 1]:,
+        {
+            fn new_with(
+                internal_matrix: TridiagonalMatrix<S, $type>,
+                pivot: TridiagonalLuPivot<S, $type>,
+            ) -> Self {
+                Self {
+                    internal_matrix,
+                    pivot,
+                }
+            }
+            fn data_ref(&self) -> (&TridiagonalMatrix<S, $type>, &TridiagonalLuPivot<S, $type>) {
+                (&self.internal_matrix, &self.pivot)
+            }
+            fn data_mut(
+                &mut self,
+            ) -> (&mut TridiagonalMatrix<S, $type>, &mut TridiagonalLuPivot<S, $type>) {
+                (&mut self.internal_matrix, &mut self.pivot)
+            }
+        }
+
+        impl<const S: usize>
+            lu::AsLu<S, S, $type, TridiagonalLuPivot<S, $type>, GeneralLuError>
+            for TridiagonalMatrix<S, $type>
+        where
+            [(); S - 1]:,
+        {
+            type Lu = TridiagonalLuFormat<S, $type, TridiagonalLuPivot<S, $type>>;
+
+            default fn fact_internal(dest: &mut Self::Lu) -> GeneralLuError {
+                use lu::{AsLuError, LuFormat};
+
+                let (mat, piv) = dest.data_mut();
+                let (du, d, dl) = mat.inner_mut();
+
+                let mut error = GeneralLuError::SUCCESS;
+
+                *error.as_lapack_into_mut() = <DefaultBackend as RealBackend<$type>>::gttrf(
+                    S,
+                    dl,
+                    d,
+                    du,
+                    &mut piv.second_upper,
+                    &mut piv.pivot,
+                );
+
+                error
+            }
+        }
+
+        impl<const S: usize, const N: usize>
+            solve::LuSolve<GeneralMatrix<S, N, $type>, GeneralSolveError>
+            for TridiagonalLuFormat<S, $type, TridiagonalLuPivot<S, $type>>
+        where
+            [(); S - 1]:,
+        {
+            type Output = GeneralMatrix<S, N, $type>;
+
+            fn solve(
+                &self,
+                rhs: GeneralMatrix<S, N, $type>,
+            ) -> Result<Self::Output, GeneralSolveError> {
+                use solve::AsSolveError;
+
+                let (du, d, dl) = self.internal_matrix.inner();
+                let mut dest = rhs;
+
+                let mut error = GeneralSolveError::SUCCESS;
+
+                *error.as_lapack_into_mut() = <DefaultBackend as RealBackend<$type>>::gttrs(
+                    S,
+                    N,
+                    dl,
+                    d,
+                    du,
+                    &self.pivot.second_upper,
+                    &self.pivot.pivot,
+                    dest.as_mut_slice(),
+                    S,
+                );
+
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(dest)
+                }
+            }
+        }
+    };
+}
+
+impl_macro!(f32);
+impl_macro!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_matrix_approx_eq;
+    use crate::factorizations::lu::{AsLu, AsLuError};
+    use crate::solve::LuSolve;
+
+    #[test]
+    fn factorize_and_reuse() {
+        let m: TridiagonalMatrix<4, f64> =
+            TridiagonalMatrix::new([1., 1., 1.], [4., 4., 4., 4.], [2., 2., 2.]);
+        let (lu, error) = m.lu().expect("factorization failed");
+        assert!(!error.is_error());
+
+        let x = lu
+            .solve(GeneralMatrix::new_col_major([[5., 7., 7., 6.]]))
+            .expect("solve failed");
+        let y = lu
+            .solve(GeneralMatrix::new_col_major([[6., 13., 20., 22.]]))
+            .expect("solve failed");
+
+        assert_matrix_approx_eq!(x, GeneralMatrix::new_col_major([[1., 1., 1., 1.]]), epsilon = 1e-12);
+        assert_matrix_approx_eq!(y, GeneralMatrix::new_col_major([[1., 2., 3., 4.]]), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn factorize_singular() {
+        let m: TridiagonalMatrix<3, f32> =
+            TridiagonalMatrix::new([1., 0.], [1., 1., 0.], [1., 0.]);
+        let (_, error) = m.lu().expect("arguments are valid");
+
+        assert!(error.is_error());
+        assert!(!error.is_invalid());
+    }
+}
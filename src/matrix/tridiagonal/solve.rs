@@ -0,0 +1,205 @@
+use super::TridiagonalMatrix;
+use crate::backend::{DefaultBackend, RealBackend};
+use crate::matrix::general::solve::GeneralSolveError;
+use crate::matrix::general::GeneralMatrix;
+use crate::matrix::AsMatrix;
+use crate::solve::{self, AsSolveError};
+
+use num_traits::Zero;
+
+use std::ops::{Div, Mul, Sub};
+
+// Thomas algorithm (Gaussian elimination without pivoting) for any field-like Inner.
+// stable for diagonally dominant or symmetric positive definite matrices.
+#[allow(unused)]
+pub fn solve_thomas<const S: usize, const N: usize, Inner>(
+    matrix: &TridiagonalMatrix<S, Inner>,
+    rhs: GeneralMatrix<S, N, Inner>,
+) -> Result<GeneralMatrix<S, N, Inner>, GeneralSolveError>
+where
+    Inner: Clone + Zero + Sub<Output = Inner> + Mul<Output = Inner> + Div<Output = Inner>,
+    [(); S - 1]:,
+{
+    let (upper, diagonal, lower) = matrix.inner();
+
+    // modified super diagonal c'
+    let mut modified: Vec<Inner> = Vec::with_capacity(S);
+    // pivots of the elimination
+    let mut pivots: Vec<Inner> = Vec::with_capacity(S);
+
+    for i in 0..S {
+        let pivot = if i == 0 {
+            diagonal[0].clone()
+        } else {
+            diagonal[i].clone() - lower[i - 1].clone() * modified[i - 1].clone()
+        };
+
+        if pivot.is_zero() {
+            let mut error = GeneralSolveError::SUCCESS;
+            *error.as_lapack_into_mut() = i as i32 + 1;
+            return Err(error);
+        }
+
+        if i + 1 < S {
+            modified.push(upper[i].clone() / pivot.clone());
+        }
+        pivots.push(pivot);
+    }
+
+    let mut dest = rhs;
+    for col in 0..N {
+        // forward sweep
+        for i in 0..S {
+            let value = if i == 0 {
+                dest.at(0, col).clone()
+            } else {
+                dest.at(i, col).clone() - lower[i - 1].clone() * dest.at(i - 1, col).clone()
+            };
+            *dest.at_mut(i, col) = value / pivots[i].clone();
+        }
+
+        // back substitution
+        for i in (0..S.saturating_sub(1)).rev() {
+            let value = dest.at(i, col).clone() - modified[i].clone() * dest.at(i + 1, col).clone();
+            *dest.at_mut(i, col) = value;
+        }
+    }
+
+    Ok(dest)
+}
+
+impl<const S: usize, const N: usize, Inner>
+    solve::AsSolve<GeneralMatrix<S, N, Inner>, GeneralSolveError> for TridiagonalMatrix<S, Inner>
+where
+    Inner: Clone + Zero + Sub<Output = Inner> + Mul<Output = Inner> + Div<Output = Inner>,
+    [(); S - 1]:,
+{
+    type Output = GeneralMatrix<S, N, Inner>;
+
+    default fn solve(
+        self,
+        rhs: GeneralMatrix<S, N, Inner>,
+    ) -> Result<Self::Output, GeneralSolveError> {
+        solve_thomas(&self, rhs)
+    }
+}
+
+impl<const S: usize, Inner> solve::AsSolve<[Inner; S], GeneralSolveError>
+    for TridiagonalMatrix<S, Inner>
+where
+    Inner: Clone + Zero + Sub<Output = Inner> + Mul<Output = Inner> + Div<Output = Inner>,
+    [(); S - 1]:,
+{
+    type Output = [Inner; S];
+
+    fn solve(self, rhs: [Inner; S]) -> Result<Self::Output, GeneralSolveError> {
+        let x: GeneralMatrix<S, 1, Inner> =
+            solve::AsSolve::solve(self, GeneralMatrix::new_col_major([rhs]))?;
+        Ok(x.inner()[0].clone())
+    }
+}
+
+macro_rules! impl_macro {
+    ($type: ty) => {
+        impl<const S: usize, const N: usize>
+            solve::AsSolve<GeneralMatrix<S, N, $type>, GeneralSolveError>
+            for TridiagonalMatrix<S, $type>
+        where
+            [(); S - 1]:,
+        {
+            fn solve(
+                self,
+                rhs: GeneralMatrix<S, N, $type>,
+            ) -> Result<Self::Output, GeneralSolveError> {
+                // ?gtsv overwrites the bands with its factorization
+                let mut mat = self;
+                let (du, d, dl) = mat.inner_mut();
+
+                let mut dest = rhs;
+
+                let mut error = GeneralSolveError::SUCCESS;
+
+                *error.as_lapack_into_mut() =
+                    <DefaultBackend as RealBackend<$type>>::gtsv(S, N, dl, d, du, dest.as_mut_slice(), S);
+
+                if error.is_error() {
+                    Err(error)
+                } else {
+                    Ok(dest)
+                }
+            }
+        }
+    };
+}
+
+impl_macro!(f32);
+impl_macro!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_matrix_approx_eq;
+    use crate::solve::AsSolve;
+
+    fn asymmetric() -> TridiagonalMatrix<4, f64> {
+        TridiagonalMatrix::new([1., 1., 1.], [4., 4., 4., 4.], [2., 2., 2.])
+    }
+
+    #[test]
+    fn thomas_generic() {
+        let b = GeneralMatrix::new_col_major([[5., 7., 7., 6.], [6., 13., 20., 22.]]);
+        let x = solve_thomas(&asymmetric(), b).expect("matrix is regular");
+
+        assert_matrix_approx_eq!(
+            x,
+            GeneralMatrix::new_col_major([[1., 1., 1., 1.], [1., 2., 3., 4.]]),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn thomas_zero_pivot() {
+        let m = TridiagonalMatrix::new([1., 1.], [0., 1., 1.], [1., 1.]);
+        let b = GeneralMatrix::new_col_major([[1., 1., 1.]]);
+
+        let error = solve_thomas(&m, b).expect_err("pivot is zero");
+        assert!(error.is_singular());
+    }
+
+    #[test]
+    fn solve_gtsv_f64() {
+        let b = GeneralMatrix::new_col_major([[5., 7., 7., 6.], [6., 13., 20., 22.]]);
+        let x = asymmetric().solve(b).expect("matrix is regular");
+
+        assert_matrix_approx_eq!(
+            x,
+            GeneralMatrix::new_col_major([[1., 1., 1., 1.], [1., 2., 3., 4.]]),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn solve_gtsv_pivoting() {
+        // zero leading pivot is fine with partial pivoting
+        let m: TridiagonalMatrix<3, f64> = TridiagonalMatrix::new([1., 1.], [0., 1., 1.], [1., 1.]);
+        let x = m.solve([1., 3., 2.]).expect("matrix is regular");
+
+        assert_matrix_approx_eq!(
+            GeneralMatrix::new_col_major([x]),
+            GeneralMatrix::new_col_major([[1., 1., 1.]]),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn solve_gtsv_f32_vector() {
+        let m: TridiagonalMatrix<3, f32> = TridiagonalMatrix::new([-1., -1.], [2., 2., 2.], [-1., -1.]);
+        let x = m.solve([0., 0., 4.]).expect("matrix is regular");
+
+        assert_matrix_approx_eq!(
+            GeneralMatrix::new_col_major([x]),
+            GeneralMatrix::new_col_major([[1., 2., 3.]]),
+            epsilon = 1e-5
+        );
+    }
+}
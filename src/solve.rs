@@ -1 +1,61 @@
-pub trait 
+pub trait AsSolveError {
+    fn as_lapack_into_mut(&mut self) -> &mut i32;
+    fn as_lapack_into(&self) -> &i32;
+
+    fn is_invalid(&self) -> bool {
+        self.as_lapack_into() < &0
+    }
+    fn is_singular(&self) -> bool {
+        self.as_lapack_into() > &0
+    }
+    fn is_error(&self) -> bool {
+        self.as_lapack_into() != &0
+    }
+}
+
+// following trait will be implemented for LuFormat
+pub trait LuSolve<Rhs, SolveError>
+where
+    SolveError: AsSolveError,
+{
+    type Output;
+
+    // require methods
+    fn solve(&self, rhs: Rhs) -> Result<Self::Output, SolveError>;
+}
+
+// following trait will be implemented for Matrix
+pub trait AsSolve<Rhs, SolveError>
+where
+    Self: Sized,
+    SolveError: AsSolveError,
+{
+    type Output;
+
+    // require methods
+    fn solve(self, rhs: Rhs) -> Result<Self::Output, SolveError>;
+}
+
+// following trait will be implemented for CholeskyFormat
+pub trait CholeskySolve<Rhs, SolveError>
+where
+    SolveError: AsSolveError,
+{
+    type Output;
+
+    // require methods
+    fn solve(&self, rhs: Rhs) -> Result<Self::Output, SolveError>;
+}
+
+// following trait will be implemented for Matrix
+pub trait AsLeastSquares<Rhs, SolveError>
+where
+    Self: Sized,
+    SolveError: AsSolveError,
+{
+    type Output;
+    type Residual;
+
+    // require methods
+    fn least_squares(self, rhs: Rhs) -> Result<(Self::Output, Self::Residual), SolveError>;
+}